@@ -0,0 +1,112 @@
+//! # Application Subclass Example
+//!
+//! This sample demonstrates how to subclass `gtk::Application` and handle
+//! the files passed on the command line through the `open` virtual method.
+//!
+//! Run it with some file paths as arguments, e.g.
+//! `cargo run --bin application_subclass -- Cargo.toml src/bin/basic.rs`
+
+use std::cell::RefCell;
+
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{gio, glib};
+
+mod imp {
+    use super::*;
+    use glib::subclass;
+
+    #[derive(Debug, Default)]
+    pub struct ExApplication {
+        /// The list of opened files, created in `startup()`.
+        pub files: RefCell<Option<gtk::ListBox>>,
+    }
+
+    impl ObjectSubclass for ExApplication {
+        const NAME: &'static str = "ExApplication";
+        type Type = super::ExApplication;
+        type ParentType = gtk::Application;
+        type Instance = subclass::simple::InstanceStruct<Self>;
+        type Class = subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for ExApplication {}
+
+    impl ApplicationImpl for ExApplication {
+        fn startup(&self, application: &Self::Type) {
+            // Chain up first, GTK can only be used after the parent class
+            // handler ran.
+            self.parent_startup(application);
+
+            let window = gtk::ApplicationWindow::new(application);
+            window.set_title(Some("Application subclass"));
+            window.set_default_size(400, 300);
+
+            let files = gtk::ListBox::new();
+            let scrolled_window = gtk::ScrolledWindow::new();
+            scrolled_window.set_child(Some(&files));
+            window.set_child(Some(&scrolled_window));
+
+            *self.files.borrow_mut() = Some(files);
+        }
+
+        fn activate(&self, application: &Self::Type) {
+            if let Some(window) = application.get_active_window() {
+                window.present();
+            }
+        }
+
+        fn open(&self, application: &Self::Type, files: &[gio::File], _hint: &str) {
+            let list = self.files.borrow();
+            let list = list.as_ref().expect("open() called before startup()");
+            for file in files {
+                let uri = file.get_uri();
+                let label = gtk::Label::new(Some(uri.as_str()));
+                label.set_xalign(0.0);
+                list.append(&label);
+            }
+
+            self.activate(application);
+        }
+    }
+
+    impl GtkApplicationImpl for ExApplication {
+        fn window_added(&self, application: &Self::Type, window: &gtk::Window) {
+            println!("Window added: {:?}", window.get_title());
+            self.parent_window_added(application, window);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct ExApplication(ObjectSubclass<imp::ExApplication>)
+        @extends gio::Application, gtk::Application, @implements gio::ActionGroup, gio::ActionMap;
+}
+
+impl ExApplication {
+    pub fn new() -> Self {
+        glib::Object::new(&[
+            (
+                "application-id",
+                &"com.github.gtk-rs.examples.application_subclass",
+            ),
+            ("flags", &gio::ApplicationFlags::HANDLES_OPEN),
+        ])
+        .expect("Failed to create ExApplication")
+    }
+}
+
+fn main() {
+    // Subclasses are created through `glib::Object::new()`, which doesn't
+    // initialize GTK like `gtk::Application::new()` does.
+    gtk::init().expect("Failed to initialize GTK");
+
+    let application = ExApplication::new();
+    std::process::exit(application.upcast_ref::<gtk::Application>().run_default());
+}
@@ -0,0 +1,134 @@
+//! # Badge
+//!
+//! This example shows how to draw a custom widget, a rounded badge
+//! showing a counter, with the `gtk::Snapshot` shorthands of
+//! `SnapshotExtManual`. Clicking the badge increments the counter.
+
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{gdk, glib, graphene};
+use std::cell::Cell;
+
+const PADDING: i32 = 6;
+
+mod imp {
+    use super::*;
+    use glib::subclass;
+
+    #[derive(Debug, Default)]
+    pub struct Badge {
+        pub count: Cell<u32>,
+    }
+
+    impl ObjectSubclass for Badge {
+        const NAME: &'static str = "ExBadge";
+        type Type = super::Badge;
+        type ParentType = gtk::Widget;
+        type Instance = subclass::simple::InstanceStruct<Self>;
+        type Class = subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for Badge {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let gesture = gtk::GestureClick::new();
+            gesture.connect_released(|gesture, _, _, _| {
+                gesture.set_state(gtk::EventSequenceState::Claimed);
+                let badge = gesture.get_widget().unwrap();
+                let imp = Badge::from_instance(badge.downcast_ref::<super::Badge>().unwrap());
+                imp.count.set(imp.count.get() + 1);
+                badge.queue_resize();
+            });
+            obj.add_controller(&gesture);
+        }
+    }
+
+    impl WidgetImpl for Badge {
+        fn measure_for_size(
+            &self,
+            widget: &Self::Type,
+            orientation: gtk::Orientation,
+            _for_size: i32,
+        ) -> MeasureResult {
+            let layout = widget.create_pango_layout(Some(&self.count.get().to_string()));
+            let (width, height) = layout.get_pixel_size();
+            let size = match orientation {
+                // Never narrower than high, so that small counts are circles.
+                gtk::Orientation::Horizontal => width.max(height),
+                _ => height,
+            } + 2 * PADDING;
+            MeasureResult::new(size, size)
+        }
+
+        fn snapshot(&self, widget: &Self::Type, snapshot: &gtk::Snapshot) {
+            let width = widget.get_width() as f32;
+            let height = widget.get_height() as f32;
+            let layout = widget.create_pango_layout(Some(&self.count.get().to_string()));
+            let (text_width, text_height) = layout.get_pixel_size();
+
+            snapshot.push_rounded_clip_rect(
+                &graphene::Rect::new(0.0, 0.0, width, height),
+                height / 2.0,
+            );
+            snapshot.append_color_rect(
+                &gdk::RGBA {
+                    red: 0.88,
+                    green: 0.11,
+                    blue: 0.14,
+                    alpha: 1.0,
+                },
+                0.0,
+                0.0,
+                width,
+                height,
+            );
+            snapshot.pop();
+
+            snapshot.with_state(|snapshot| {
+                snapshot.translate_xy(
+                    (width - text_width as f32) / 2.0,
+                    (height - text_height as f32) / 2.0,
+                );
+                snapshot.append_layout(&layout, &gdk::RGBA::WHITE);
+            });
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct Badge(ObjectSubclass<imp::Badge>)
+        @extends gtk::Widget;
+}
+
+impl Badge {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create Badge")
+    }
+}
+
+fn main() {
+    let application =
+        gtk::Application::new(Some("com.github.gtk-rs.examples.badge"), Default::default())
+            .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        let window = gtk::ApplicationWindow::new(app);
+        window.set_title(Some("Badge"));
+        window.set_default_size(200, 100);
+
+        let badge = Badge::new();
+        badge.set_halign(gtk::Align::Center);
+        badge.set_valign(gtk::Align::Center);
+        window.set_child(Some(&badge));
+        window.show();
+    });
+
+    std::process::exit(application.run_default());
+}
@@ -5,8 +5,6 @@
 //! actions.
 use gtk::prelude::*;
 
-use std::env::args;
-
 fn build_ui(application: &gtk::Application) {
     let window = gtk::ApplicationWindow::new(application);
 
@@ -29,5 +27,5 @@ fn main() {
         build_ui(app);
     });
 
-    application.run(&args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
@@ -5,17 +5,15 @@
 use gtk::prelude::*;
 use gtk::{Application, ApplicationWindow, Builder, Button, MessageDialog, ResponseType};
 
-use std::env::args;
-
 fn build_ui(application: &Application) {
     let ui_src = include_str!("ui/builder_basics.ui");
-    let builder = Builder::from_string(ui_src);
+    let builder = Builder::from_string(ui_src).expect("Couldn't build the UI");
 
-    let window: ApplicationWindow = builder.get_object("window").expect("Couldn't get window");
+    let window: ApplicationWindow = builder.try_object("window").expect("Couldn't get window");
     window.set_application(Some(application));
-    let bigbutton: Button = builder.get_object("button").expect("Couldn't get button");
+    let bigbutton: Button = builder.try_object("button").expect("Couldn't get button");
     let dialog: MessageDialog = builder
-        .get_object("messagedialog")
+        .try_object("messagedialog")
         .expect("Couldn't get messagedialog");
 
     dialog.connect_response(move |d: &MessageDialog, _: ResponseType| {
@@ -40,5 +38,5 @@ fn main() {
         build_ui(app);
     });
 
-    application.run(&args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
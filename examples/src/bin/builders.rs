@@ -7,8 +7,6 @@
 use gtk::prelude::*;
 use gtk::{Align, Application, ApplicationWindowBuilder, ButtonBuilder};
 
-use std::env::args;
-
 fn build_ui(application: &Application) {
     let window = ApplicationWindowBuilder::new()
         .application(application)
@@ -41,5 +39,5 @@ fn main() {
         build_ui(app);
     });
 
-    application.run(&args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
@@ -0,0 +1,135 @@
+//! # Canvas Gestures Sample
+//!
+//! This sample demonstrates how to pan, zoom and rotate the contents of a
+//! custom widget with `gtk::gestures::CanvasGestures`: drag to pan, pinch
+//! or Control and scroll to zoom, and double click to reset the grid.
+
+use gtk::gestures::CanvasGestures;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{gdk, glib, graphene};
+use std::cell::RefCell;
+
+const GRID_SIZE: f32 = 1000.0;
+const CELL_SIZE: f32 = 50.0;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct Grid {
+        pub gestures: RefCell<Option<CanvasGestures>>,
+    }
+
+    impl ObjectSubclass for Grid {
+        const NAME: &'static str = "ExCanvasGrid";
+        type Type = super::Grid;
+        type ParentType = gtk::Widget;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for Grid {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let gestures = CanvasGestures::new(obj);
+            gestures.set_scale_range(0.2, 5.0);
+            gestures.connect_transform_changed(glib::clone!(@weak obj => move |_| {
+                obj.queue_draw();
+            }));
+            self.gestures.replace(Some(gestures));
+        }
+
+        fn dispose(&self, _obj: &Self::Type) {
+            // Removes the controllers of the gestures from the widget.
+            self.gestures.replace(None);
+        }
+    }
+
+    impl WidgetImpl for Grid {
+        fn snapshot(&self, widget: &Self::Type, snapshot: &gtk::Snapshot) {
+            let transform = match self.gestures.borrow().as_ref() {
+                Some(gestures) => gestures.get_transform(),
+                None => return,
+            };
+
+            // The grid is drawn in its own coordinates, clipped to the
+            // widget.
+            let width = widget.get_width() as f32;
+            let height = widget.get_height() as f32;
+            snapshot.push_clip(&graphene::Rect::new(0.0, 0.0, width, height));
+            snapshot.save();
+            snapshot.transform(Some(&transform.to_transform()));
+
+            let background = gdk::RGBA {
+                red: 0.95,
+                green: 0.95,
+                blue: 0.9,
+                alpha: 1.0,
+            };
+            snapshot.append_color(
+                &background,
+                &graphene::Rect::new(0.0, 0.0, GRID_SIZE, GRID_SIZE),
+            );
+            let line = gdk::RGBA {
+                red: 0.2,
+                green: 0.4,
+                blue: 0.8,
+                alpha: 1.0,
+            };
+            let cells = (GRID_SIZE / CELL_SIZE) as i32;
+            for i in 0..=cells {
+                let position = i as f32 * CELL_SIZE;
+                snapshot.append_color(
+                    &line,
+                    &graphene::Rect::new(position - 1.0, 0.0, 2.0, GRID_SIZE),
+                );
+                snapshot.append_color(
+                    &line,
+                    &graphene::Rect::new(0.0, position - 1.0, GRID_SIZE, 2.0),
+                );
+            }
+
+            snapshot.restore();
+            snapshot.pop();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct Grid(ObjectSubclass<imp::Grid>)
+        @extends gtk::Widget;
+}
+
+impl Grid {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create a Grid")
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Canvas Gestures"));
+    window.set_default_size(500, 500);
+    window.set_child(Some(&Grid::new()));
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.canvas_gestures"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
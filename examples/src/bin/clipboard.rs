@@ -0,0 +1,66 @@
+//! # Clipboard Example
+//!
+//! This sample demonstrates how to copy text to the clipboard and read it
+//! back with the future returning variants of the `gdk::Clipboard` API.
+
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::prelude::*;
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Clipboard"));
+    window.set_default_size(350, 120);
+
+    let container = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    container.set_margin_top(12);
+    container.set_margin_bottom(12);
+    container.set_margin_start(12);
+    container.set_margin_end(12);
+
+    let source = gtk::Entry::new();
+    source.set_placeholder_text(Some("Text to copy"));
+    let target = gtk::Label::new(None);
+
+    let buttons = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    let copy_button = gtk::Button::with_label("Copy");
+    let paste_button = gtk::Button::with_label("Paste");
+    buttons.append(&copy_button);
+    buttons.append(&paste_button);
+
+    container.append(&source);
+    container.append(&buttons);
+    container.append(&target);
+    window.set_child(Some(&container));
+
+    copy_button.connect_clicked(clone!(@weak source => move |button| {
+        let text = source.get_text().unwrap_or_default();
+        button.get_clipboard().set_text(&text);
+    }));
+
+    paste_button.connect_clicked(clone!(@weak target => move |button| {
+        let clipboard = button.get_clipboard();
+        // The read finishes asynchronously, dropping the future before that
+        // would cancel it.
+        glib::MainContext::default().spawn_local(async move {
+            match clipboard.read_text_async_future().await {
+                Ok(Some(text)) => target.set_text(&text),
+                Ok(None) => target.set_text("The clipboard doesn't contain text"),
+                Err(err) => target.set_text(&format!("Failed to paste: {}", err)),
+            }
+        });
+    }));
+
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.clipboard"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+    std::process::exit(application.run_default());
+}
@@ -0,0 +1,136 @@
+//! # Clock Paintable Sample
+//!
+//! This sample demonstrates how to implement a `gdk::Paintable` whose
+//! contents change over time: an analog clock, drawn when GTK asks for it,
+//! and invalidated every second so the widgets showing it draw it again.
+
+use chrono::{Local, Timelike};
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{gdk, glib, graphene};
+use std::f64::consts::PI;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct ClockPaintable;
+
+    impl ObjectSubclass for ClockPaintable {
+        const NAME: &'static str = "ExClockPaintable";
+        type Type = super::ClockPaintable;
+        type ParentType = glib::Object;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn type_init(type_: &mut glib::subclass::InitializingType<Self>) {
+            type_.add_interface::<gdk::Paintable>();
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for ClockPaintable {}
+
+    impl PaintableImpl for ClockPaintable {
+        fn get_flags(&self, _paintable: &Self::Type) -> gdk::PaintableFlags {
+            // The size never changes, only the contents do.
+            gdk::PaintableFlags::SIZE
+        }
+
+        fn get_intrinsic_width(&self, _paintable: &Self::Type) -> i32 {
+            200
+        }
+
+        fn get_intrinsic_height(&self, _paintable: &Self::Type) -> i32 {
+            200
+        }
+
+        fn snapshot(
+            &self,
+            _paintable: &Self::Type,
+            snapshot: &gdk::Snapshot,
+            width: f64,
+            height: f64,
+        ) {
+            // GTK widgets draw with a `gtk::Snapshot`.
+            let snapshot = snapshot.downcast_ref::<gtk::Snapshot>().unwrap();
+            let cr = snapshot
+                .append_cairo(&graphene::Rect::new(0.0, 0.0, width as f32, height as f32))
+                .expect("Failed to draw with cairo");
+
+            let radius = width.min(height) / 2.0 - 4.0;
+            cr.translate(width / 2.0, height / 2.0);
+            cr.set_line_width(4.0);
+            cr.arc(0.0, 0.0, radius, 0.0, 2.0 * PI);
+            cr.stroke();
+
+            let now = Local::now();
+            let seconds = now.second() as f64;
+            let minutes = now.minute() as f64 + seconds / 60.0;
+            let hours = (now.hour() % 12) as f64 + minutes / 60.0;
+            // The fraction of a turn, the length and the width of each hand.
+            for &(turn, length, line_width) in &[
+                (hours / 12.0, 0.5, 6.0),
+                (minutes / 60.0, 0.8, 4.0),
+                (seconds / 60.0, 0.9, 2.0),
+            ] {
+                let angle = turn * 2.0 * PI;
+                cr.set_line_width(line_width);
+                cr.move_to(0.0, 0.0);
+                cr.line_to(
+                    angle.sin() * radius * length,
+                    -angle.cos() * radius * length,
+                );
+                cr.stroke();
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct ClockPaintable(ObjectSubclass<imp::ClockPaintable>) @implements gdk::Paintable;
+}
+
+impl ClockPaintable {
+    pub fn new() -> Self {
+        let paintable: Self = glib::Object::new(&[]).expect("Failed to create a ClockPaintable");
+        // Stops once the paintable is gone.
+        glib::timeout_add_seconds_local(
+            1,
+            glib::clone!(@weak paintable => @default-return glib::Continue(false), move || {
+                paintable.invalidate_contents();
+                glib::Continue(true)
+            }),
+        );
+        paintable
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Clock Paintable"));
+    window.set_default_size(300, 300);
+
+    let picture = gtk::Picture::new();
+    picture.set_paintable(Some(&ClockPaintable::new()));
+
+    window.set_child(Some(&picture));
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.clock_paintable"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
@@ -0,0 +1,160 @@
+//! # Code Editor Example
+//!
+//! This sample demonstrates how to subclass `gtk::TextView` and
+//! `gtk::TextBuffer`: the buffer closes the brackets that are typed, and the
+//! view highlights the line of the cursor.
+
+use gtk::prelude::*;
+use gtk::{gdk, glib};
+
+mod imp {
+    use super::*;
+    use gtk::subclass::prelude::*;
+
+    #[derive(Debug, Default)]
+    pub struct BracketBuffer;
+
+    impl ObjectSubclass for BracketBuffer {
+        const NAME: &'static str = "ExBracketBuffer";
+        type Type = super::BracketBuffer;
+        type ParentType = gtk::TextBuffer;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for BracketBuffer {}
+
+    impl TextBufferImpl for BracketBuffer {
+        fn insert_text(&self, buffer: &Self::Type, iter: &mut gtk::TextIter, new_text: &str) {
+            let closing = match new_text {
+                "(" => ")",
+                "[" => "]",
+                "{" => "}",
+                _ => return self.parent_insert_text(buffer, iter, new_text),
+            };
+            self.parent_insert_text(buffer, iter, &format!("{}{}", new_text, closing));
+
+            // Puts the cursor between the brackets.
+            let mut cursor = iter.clone();
+            cursor.backward_char();
+            buffer.place_cursor(&cursor);
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct CodeView;
+
+    impl ObjectSubclass for CodeView {
+        const NAME: &'static str = "ExCodeView";
+        type Type = super::CodeView;
+        type ParentType = gtk::TextView;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for CodeView {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            obj.set_monospace(true);
+            // The highlighted line follows the cursor.
+            obj.get_buffer().connect_property_cursor_position_notify(
+                glib::clone!(@weak obj => move |_| obj.queue_draw()),
+            );
+        }
+    }
+
+    impl WidgetImpl for CodeView {}
+
+    impl TextViewImpl for CodeView {
+        fn create_buffer(&self, _text_view: &Self::Type) -> gtk::TextBuffer {
+            super::BracketBuffer::new().upcast()
+        }
+
+        fn snapshot_layer(
+            &self,
+            text_view: &Self::Type,
+            layer: gtk::TextViewLayer,
+            snapshot: gtk::Snapshot,
+        ) {
+            if layer == gtk::TextViewLayer::BelowText {
+                // The layers are drawn in buffer coordinates.
+                let buffer = text_view.get_buffer();
+                let cursor = buffer.get_iter_at_mark(&buffer.get_insert());
+                let (y, height) = text_view.get_line_yrange(&cursor);
+                let visible = text_view.get_visible_rect();
+                snapshot.append_color_rect(
+                    &gdk::RGBA {
+                        red: 0.5,
+                        green: 0.5,
+                        blue: 0.5,
+                        alpha: 0.15,
+                    },
+                    visible.x as f32,
+                    y as f32,
+                    visible.width as f32,
+                    height as f32,
+                );
+            }
+            self.parent_snapshot_layer(text_view, layer, snapshot)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct BracketBuffer(ObjectSubclass<imp::BracketBuffer>) @extends gtk::TextBuffer;
+}
+
+impl BracketBuffer {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create BracketBuffer")
+    }
+}
+
+glib::wrapper! {
+    pub struct CodeView(ObjectSubclass<imp::CodeView>)
+        @extends gtk::Widget, gtk::TextView;
+}
+
+impl CodeView {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create CodeView")
+    }
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.code_editor"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        let window = gtk::ApplicationWindow::new(app);
+        window.set_title(Some("Code Editor"));
+        window.set_default_size(400, 300);
+
+        let code_view = CodeView::new();
+        code_view.get_buffer().set_text("fn main() {\n    \n}\n");
+
+        let scrolled_window = gtk::ScrolledWindow::new();
+        scrolled_window.set_child(Some(&code_view));
+        window.set_child(Some(&scrolled_window));
+
+        window.show();
+    });
+
+    std::process::exit(application.run_default());
+}
@@ -0,0 +1,210 @@
+//! # Color Stream Sample
+//!
+//! This sample demonstrates how to implement a `gtk::MediaStream`, to play
+//! frames that don't come from a file in a `gtk::Video`. The "video" fades
+//! through the colors of the rainbow, with one `gdk::MemoryTexture` per
+//! frame.
+
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{gdk, glib};
+use std::cell::{Cell, RefCell};
+
+const WIDTH: i32 = 320;
+const HEIGHT: i32 = 240;
+// In microseconds, like the timestamps of the streams.
+const DURATION: i64 = 10_000_000;
+// In milliseconds, 25 frames per second.
+const FRAME_INTERVAL: u32 = 40;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct ColorStream {
+        pub frame: RefCell<Option<gdk::Texture>>,
+        // Whether a timeout is running to play the frames.
+        pub ticking: Cell<bool>,
+    }
+
+    impl ObjectSubclass for ColorStream {
+        const NAME: &'static str = "ExColorStream";
+        type Type = super::ColorStream;
+        type ParentType = gtk::MediaStream;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn type_init(type_: &mut glib::subclass::InitializingType<Self>) {
+            // `gtk::MediaStream` implements `gdk::Paintable` without drawing
+            // anything, the streams draw their frames themselves.
+            type_.add_interface::<gdk::Paintable>();
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for ColorStream {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            obj.show_frame(0);
+            // All the frames can be generated right away, the stream is
+            // ready to be played.
+            obj.prepared(false, true, true, DURATION);
+        }
+    }
+
+    impl MediaStreamImpl for ColorStream {
+        fn play(&self, stream: &Self::Type) -> bool {
+            if !self.ticking.replace(true) {
+                glib::timeout_add_local(
+                    FRAME_INTERVAL,
+                    glib::clone!(@weak stream => @default-return glib::Continue(false), move || {
+                        glib::Continue(stream.tick())
+                    }),
+                );
+            }
+            // Tells that the playback started.
+            true
+        }
+
+        fn pause(&self, _stream: &Self::Type) {
+            // The timeout stops on its next tick.
+        }
+
+        fn seek(&self, stream: &Self::Type, timestamp: i64) {
+            stream.seek_success();
+            stream.show_frame(timestamp);
+        }
+    }
+
+    impl PaintableImpl for ColorStream {
+        fn get_current_image(&self, _paintable: &Self::Type) -> gdk::Paintable {
+            self.frame.borrow().as_ref().unwrap().clone().upcast()
+        }
+
+        fn get_flags(&self, _paintable: &Self::Type) -> gdk::PaintableFlags {
+            // All the frames have the same size.
+            gdk::PaintableFlags::SIZE
+        }
+
+        fn get_intrinsic_width(&self, _paintable: &Self::Type) -> i32 {
+            WIDTH
+        }
+
+        fn get_intrinsic_height(&self, _paintable: &Self::Type) -> i32 {
+            HEIGHT
+        }
+
+        fn snapshot(
+            &self,
+            _paintable: &Self::Type,
+            snapshot: &gdk::Snapshot,
+            width: f64,
+            height: f64,
+        ) {
+            if let Some(frame) = self.frame.borrow().as_ref() {
+                frame.snapshot(snapshot, width, height);
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct ColorStream(ObjectSubclass<imp::ColorStream>)
+        @extends gtk::MediaStream, @implements gdk::Paintable;
+}
+
+impl ColorStream {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create a ColorStream")
+    }
+
+    // Plays the next frame, returns whether to keep playing.
+    fn tick(&self) -> bool {
+        let self_ = imp::ColorStream::from_instance(self);
+        if !self.get_playing() {
+            self_.ticking.set(false);
+            return false;
+        }
+
+        let mut timestamp = self.get_timestamp() + FRAME_INTERVAL as i64 * 1000;
+        if timestamp >= DURATION {
+            if !self.get_loop() {
+                self.show_frame(DURATION);
+                // Also pauses the stream.
+                self.ended();
+                self_.ticking.set(false);
+                return false;
+            }
+            timestamp %= DURATION;
+        }
+        self.show_frame(timestamp);
+        true
+    }
+
+    fn show_frame(&self, timestamp: i64) {
+        let self_ = imp::ColorStream::from_instance(self);
+        let [r, g, b] = color_at(timestamp as f64 / DURATION as f64);
+        let data = [r, g, b].repeat((WIDTH * HEIGHT) as usize);
+        let frame = gdk::MemoryTexture::new(
+            WIDTH,
+            HEIGHT,
+            gdk::MemoryFormat::R8g8b8,
+            &glib::Bytes::from_owned(data),
+            WIDTH as usize * 3,
+        );
+        self_.frame.replace(Some(frame.upcast()));
+
+        // Sets the timestamp of the stream, and tells the widgets to draw
+        // the new frame.
+        self.update(timestamp);
+        self.invalidate_contents();
+    }
+}
+
+// The color of the rainbow at `position`, between 0 and 1.
+fn color_at(position: f64) -> [u8; 3] {
+    let hue = position * 6.0;
+    let x = (1.0 - (hue % 2.0 - 1.0).abs()) * 255.0;
+    let x = x as u8;
+    match hue as u32 {
+        0 => [255, x, 0],
+        1 => [x, 255, 0],
+        2 => [0, 255, x],
+        3 => [0, x, 255],
+        4 => [x, 0, 255],
+        _ => [255, 0, x],
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Color Stream"));
+    window.set_default_size(WIDTH, HEIGHT);
+
+    let stream = ColorStream::new();
+    stream.set_loop(true);
+
+    let video = gtk::Video::new();
+    video.set_media_stream(Some(&stream));
+
+    window.set_child(Some(&video));
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.color_stream"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
@@ -1,6 +1,7 @@
 //! # Composite Template Example
 //!
-//! This sample demonstrates how to create a widget using GTK's composite templates.
+//! This sample demonstrates how to create a widget using GTK's composite templates
+//! and how to handle the signals declared in the template with Rust methods.
 
 use glib::subclass::prelude::*;
 use gtk::prelude::*;
@@ -12,7 +13,10 @@ mod imp {
     use gtk::subclass::prelude::*;
 
     /// The private struct, which can hold widgets and other data.
+    // The template(callbacks) attribute makes bind_template_children()
+    // also bind the handlers of the template_callbacks impl block below.
     #[derive(Debug, CompositeTemplate)]
+    #[template(callbacks)]
     pub struct ExApplicationWindow {
         // The #[template_child] attribute tells the CompositeTemplate macro
         // that a field is meant to be a child within the template.
@@ -47,7 +51,8 @@ mod imp {
         // and bind it's children. The CompositeTemplate
         // derive macro provides a convenience function
         // bind_template_children() to bind all children
-        // at once.
+        // at once, together with the handlers of the
+        // template's signals.
         fn class_init(klass: &mut Self::Class) {
             let template = include_bytes!("composite_template.ui");
             klass.set_template(template);
@@ -60,6 +65,17 @@ mod imp {
         }
     }
 
+    #[gtk::template_callbacks]
+    impl ExApplicationWindow {
+        // The handler is swapped in the template, so the first argument is
+        // the window and becomes `&self`. The emitting button comes last.
+        #[template_callback]
+        fn on_button_clicked(&self, button: gtk::Button) {
+            button.set_label("Pressed!");
+            self.subtitle.set_text("The button was pressed");
+        }
+    }
+
     impl ObjectImpl for ExApplicationWindow {
         fn constructed(&self, obj: &Self::Type) {
             obj.init_label();
@@ -104,5 +120,5 @@ fn main() {
         win.show();
     });
 
-    application.run(&std::env::args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
@@ -0,0 +1,171 @@
+//! # Constraint Layout Sample
+//!
+//! This sample demonstrates how to lay out a login form with a
+//! `gtk::ConstraintLayout`: the rows of the form are described in the
+//! Visual Format Language, the button is placed with `gtk::Constraint`s
+//! created in code.
+
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{ConstraintAttribute, ConstraintRelation, ConstraintStrength};
+use std::cell::RefCell;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct LoginForm {
+        pub children: RefCell<Vec<gtk::Widget>>,
+    }
+
+    impl ObjectSubclass for LoginForm {
+        const NAME: &'static str = "ExLoginForm";
+        type Type = super::LoginForm;
+        type ParentType = gtk::Widget;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.set_layout_manager_type::<gtk::ConstraintLayout>();
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for LoginForm {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let username_label = gtk::Label::new(Some("Username"));
+            username_label.set_xalign(1.0);
+            let username_entry = gtk::Entry::new();
+            let password_label = gtk::Label::new(Some("Password"));
+            password_label.set_xalign(1.0);
+            let password_entry = gtk::PasswordEntry::new();
+            let button = gtk::Button::with_label("Log In");
+
+            let views: Vec<(&str, gtk::Widget)> = vec![
+                ("username_label", username_label.upcast()),
+                ("username_entry", username_entry.upcast()),
+                ("password_label", password_label.upcast()),
+                ("password_entry", password_entry.upcast()),
+            ];
+            for (_, widget) in &views {
+                widget.set_parent(obj);
+            }
+            button.set_parent(obj);
+
+            let layout = obj
+                .get_layout_manager()
+                .unwrap()
+                .downcast::<gtk::ConstraintLayout>()
+                .unwrap();
+
+            // The labels and the entries are aligned in two columns, `-` is
+            // the default spacing.
+            let targets: Vec<(&str, &gtk::ConstraintTarget)> = views
+                .iter()
+                .map(|(name, widget)| (*name, widget.upcast_ref()))
+                .collect();
+            layout
+                .add_constraints_from_description(
+                    &[
+                        "H:|-[username_label(==password_label)]-[username_entry(>=200)]-|",
+                        "H:|-[password_label]-[password_entry(==username_entry)]-|",
+                        "V:|-[username_label(==username_entry)]-[password_label(==password_entry)]",
+                        "V:|-[username_entry]-[password_entry]",
+                    ],
+                    12,
+                    6,
+                    &targets,
+                )
+                .expect("Invalid layout description");
+
+            // The button goes below the entries, at the end of the form.
+            let password_entry = &views[3].1;
+            for constraint in &[
+                gtk::Constraint::new(
+                    Some(&button),
+                    ConstraintAttribute::Top,
+                    ConstraintRelation::Eq,
+                    Some(password_entry),
+                    ConstraintAttribute::Bottom,
+                    1.0,
+                    12.0,
+                    ConstraintStrength::Required,
+                ),
+                gtk::Constraint::new(
+                    Some(&button),
+                    ConstraintAttribute::End,
+                    ConstraintRelation::Eq,
+                    Some(password_entry),
+                    ConstraintAttribute::End,
+                    1.0,
+                    0.0,
+                    ConstraintStrength::Required,
+                ),
+                // `None` is the form itself.
+                gtk::Constraint::new(
+                    Some(&button),
+                    ConstraintAttribute::Bottom,
+                    ConstraintRelation::Le,
+                    gtk::NONE_WIDGET,
+                    ConstraintAttribute::Bottom,
+                    1.0,
+                    -12.0,
+                    ConstraintStrength::Required,
+                ),
+            ] {
+                layout.add_constraint(constraint);
+            }
+
+            let mut children: Vec<gtk::Widget> =
+                views.into_iter().map(|(_, widget)| widget).collect();
+            children.push(button.upcast());
+            self.children.replace(children);
+        }
+
+        fn dispose(&self, _obj: &Self::Type) {
+            for child in self.children.borrow_mut().drain(..) {
+                child.unparent();
+            }
+        }
+    }
+
+    impl WidgetImpl for LoginForm {}
+}
+
+glib::wrapper! {
+    pub struct LoginForm(ObjectSubclass<imp::LoginForm>)
+        @extends gtk::Widget;
+}
+
+impl LoginForm {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create a LoginForm")
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Constraint Layout"));
+    window.set_child(Some(&LoginForm::new()));
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.constraint_layout"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
@@ -4,8 +4,6 @@
 
 use gtk::prelude::*;
 
-use std::env::args;
-
 use gtk::gdk::Display;
 use gtk::{
     Application, ApplicationWindow, Box as Box_, Button, ComboBoxText, CssProvider, Entry,
@@ -92,5 +90,5 @@ fn main() {
         build_ui(app);
     });
 
-    application.run(&args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
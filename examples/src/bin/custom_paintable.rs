@@ -6,7 +6,6 @@
 use gtk::prelude::*;
 use gtk::subclass::prelude::*;
 use gtk::{gdk, glib, graphene, gsk};
-use std::env::args;
 
 mod imp {
     use super::*;
@@ -61,7 +60,7 @@ mod imp {
                 &graphene::Point::new(0f32, 0f32),
                 &graphene::Point::new(width as f32, height as f32),
                 &[
-                    gsk::ColorStop::new(0.0, gdk::RGBA::red()),
+                    gsk::ColorStop::new(0.0, gdk::RGBA::RED),
                     gsk::ColorStop::new(
                         0.15,
                         gdk::RGBA {
@@ -80,8 +79,8 @@ mod imp {
                             alpha: 1.0,
                         },
                     ),
-                    gsk::ColorStop::new(0.45, gdk::RGBA::green()),
-                    gsk::ColorStop::new(0.6, gdk::RGBA::blue()),
+                    gsk::ColorStop::new(0.45, gdk::RGBA::GREEN),
+                    gsk::ColorStop::new(0.6, gdk::RGBA::BLUE),
                     gsk::ColorStop::new(
                         0.75,
                         gdk::RGBA {
@@ -143,5 +142,5 @@ fn main() {
         build_ui(app);
     });
 
-    application.run(&args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
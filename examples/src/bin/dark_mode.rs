@@ -0,0 +1,79 @@
+//! # Dark Mode Sample
+//!
+//! This sample demonstrates how to follow the dark theme preference of
+//! `gtk::Settings`: the switch changes the preference, and the window gets the
+//! `dark` CSS class whenever it changes, from the switch or from elsewhere.
+
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::style::{add_css, StyleProviderPriority};
+
+const CSS: &str = "
+window.dark label.status {
+    color: #8ab4f8;
+}
+
+window:not(.dark) label.status {
+    color: #1a5fb4;
+}
+";
+
+fn update_window(window: &gtk::ApplicationWindow, label: &gtk::Label, prefer_dark: bool) {
+    if prefer_dark {
+        window.add_css_class("dark");
+        label.set_text("Dark theme preferred");
+    } else {
+        window.remove_css_class("dark");
+        label.set_text("Light theme preferred");
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let settings = gtk::Settings::get_default().expect("No default settings");
+    add_css(CSS, StyleProviderPriority::Application).expect("Failed to load the CSS");
+
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Dark Mode"));
+    window.set_default_size(300, 150);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    vbox.set_valign(gtk::Align::Center);
+    vbox.set_halign(gtk::Align::Center);
+
+    let label = gtk::Label::new(None);
+    label.add_css_class("status");
+    vbox.append(&label);
+
+    let prefer_dark = settings.get_property_gtk_application_prefer_dark_theme();
+    let switch = gtk::Switch::new();
+    switch.set_active(prefer_dark);
+    switch.set_halign(gtk::Align::Center);
+    switch.connect_property_active_notify(glib::clone!(@weak settings => move |switch| {
+        settings.set_property_gtk_application_prefer_dark_theme(switch.get_active());
+    }));
+    vbox.append(&switch);
+
+    update_window(&window, &label, prefer_dark);
+    settings.connect_property_gtk_application_prefer_dark_theme_notify(
+        glib::clone!(@weak window, @weak label, @weak switch => move |settings| {
+            let prefer_dark = settings.get_property_gtk_application_prefer_dark_theme();
+            update_window(&window, &label, prefer_dark);
+            switch.set_active(prefer_dark);
+        }),
+    );
+
+    window.set_child(Some(&vbox));
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.dark_mode"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
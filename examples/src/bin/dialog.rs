@@ -6,7 +6,6 @@ use gtk::glib::clone;
 use gtk::glib::signal::Inhibit;
 use gtk::prelude::*;
 
-use std::env::args;
 use std::rc::Rc;
 
 async fn dialog<W: IsA<gtk::Window>>(window: Rc<W>) {
@@ -71,7 +70,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     application.connect_activate(build_ui);
 
-    application.run(&args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 
     Ok(())
 }
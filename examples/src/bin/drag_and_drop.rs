@@ -0,0 +1,103 @@
+//! # Drag and Drop Example
+//!
+//! This sample demonstrates how to offer data in several formats at once
+//! when dragging, with a union of `gdk::ContentProvider`s: plain text and a
+//! custom mime type. The "Serialize" button writes the custom format of the
+//! provider to a memory stream to check what a drop target would receive.
+//!
+//! The drop area accepts text, like the label above it, and files, like
+//! the ones dragged from a file manager.
+
+use gtk::glib::clone;
+use gtk::prelude::*;
+use gtk::{gdk, gio, glib};
+
+const CUSTOM_MIME_TYPE: &str = "application/x-gtk-rs-example";
+
+fn content_provider() -> gdk::ContentProvider {
+    gdk::ContentProvider::new_union(&[
+        gdk::ContentProvider::new_for_value(&"Dragged from gtk-rs".to_value()),
+        gdk::ContentProvider::for_bytes_static(CUSTOM_MIME_TYPE, b"gtk-rs custom payload"),
+    ])
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Drag and Drop"));
+    window.set_default_size(350, 120);
+
+    let container = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    container.set_margin_top(12);
+    container.set_margin_bottom(12);
+    container.set_margin_start(12);
+    container.set_margin_end(12);
+
+    let source = gtk::Label::new(Some("Drag me"));
+    let drag_source = gtk::DragSource::new();
+    drag_source.set_actions(gdk::DragAction::COPY);
+    drag_source.connect_prepare(|_, _, _| Some(content_provider()));
+    source.add_controller(&drag_source);
+
+    let drop_area = gtk::Label::new(Some("Drop text or files here"));
+    let drop_target = gtk::DropTarget::new(glib::Type::Invalid, gdk::DragAction::COPY);
+    drop_target.set_types(&[String::static_type(), gdk::FileList::static_type()]);
+    drop_target.connect_drop_text(
+        clone!(@weak drop_area => @default-return false, move |_, text, _, _| {
+            drop_area.set_text(&format!("Dropped text: {}", text));
+            true
+        }),
+    );
+    drop_target.connect_drop_files(
+        clone!(@weak drop_area => @default-return false, move |_, files, _, _| {
+            let names = files
+                .iter()
+                .filter_map(|file| file.get_basename())
+                .map(|name| name.display().to_string())
+                .collect::<Vec<_>>();
+            drop_area.set_text(&format!("Dropped files: {}", names.join(", ")));
+            true
+        }),
+    );
+    drop_area.add_controller(&drop_target);
+
+    let serialize_button = gtk::Button::with_label("Serialize");
+    let output = gtk::Label::new(None);
+
+    container.append(&source);
+    container.append(&drop_area);
+    container.append(&serialize_button);
+    container.append(&output);
+    window.set_child(Some(&container));
+
+    serialize_button.connect_clicked(clone!(@weak output => move |_| {
+        let provider = content_provider();
+        let stream = gio::MemoryOutputStream::new_resizable();
+        glib::MainContext::default().spawn_local(async move {
+            let res = provider
+                .write_mime_type_async_future(CUSTOM_MIME_TYPE, &stream, glib::PRIORITY_DEFAULT)
+                .await
+                .and_then(|_| stream.close(gio::NONE_CANCELLABLE));
+            match res {
+                Ok(()) => {
+                    let bytes = stream.steal_as_bytes();
+                    output.set_text(&String::from_utf8_lossy(&bytes));
+                }
+                Err(err) => output.set_text(&format!("Failed to serialize: {}", err)),
+            }
+        });
+    }));
+
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.drag_and_drop"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
@@ -0,0 +1,103 @@
+//! # DrawingArea Subclass Example
+//!
+//! This sample demonstrates how to subclass `gtk::DrawingArea`, override its
+//! `resize` virtual method and install a draw function for the new type.
+
+use std::cell::Cell;
+
+use gtk::prelude::*;
+use gtk::{cairo, glib};
+
+mod imp {
+    use super::*;
+    use glib::subclass;
+    use gtk::subclass::prelude::*;
+
+    #[derive(Debug, Default)]
+    pub struct ExDrawingArea {
+        /// How many times `resize` has been called.
+        pub resize_count: Cell<u32>,
+    }
+
+    impl ObjectSubclass for ExDrawingArea {
+        const NAME: &'static str = "ExDrawingArea";
+        type Type = super::ExDrawingArea;
+        type ParentType = gtk::DrawingArea;
+        type Instance = subclass::simple::InstanceStruct<Self>;
+        type Class = subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self::Type>) {
+            // The instance of GtkDrawingArea is initialized by now, the draw
+            // function has to be installed on it, the subclass doesn't
+            // replace it.
+            let area = unsafe { obj.as_ref() };
+            area.set_content_width(240);
+            area.set_content_height(240);
+            area.set_draw_func(|area, cr, width, height| {
+                let area = area.downcast_ref::<super::ExDrawingArea>().unwrap();
+                area.draw(cr, width, height);
+            });
+        }
+    }
+
+    impl ObjectImpl for ExDrawingArea {}
+
+    impl WidgetImpl for ExDrawingArea {}
+
+    impl DrawingAreaImpl for ExDrawingArea {
+        fn resize(&self, drawing_area: &Self::Type, width: i32, height: i32) {
+            self.resize_count.set(self.resize_count.get() + 1);
+            println!("Resize #{}: {}x{}", self.resize_count.get(), width, height);
+
+            // Chain up so that the default class handler still runs.
+            self.parent_resize(drawing_area, width, height);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct ExDrawingArea(ObjectSubclass<imp::ExDrawingArea>)
+        @extends gtk::Widget, gtk::DrawingArea;
+}
+
+impl ExDrawingArea {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create ExDrawingArea")
+    }
+
+    fn draw(&self, cr: &cairo::Context, width: i32, height: i32) {
+        let radius = f64::from(width.min(height)) / 2.0;
+        cr.set_source_rgb(0.2, 0.4, 0.8);
+        cr.arc(
+            f64::from(width) / 2.0,
+            f64::from(height) / 2.0,
+            radius,
+            0.0,
+            2.0 * std::f64::consts::PI,
+        );
+        cr.fill();
+    }
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.drawing_area_subclass"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        let window = gtk::ApplicationWindow::new(app);
+        window.set_title(Some("DrawingArea subclass"));
+        window.set_child(Some(&ExDrawingArea::new()));
+        window.show();
+    });
+
+    std::process::exit(application.run_default());
+}
@@ -6,8 +6,6 @@
 use gtk::glib;
 use gtk::prelude::*;
 
-use std::env::args;
-
 use glib::Type;
 use gtk::gio::SimpleAction;
 use gtk::{
@@ -106,5 +104,5 @@ fn main() {
     application.add_action(&quit);
 
     // Run the application
-    application.run(&args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
@@ -1,7 +1,6 @@
 //! # Entry: Enabling undo
 
 use gtk::prelude::*;
-use std::env::args;
 
 fn build_ui(application: &gtk::Application) {
     let window = gtk::ApplicationWindow::new(application);
@@ -38,5 +37,5 @@ fn main() {
         build_ui(app);
     });
 
-    application.run(&args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
@@ -0,0 +1,50 @@
+//! # Escape to Close Sample
+//!
+//! This sample demonstrates how to handle key presses with a
+//! `gtk::EventControllerKey`, comparing them with the `gdk::keys` constants
+//! to close the window when Escape is pressed.
+
+use gtk::gdk;
+use gtk::glib;
+use gtk::prelude::*;
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title(Some("Escape to Close"));
+    window.set_default_size(350, 70);
+
+    let label = gtk::Label::new(Some("Press Escape to close the window"));
+    window.set_child(Some(&label));
+
+    let controller = gtk::EventControllerKey::new();
+    controller.connect_key_pressed(
+        glib::clone!(@weak window, @weak label => @default-return glib::signal::Inhibit(false),
+            move |_, key, _keycode, _state| {
+                if key == gdk::keys::constants::Escape {
+                    window.close();
+                    return glib::signal::Inhibit(true);
+                }
+                if let Some(c) = key.to_unicode() {
+                    label.set_text(&format!("Pressed {:?}, Escape closes the window", c));
+                }
+                glib::signal::Inhibit(false)
+            }
+        ),
+    );
+    window.add_controller(&controller);
+
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.escape_to_close"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
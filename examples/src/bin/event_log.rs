@@ -0,0 +1,101 @@
+//! # Event Log Sample
+//!
+//! This sample demonstrates how to handle raw `gdk::Event`s with a
+//! `gtk::EventControllerLegacy`, downcasting them to their kind, like
+//! `gdk::KeyEvent`, to read their details.
+
+use gtk::prelude::*;
+use gtk::{gdk, glib};
+
+fn describe(event: &gdk::Event) -> Option<String> {
+    if let Some(event) = event.downcast_ref::<gdk::KeyEvent>() {
+        let key = event.get_keyval();
+        Some(format!(
+            "{:?} {} (keycode {}, modifiers {:?}{})",
+            event.get_event_type(),
+            key.name().as_deref().unwrap_or("unknown key"),
+            event.get_keycode(),
+            event.get_modifier_state(),
+            if event.is_modifier() {
+                ", a modifier"
+            } else {
+                ""
+            },
+        ))
+    } else if let Some(event) = event.downcast_ref::<gdk::ButtonEvent>() {
+        let (x, y) = event.get_position().unwrap_or((0.0, 0.0));
+        Some(format!(
+            "{:?} of button {} at {:.0}, {:.0}",
+            event.get_event_type(),
+            event.get_button(),
+            x,
+            y
+        ))
+    } else if let Some(event) = event.downcast_ref::<gdk::ScrollEvent>() {
+        let direction = event.get_direction();
+        if direction == gdk::ScrollDirection::Smooth {
+            let (dx, dy) = event.get_deltas();
+            Some(format!("Scroll by {:.2}, {:.2}", dx, dy))
+        } else {
+            Some(format!("Scroll {:?}", direction))
+        }
+    } else if let Some(event) = event.downcast_ref::<gdk::CrossingEvent>() {
+        Some(format!(
+            "{:?}, {:?}",
+            event.get_event_type(),
+            event.get_mode()
+        ))
+    } else if event.is::<gdk::TouchEvent>() {
+        Some(format!("{:?}", event.get_event_type()))
+    } else {
+        // Motion events would flood the log.
+        None
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Event Log"));
+    window.set_default_size(400, 300);
+
+    let label = gtk::Label::new(Some("Type, click or scroll in the window"));
+    label.set_wrap(true);
+    window.set_child(Some(&label));
+
+    let controller = gtk::EventControllerLegacy::new();
+    controller.connect_event(
+        glib::clone!(@weak label => @default-return glib::signal::Inhibit(false),
+            move |controller, event| {
+                if let Some(description) = describe(event) {
+                    label.set_text(&format!(
+                        "{}\nat {} ms on {}",
+                        description,
+                        controller.get_current_event_time(),
+                        event
+                            .get_device()
+                            .and_then(|device| device.get_name())
+                            .as_deref()
+                            .unwrap_or("no device"),
+                    ));
+                }
+                // The events still reach the other controllers.
+                glib::signal::Inhibit(false)
+            }
+        ),
+    );
+    window.add_controller(&controller);
+
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.event_log"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
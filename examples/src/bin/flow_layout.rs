@@ -0,0 +1,361 @@
+//! # Flow Layout Sample
+//!
+//! This sample demonstrates how to implement a `gtk::LayoutManager`, with a
+//! `gtk::LayoutChild` for its per child properties. The children are laid
+//! out in rows, like words in a paragraph, and the extra space of each row
+//! is shared between its children according to their "weight". The container
+//! expands when one of its children does.
+
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use std::cell::Cell;
+
+const SPACING: i32 = 6;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug)]
+    pub struct FlowLayoutChild {
+        pub weight: Cell<u32>,
+    }
+
+    static CHILD_PROPERTIES: [glib::subclass::Property; 1] =
+        [glib::subclass::Property("weight", |name| {
+            glib::ParamSpec::uint(
+                name,
+                "Weight",
+                "Share of the extra space of its row",
+                0,
+                u32::MAX,
+                1,
+                glib::ParamFlags::READWRITE,
+            )
+        })];
+
+    impl ObjectSubclass for FlowLayoutChild {
+        const NAME: &'static str = "ExFlowLayoutChild";
+        type Type = super::FlowLayoutChild;
+        type ParentType = gtk::LayoutChild;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.install_properties(&CHILD_PROPERTIES);
+        }
+
+        fn new() -> Self {
+            Self {
+                weight: Cell::new(1),
+            }
+        }
+    }
+
+    impl ObjectImpl for FlowLayoutChild {
+        fn set_property(&self, obj: &Self::Type, id: usize, value: &glib::Value) {
+            let prop = &CHILD_PROPERTIES[id];
+
+            match *prop {
+                glib::subclass::Property("weight", ..) => {
+                    self.weight.set(value.get().unwrap().unwrap());
+                    // The children only change the layout through their
+                    // layout manager.
+                    if let Some(layout_manager) = obj.get_layout_manager() {
+                        layout_manager.layout_changed();
+                    }
+                }
+                _ => unimplemented!(),
+            }
+        }
+
+        fn get_property(&self, _obj: &Self::Type, id: usize) -> glib::Value {
+            let prop = &CHILD_PROPERTIES[id];
+
+            match *prop {
+                glib::subclass::Property("weight", ..) => self.weight.get().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+    }
+
+    impl LayoutChildImpl for FlowLayoutChild {}
+
+    #[derive(Debug, Default)]
+    pub struct FlowLayout;
+
+    impl ObjectSubclass for FlowLayout {
+        const NAME: &'static str = "ExFlowLayout";
+        type Type = super::FlowLayout;
+        type ParentType = gtk::LayoutManager;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for FlowLayout {}
+
+    impl LayoutManagerImpl for FlowLayout {
+        // The default `create_layout_child` creates one of those for each
+        // child widget.
+        fn layout_child_type() -> glib::Type {
+            super::FlowLayoutChild::static_type()
+        }
+
+        fn get_request_mode(
+            &self,
+            _layout_manager: &Self::Type,
+            _widget: &gtk::Widget,
+        ) -> gtk::SizeRequestMode {
+            // The wider, the less rows.
+            gtk::SizeRequestMode::HeightForWidth
+        }
+
+        fn measure(
+            &self,
+            layout_manager: &Self::Type,
+            widget: &gtk::Widget,
+            orientation: gtk::Orientation,
+            for_size: i32,
+            minimum: &mut i32,
+            natural: &mut i32,
+            _minimum_baseline: &mut i32,
+            _natural_baseline: &mut i32,
+        ) {
+            let children = layout_manager.children(widget);
+            if orientation == gtk::Orientation::Horizontal {
+                // At worst, a single child per row.
+                *minimum = children.iter().map(|child| child.width).max().unwrap_or(0);
+                *natural = children.iter().map(|child| child.width).sum::<i32>()
+                    + SPACING * (children.len() as i32 - 1).max(0);
+            } else {
+                // Without a width, all the children fit in a single row.
+                let width = if for_size < 0 { i32::MAX } else { for_size };
+                let rows = rows(&children, width);
+                let height = rows.iter().map(|row| row_height(row)).sum::<i32>()
+                    + SPACING * (rows.len() as i32 - 1).max(0);
+                *minimum = height;
+                *natural = height;
+            }
+        }
+
+        fn allocate(
+            &self,
+            layout_manager: &Self::Type,
+            widget: &gtk::Widget,
+            width: i32,
+            _height: i32,
+            _baseline: i32,
+        ) {
+            let children = layout_manager.children(widget);
+            let mut y = 0;
+            for row in rows(&children, width) {
+                let height = row_height(row);
+                let used = row.iter().map(|child| child.width).sum::<i32>()
+                    + SPACING * (row.len() as i32 - 1);
+                let extra = (width - used).max(0) as i64;
+                let total_weight = row.iter().map(|child| child.weight as i64).sum::<i64>();
+
+                let mut x = 0;
+                for child in row {
+                    let mut child_width = child.width;
+                    if total_weight > 0 {
+                        child_width += (extra * child.weight as i64 / total_weight) as i32;
+                    }
+                    child.widget.size_allocate(
+                        &gtk::Allocation {
+                            x,
+                            y,
+                            width: child_width,
+                            height,
+                        },
+                        -1,
+                    );
+                    x += child_width + SPACING;
+                }
+                y += height + SPACING;
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct FlowContainer;
+
+    impl ObjectSubclass for FlowContainer {
+        const NAME: &'static str = "ExFlowContainer";
+        type Type = super::FlowContainer;
+        type ParentType = gtk::Widget;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.set_layout_manager_type::<super::FlowLayout>();
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for FlowContainer {
+        fn dispose(&self, obj: &Self::Type) {
+            while let Some(child) = obj.get_first_child() {
+                child.unparent();
+            }
+        }
+    }
+
+    impl WidgetImpl for FlowContainer {
+        fn compute_expand(&self, widget: &Self::Type, hexpand: &mut bool, vexpand: &mut bool) {
+            // The container expands if any of its children does.
+            let mut child = widget.get_first_child();
+            while let Some(widget) = child {
+                *hexpand |= widget.compute_expand(gtk::Orientation::Horizontal);
+                *vexpand |= widget.compute_expand(gtk::Orientation::Vertical);
+                child = widget.get_next_sibling();
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct FlowLayoutChild(ObjectSubclass<imp::FlowLayoutChild>)
+        @extends gtk::LayoutChild;
+}
+
+glib::wrapper! {
+    pub struct FlowLayout(ObjectSubclass<imp::FlowLayout>)
+        @extends gtk::LayoutManager;
+}
+
+glib::wrapper! {
+    pub struct FlowContainer(ObjectSubclass<imp::FlowContainer>)
+        @extends gtk::Widget;
+}
+
+// A child widget with the width it asks for, its height for that width, and
+// its weight.
+struct Child {
+    widget: gtk::Widget,
+    width: i32,
+    height: i32,
+    weight: u32,
+}
+
+impl FlowLayout {
+    fn children(&self, widget: &gtk::Widget) -> Vec<Child> {
+        let mut children = Vec::new();
+        let mut next = widget.get_first_child();
+        while let Some(widget) = next {
+            next = widget.get_next_sibling();
+            if !widget.should_layout() {
+                continue;
+            }
+
+            let (_, width, _, _) = widget.measure(gtk::Orientation::Horizontal, -1);
+            let (_, height, _, _) = widget.measure(gtk::Orientation::Vertical, width);
+            let weight = self
+                .get_layout_child(&widget)
+                .and_then(|child| child.downcast::<FlowLayoutChild>().ok())
+                .map(|child| imp::FlowLayoutChild::from_instance(&child).weight.get())
+                .unwrap_or(1);
+            children.push(Child {
+                widget,
+                width,
+                height,
+                weight,
+            });
+        }
+        children
+    }
+}
+
+// Splits the children into the rows fitting in `width`, with at least one
+// child per row.
+fn rows(children: &[Child], width: i32) -> Vec<&[Child]> {
+    let mut rows = Vec::new();
+    let mut start = 0;
+    let mut x = 0i64;
+    for (i, child) in children.iter().enumerate() {
+        if i > start && x + child.width as i64 > width as i64 {
+            rows.push(&children[start..i]);
+            start = i;
+            x = 0;
+        }
+        x += (child.width + SPACING) as i64;
+    }
+    if start < children.len() {
+        rows.push(&children[start..]);
+    }
+    rows
+}
+
+fn row_height(row: &[Child]) -> i32 {
+    row.iter().map(|child| child.height).max().unwrap_or(0)
+}
+
+impl FlowContainer {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create a FlowContainer")
+    }
+
+    pub fn append<P: IsA<gtk::Widget>>(&self, child: &P, weight: u32) {
+        child.set_parent(self);
+        // The layout manager creates the layout child of the widget the
+        // first time it is asked for it.
+        let layout_child = self
+            .get_layout_manager()
+            .unwrap()
+            .get_layout_child(child)
+            .unwrap();
+        layout_child
+            .set_property("weight", &weight)
+            .expect("Failed to set the weight");
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Flow Layout"));
+    window.set_default_size(400, 200);
+
+    let container = FlowContainer::new();
+    container.set_margin_top(12);
+    container.set_margin_bottom(12);
+    container.set_margin_start(12);
+    container.set_margin_end(12);
+
+    let words = "The weight of a button is its share of the extra space of its row";
+    for (i, word) in words.split(' ').enumerate() {
+        // Every third button grows, the other ones keep their size.
+        let weight = if i % 3 == 0 { 1 } else { 0 };
+        let button = gtk::Button::with_label(word);
+        // The expanding children make the container fill the window.
+        button.set_hexpand(weight > 0);
+        container.append(&button, weight);
+    }
+
+    window.set_child(Some(&container));
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.flow_layout"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
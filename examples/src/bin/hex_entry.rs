@@ -0,0 +1,170 @@
+//! # Hex Entry Example
+//!
+//! This sample demonstrates how to filter the text inserted in a custom
+//! `gtk::Editable` by overriding `EditableImpl::insert_text`. The entry only
+//! accepts hexadecimal digits, which it turns to uppercase.
+//!
+//! The text typed in the internal `gtk::Text` is not inserted in the `Text`
+//! directly; it is redirected through the entry so the same filter applies
+//! to it and to the text set programmatically.
+
+use std::cell::{Cell, RefCell};
+
+use gtk::glib;
+use gtk::prelude::*;
+
+mod imp {
+    use super::*;
+    use gtk::subclass::prelude::*;
+
+    #[derive(Debug, Default)]
+    pub struct HexEntry {
+        pub text: RefCell<Option<gtk::Text>>,
+        // Whether the text is being inserted in the delegate by the entry.
+        pub inserting: Cell<bool>,
+    }
+
+    impl ObjectSubclass for HexEntry {
+        const NAME: &'static str = "ExHexEntry";
+        type Type = super::HexEntry;
+        type ParentType = gtk::Widget;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn type_init(type_: &mut glib::subclass::InitializingType<Self>) {
+            type_.add_interface::<gtk::Editable>();
+        }
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.set_layout_manager_type::<gtk::BinLayout>();
+            klass.install_editable_properties(1);
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for HexEntry {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let text = gtk::Text::new();
+            text.set_hexpand(true);
+            text.set_parent(obj);
+            text.connect_insert_text(glib::clone!(@weak obj => move |text, new_text, position| {
+                let self_ = HexEntry::from_instance(&obj);
+                if !self_.inserting.get() {
+                    text.stop_signal_emission("insert-text");
+                    obj.insert_text(new_text, position);
+                }
+            }));
+            self.text.replace(Some(text));
+
+            obj.init_delegate();
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            if self.text.borrow().is_some() {
+                obj.finish_delegate();
+            }
+            if let Some(text) = self.text.borrow_mut().take() {
+                text.unparent();
+            }
+        }
+
+        fn set_property(&self, obj: &Self::Type, id: usize, value: &glib::Value) {
+            if !self.delegate_set_property(obj, id, value) {
+                unimplemented!();
+            }
+        }
+
+        fn get_property(&self, obj: &Self::Type, id: usize) -> glib::Value {
+            self.delegate_get_property(obj, id)
+                .expect("Unknown property")
+        }
+    }
+
+    impl WidgetImpl for HexEntry {}
+
+    impl EditableImpl for HexEntry {
+        fn insert_text(&self, editable: &Self::Type, text: &str, _length: i32, position: &mut i32) {
+            let hex = text
+                .chars()
+                .filter(char::is_ascii_hexdigit)
+                .map(|c| c.to_ascii_uppercase())
+                .collect::<String>();
+            if hex.len() != text.len() {
+                editable.error_bell();
+            }
+            if hex.is_empty() {
+                return;
+            }
+
+            // Inserts in the delegate.
+            self.inserting.set(true);
+            self.parent_insert_text(editable, &hex, hex.len() as i32, position);
+            self.inserting.set(false);
+        }
+
+        fn get_delegate(&self, _editable: &Self::Type) -> Option<gtk::Editable> {
+            self.text
+                .borrow()
+                .as_ref()
+                .map(|text| text.clone().upcast())
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct HexEntry(ObjectSubclass<imp::HexEntry>)
+        @extends gtk::Widget, @implements gtk::Editable;
+}
+
+impl HexEntry {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create HexEntry")
+    }
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.hex_entry"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        let window = gtk::ApplicationWindow::new(app);
+        window.set_title(Some("Hex Entry"));
+        window.set_default_size(300, 80);
+
+        let container = gtk::Box::new(gtk::Orientation::Vertical, 6);
+        container.set_margin_top(12);
+        container.set_margin_bottom(12);
+        container.set_margin_start(12);
+        container.set_margin_end(12);
+
+        let entry = HexEntry::new();
+        let label = gtk::Label::new(Some("Value: 0"));
+        entry.connect_changed(glib::clone!(@weak label => move |entry| {
+            let value = entry
+                .get_text()
+                .and_then(|text| u64::from_str_radix(&text, 16).ok())
+                .unwrap_or(0);
+            label.set_text(&format!("Value: {}", value));
+        }));
+        // Goes through the filter too, and shows up as "C0FFEE".
+        entry.set_text("c0ffee");
+
+        container.append(&entry);
+        container.append(&label);
+        window.set_child(Some(&container));
+
+        window.show();
+    });
+
+    std::process::exit(application.run_default());
+}
@@ -0,0 +1,42 @@
+//! # List View Example
+//!
+//! This sample demonstrates how to show a `gtk::TypedListStore` in a
+//! `gtk::ListView`, with a `gtk::SignalListItemFactory` creating a label for
+//! each row without any downcast in user code.
+
+use gtk::prelude::*;
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("List View"));
+    window.set_default_size(300, 400);
+
+    let store = (1..=1000)
+        .map(|i| gtk::StringObject::new(&format!("Row {}", i)))
+        .collect::<gtk::TypedListStore<_>>();
+
+    let factory = gtk::SignalListItemFactory::with_handlers(
+        || gtk::Label::new(None),
+        |label: &gtk::Label, item: &gtk::StringObject| label.set_text(&item.get_string()),
+    );
+
+    let selection = gtk::SingleSelection::new(Some(&*store));
+    let list_view = gtk::ListView::new(Some(&selection), Some(&factory));
+
+    let scrolled_window = gtk::ScrolledWindow::new();
+    scrolled_window.set_child(Some(&list_view));
+    window.set_child(Some(&scrolled_window));
+
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.list_view"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+    std::process::exit(application.run_default());
+}
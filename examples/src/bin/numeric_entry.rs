@@ -0,0 +1,159 @@
+//! # Numeric Entry Example
+//!
+//! This sample demonstrates how to implement `gtk::Editable` in a custom
+//! widget by delegating to an internal `gtk::Text`, the way entries are
+//! implemented in GTK. The entry only accepts digits.
+
+use std::cell::RefCell;
+
+use gtk::glib;
+use gtk::prelude::*;
+
+mod imp {
+    use super::*;
+    use gtk::subclass::prelude::*;
+
+    #[derive(Debug, Default)]
+    pub struct NumericEntry {
+        pub text: RefCell<Option<gtk::Text>>,
+    }
+
+    impl ObjectSubclass for NumericEntry {
+        const NAME: &'static str = "ExNumericEntry";
+        type Type = super::NumericEntry;
+        type ParentType = gtk::Widget;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn type_init(type_: &mut glib::subclass::InitializingType<Self>) {
+            type_.add_interface::<gtk::Editable>();
+        }
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.set_layout_manager_type::<gtk::BinLayout>();
+            // The type has no properties of its own, the ones of
+            // `gtk::Editable` start from the first id.
+            klass.install_editable_properties(1);
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for NumericEntry {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let text = gtk::Text::new();
+            text.set_hexpand(true);
+            text.set_parent(obj);
+            // Reject the insertions of anything but digits.
+            text.connect_insert_text(|text, new_text, _position| {
+                if !new_text.chars().all(|c| c.is_ascii_digit()) {
+                    text.stop_signal_emission("insert-text");
+                    text.error_bell();
+                }
+            });
+            self.text.replace(Some(text));
+
+            // Forwards the changes of the text to the `gtk::Editable` signals
+            // and properties of the entry.
+            obj.init_delegate();
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            if self.text.borrow().is_some() {
+                // Needs the delegate, before dropping it.
+                obj.finish_delegate();
+            }
+            if let Some(text) = self.text.borrow_mut().take() {
+                text.unparent();
+            }
+        }
+
+        fn set_property(&self, obj: &Self::Type, id: usize, value: &glib::Value) {
+            if !self.delegate_set_property(obj, id, value) {
+                unimplemented!();
+            }
+        }
+
+        fn get_property(&self, obj: &Self::Type, id: usize) -> glib::Value {
+            self.delegate_get_property(obj, id)
+                .expect("Unknown property")
+        }
+    }
+
+    impl WidgetImpl for NumericEntry {
+        // The entry acts as a single widget, the focus goes to the text.
+        fn grab_focus(&self, _widget: &Self::Type) -> bool {
+            match self.text.borrow().as_ref() {
+                Some(text) => text.grab_focus(),
+                None => false,
+            }
+        }
+    }
+
+    impl EditableImpl for NumericEntry {
+        // Everything else defaults to the implementation of `gtk::Editable`,
+        // which goes through the delegate.
+        fn get_delegate(&self, _editable: &Self::Type) -> Option<gtk::Editable> {
+            self.text
+                .borrow()
+                .as_ref()
+                .map(|text| text.clone().upcast())
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct NumericEntry(ObjectSubclass<imp::NumericEntry>)
+        @extends gtk::Widget, @implements gtk::Editable;
+}
+
+impl NumericEntry {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create NumericEntry")
+    }
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.numeric_entry"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        let window = gtk::ApplicationWindow::new(app);
+        window.set_title(Some("Numeric Entry"));
+        window.set_default_size(300, 80);
+
+        let container = gtk::Box::new(gtk::Orientation::Vertical, 6);
+        container.set_margin_top(12);
+        container.set_margin_bottom(12);
+        container.set_margin_start(12);
+        container.set_margin_end(12);
+
+        let entry = NumericEntry::new();
+        let label = gtk::Label::new(Some("Value: 0"));
+        entry.connect_changed(glib::clone!(@weak label => move |entry| {
+            let value = entry
+                .get_text()
+                .and_then(|text| text.parse::<u64>().ok())
+                .unwrap_or(0);
+            label.set_text(&format!("Value: {}", value));
+        }));
+        entry.set_text("42");
+
+        container.append(&entry);
+        container.append(&label);
+        window.set_child(Some(&container));
+
+        window.show();
+    });
+
+    std::process::exit(application.run_default());
+}
@@ -1,5 +1,4 @@
 use std::cell::RefCell;
-use std::env;
 
 use gtk::glib;
 use gtk::prelude::*;
@@ -51,6 +50,11 @@ mod imp {
             // The layout manager determines how child widgets are laid out.
             klass.set_layout_manager_type::<gtk::BoxLayout>();
             klass.install_properties(&PROPERTIES);
+            // Assistive technologies present the widget as a group of
+            // labels.
+            klass.set_accessible_role(gtk::AccessibleRole::Group);
+            // The name of the CSS node of the widget, for the style sheets.
+            klass.set_css_name("custom-orientable");
         }
 
         fn new() -> Self {
@@ -76,6 +80,7 @@ mod imp {
                 .downcast::<gtk::BoxLayout>()
                 .unwrap();
             layout_manager.set_spacing(6);
+            obj.update_property(&[gtk::accessible::Property::Label("Greeting")]);
             first_label.set_parent(obj);
             second_label.set_parent(obj);
             self.first_label
@@ -109,6 +114,8 @@ mod imp {
                         .downcast::<gtk::BoxLayout>()
                         .unwrap();
                     layout_manager.set_orientation(orientation);
+                    // And tell assistive technologies about it.
+                    obj.update_property(&[gtk::accessible::Property::Orientation(orientation)]);
                 }
                 _ => unimplemented!(),
             }
@@ -147,11 +154,10 @@ fn main() {
     .expect("Initialization failed...");
 
     application.connect_activate(|app| {
-        let window = gtk::ApplicationWindow::new(app);
-        let bx = gtk::Box::new(gtk::Orientation::Vertical, 6);
         let orientable = CustomOrientable::new();
-        let button = gtk::Button::with_label("Switch orientation");
+        orientable.set_halign(gtk::Align::Center);
 
+        let button = gtk::Button::with_label("Switch to vertical");
         button.connect_clicked(glib::clone!(@weak orientable => move |_| {
             match orientable.get_orientation() {
                 gtk::Orientation::Horizontal => orientable.set_orientation(gtk::Orientation::Vertical),
@@ -159,18 +165,32 @@ fn main() {
                 _ => unreachable!(),
             };
         }));
+        // Every property has a typed connector for its notifications, which
+        // are emitted when the property is set.
+        orientable.connect_property_orientation_notify(glib::clone!(@weak button => move |orientable| {
+            match orientable.get_orientation() {
+                gtk::Orientation::Horizontal => button.set_label("Switch to vertical"),
+                _ => button.set_label("Switch to horizontal"),
+            }
+        }));
 
-        orientable.set_halign(gtk::Align::Center);
+        let bx = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .spacing(6)
+            .margin_top(18)
+            .margin_bottom(18)
+            .margin_start(18)
+            .margin_end(18)
+            .build();
         bx.append(&orientable);
         bx.append(&button);
-        bx.set_margin_top(18);
-        bx.set_margin_bottom(18);
-        bx.set_margin_start(18);
-        bx.set_margin_end(18);
 
-        window.set_child(Some(&bx));
+        let window = gtk::ApplicationWindow::builder()
+            .application(app)
+            .child(&bx)
+            .build();
         window.show();
     });
 
-    application.run(&env::args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
@@ -0,0 +1,93 @@
+//! # Scroll Zoom Sample
+//!
+//! This sample demonstrates how to handle scrolling with a
+//! `gtk::EventControllerScroll`: scrolling with Control held down zooms the
+//! picture, plain scrolling is left to the `gtk::ScrolledWindow`.
+
+use gtk::prelude::*;
+use gtk::{gdk, glib};
+use std::cell::Cell;
+use std::rc::Rc;
+
+const SIZE: i32 = 256;
+const MIN_ZOOM: f64 = 0.25;
+const MAX_ZOOM: f64 = 8.0;
+// The zoom factor of a single step of the wheel.
+const ZOOM_STEP: f64 = 1.1;
+
+// A checkerboard fading from red to blue, to see how far it is zoomed.
+fn checkerboard() -> gdk::MemoryTexture {
+    let mut data = Vec::with_capacity((SIZE * SIZE * 3) as usize);
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            if (x / 32 + y / 32) % 2 == 0 {
+                let blue = (x + y) * 255 / (2 * SIZE - 2);
+                data.extend_from_slice(&[(255 - blue) as u8, 0, blue as u8]);
+            } else {
+                data.extend_from_slice(&[255, 255, 255]);
+            }
+        }
+    }
+    gdk::MemoryTexture::new(
+        SIZE,
+        SIZE,
+        gdk::MemoryFormat::R8g8b8,
+        &glib::Bytes::from_owned(data),
+        SIZE as usize * 3,
+    )
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Scroll Zoom"));
+    window.set_default_size(400, 400);
+
+    let picture = gtk::Picture::new_for_paintable(Some(&checkerboard()));
+    picture.set_size_request(SIZE, SIZE);
+
+    let scrolled_window = gtk::ScrolledWindow::new();
+    scrolled_window.set_child(Some(&picture));
+
+    let zoom = Rc::new(Cell::new(1.0));
+    let controller = gtk::EventControllerScroll::new(gtk::EventControllerScrollFlags::VERTICAL);
+    // The scrolled window handles the scroll events in the bubble phase,
+    // the controller gets them before in the capture phase.
+    controller.set_propagation_phase(gtk::PropagationPhase::Capture);
+    controller.connect_scroll(
+        glib::clone!(@weak picture, @strong zoom => @default-return glib::signal::Inhibit(false),
+            move |controller, _dx, dy| {
+                let state = controller.get_current_event_state();
+                if !state.contains(gdk::ModifierType::CONTROL_MASK) {
+                    // Not handled, the scrolled window scrolls.
+                    return glib::signal::Inhibit(false);
+                }
+
+                // `dy` is in steps of the wheel, fractional with touchpads,
+                // and positive when scrolling down, which zooms out.
+                let new_zoom = (zoom.get() * ZOOM_STEP.powf(-dy)).max(MIN_ZOOM).min(MAX_ZOOM);
+                zoom.set(new_zoom);
+                let size = (SIZE as f64 * new_zoom) as i32;
+                picture.set_size_request(size, size);
+
+                // Handled, the scrolled window doesn't scroll.
+                glib::signal::Inhibit(true)
+            }
+        ),
+    );
+    scrolled_window.add_controller(&controller);
+
+    window.set_child(Some(&scrolled_window));
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.scroll_zoom"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
@@ -0,0 +1,224 @@
+//! # Scrollable Canvas Sample
+//!
+//! This sample demonstrates how to implement `gtk::Scrollable`, for a widget
+//! bigger than the room it's given to be scrolled by a `gtk::ScrolledWindow`.
+//! The canvas can also be scrolled by dragging it, which moves the
+//! scrollbars along.
+
+use gtk::glib;
+use gtk::graphene;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use std::cell::{Cell, RefCell};
+
+const CANVAS_SIZE: f64 = 2000.0;
+const CELL_SIZE: f64 = 50.0;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug)]
+    pub struct Canvas {
+        pub hadjustment: RefCell<Option<gtk::Adjustment>>,
+        pub vadjustment: RefCell<Option<gtk::Adjustment>>,
+        pub hscroll_policy: Cell<gtk::ScrollablePolicy>,
+        pub vscroll_policy: Cell<gtk::ScrollablePolicy>,
+        // The values of the adjustments when the drag started.
+        pub drag_start: Cell<(f64, f64)>,
+    }
+
+    impl ObjectSubclass for Canvas {
+        const NAME: &'static str = "ExScrollableCanvas";
+        type Type = super::Canvas;
+        type ParentType = gtk::Widget;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn type_init(type_: &mut glib::subclass::InitializingType<Self>) {
+            type_.add_interface::<gtk::Scrollable>();
+        }
+
+        fn class_init(klass: &mut Self::Class) {
+            // The type has no properties of its own, the ones of
+            // `gtk::Scrollable` start from the first id.
+            klass.override_scrollable_properties(1);
+        }
+
+        fn new() -> Self {
+            Self {
+                hadjustment: RefCell::new(None),
+                vadjustment: RefCell::new(None),
+                hscroll_policy: Cell::new(gtk::ScrollablePolicy::Minimum),
+                vscroll_policy: Cell::new(gtk::ScrollablePolicy::Minimum),
+                drag_start: Cell::new((0.0, 0.0)),
+            }
+        }
+    }
+
+    impl ObjectImpl for Canvas {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let drag = gtk::GestureDrag::new();
+            drag.connect_drag_begin(glib::clone!(@weak obj => move |_, _, _| {
+                let self_ = Canvas::from_instance(&obj);
+                self_.drag_start.set((
+                    adjustment_value(&self_.hadjustment),
+                    adjustment_value(&self_.vadjustment),
+                ));
+            }));
+            drag.connect_drag_update(glib::clone!(@weak obj => move |_, x, y| {
+                // The contents follow the pointer, the adjustments clamp the
+                // values to the scrollable range, and tell the scrollbars.
+                let self_ = Canvas::from_instance(&obj);
+                let (start_x, start_y) = self_.drag_start.get();
+                if let Some(adjustment) = self_.hadjustment.borrow().as_ref() {
+                    adjustment.set_value(start_x - x);
+                }
+                if let Some(adjustment) = self_.vadjustment.borrow().as_ref() {
+                    adjustment.set_value(start_y - y);
+                }
+            }));
+            obj.add_controller(&drag);
+        }
+
+        fn set_property(&self, obj: &Self::Type, id: usize, value: &glib::Value) {
+            // In the order of `override_scrollable_properties`.
+            match id {
+                0 => self.set_adjustment(obj, &self.hadjustment, value.get().unwrap()),
+                1 => self.set_adjustment(obj, &self.vadjustment, value.get().unwrap()),
+                2 => self.hscroll_policy.set(value.get().unwrap().unwrap()),
+                3 => self.vscroll_policy.set(value.get().unwrap().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn get_property(&self, _obj: &Self::Type, id: usize) -> glib::Value {
+            match id {
+                0 => self.hadjustment.borrow().to_value(),
+                1 => self.vadjustment.borrow().to_value(),
+                2 => self.hscroll_policy.get().to_value(),
+                3 => self.vscroll_policy.get().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+    }
+
+    impl WidgetImpl for Canvas {
+        fn size_allocate(&self, _widget: &Self::Type, width: i32, height: i32, _baseline: i32) {
+            // The visible part of the canvas is a page of the adjustments.
+            for (adjustment, size) in &[
+                (&self.hadjustment, width as f64),
+                (&self.vadjustment, height as f64),
+            ] {
+                if let Some(adjustment) = adjustment.borrow().as_ref() {
+                    let value = adjustment.get_value().min(CANVAS_SIZE - size).max(0.0);
+                    adjustment.configure(
+                        value,
+                        0.0,
+                        CANVAS_SIZE,
+                        CELL_SIZE,
+                        size * 0.9,
+                        size.min(CANVAS_SIZE),
+                    );
+                }
+            }
+        }
+
+        fn snapshot(&self, widget: &Self::Type, snapshot: &gtk::Snapshot) {
+            let width = widget.get_width() as f32;
+            let height = widget.get_height() as f32;
+            let cr = snapshot
+                .append_cairo(&graphene::Rect::new(0.0, 0.0, width, height))
+                .expect("Failed to draw with cairo");
+
+            // A checkerboard, shifted by the scrolled distance.
+            let x = adjustment_value(&self.hadjustment);
+            let y = adjustment_value(&self.vadjustment);
+            cr.translate(-x, -y);
+            let cells = (CANVAS_SIZE / CELL_SIZE) as i32;
+            for row in 0..cells {
+                for column in 0..cells {
+                    if (row + column) % 2 == 0 {
+                        cr.set_source_rgb(0.2, 0.4, 0.8);
+                    } else {
+                        cr.set_source_rgb(0.9, 0.9, 0.9);
+                    }
+                    cr.rectangle(
+                        column as f64 * CELL_SIZE,
+                        row as f64 * CELL_SIZE,
+                        CELL_SIZE,
+                        CELL_SIZE,
+                    );
+                    cr.fill();
+                }
+            }
+        }
+    }
+
+    impl ScrollableImpl for Canvas {}
+
+    impl Canvas {
+        fn set_adjustment(
+            &self,
+            obj: &super::Canvas,
+            adjustment: &RefCell<Option<gtk::Adjustment>>,
+            new: Option<gtk::Adjustment>,
+        ) {
+            // The scrolled window gives its adjustments, the canvas is
+            // redrawn when they're moved.
+            if let Some(new) = new.as_ref() {
+                new.connect_value_changed(glib::clone!(@weak obj => move |_| {
+                    obj.queue_draw();
+                }));
+            }
+            adjustment.replace(new);
+            obj.queue_allocate();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct Canvas(ObjectSubclass<imp::Canvas>)
+        @extends gtk::Widget, @implements gtk::Scrollable;
+}
+
+impl Canvas {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create a Canvas")
+    }
+}
+
+fn adjustment_value(adjustment: &RefCell<Option<gtk::Adjustment>>) -> f64 {
+    adjustment
+        .borrow()
+        .as_ref()
+        .map(|adjustment| adjustment.get_value())
+        .unwrap_or(0.0)
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Scrollable Canvas"));
+    window.set_default_size(400, 400);
+
+    let scrolled_window = gtk::ScrolledWindow::new();
+    scrolled_window.set_child(Some(&Canvas::new()));
+
+    window.set_child(Some(&scrolled_window));
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.scrollable_canvas"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(build_ui);
+
+    std::process::exit(application.run_default());
+}
@@ -0,0 +1,101 @@
+//! # Text Search Example
+//!
+//! This sample demonstrates how to search the text of a `gtk::TextView`.
+//! Typing in the search entry selects the first match, Enter moves to the
+//! next one.
+
+use gtk::glib;
+use gtk::prelude::*;
+
+const TEXT: &str = "One fish, two fish, red fish, blue fish.
+Black fish, blue fish, old fish, new fish.
+This one has a little star.
+This one has a little car.
+Say! What a lot of fish there are.";
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(Some("Text Search"));
+    window.set_default_size(400, 250);
+
+    let search_entry = gtk::SearchEntry::new();
+    let case_sensitive = gtk::CheckButton::with_label("Match case");
+
+    let search_bar = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    search_entry.set_hexpand(true);
+    search_bar.append(&search_entry);
+    search_bar.append(&case_sensitive);
+
+    let text_view = gtk::TextView::new();
+    text_view.set_wrap_mode(gtk::WrapMode::Word);
+    text_view.get_buffer().set_text(TEXT);
+    let scrolled_window = gtk::ScrolledWindow::new();
+    scrolled_window.set_vexpand(true);
+    scrolled_window.set_child(Some(&text_view));
+
+    // Selects the first match after `from`, wrapping around at the end.
+    let search = glib::clone!(@weak text_view, @weak case_sensitive => move |search_entry: &gtk::SearchEntry, from: Option<gtk::TextIter>| {
+        let buffer = text_view.get_buffer();
+        let needle = search_entry.get_text().unwrap_or_default();
+        let flags = if case_sensitive.get_active() {
+            gtk::TextSearchFlags::empty()
+        } else {
+            gtk::TextSearchFlags::CASE_INSENSITIVE
+        };
+        let from = from.unwrap_or_else(|| buffer.get_start_iter());
+        let found = buffer
+            .find_in_range(&needle, flags, &from, &buffer.get_end_iter())
+            .next()
+            .or_else(|| buffer.find(&needle, flags).next());
+        match found {
+            Some((start, end)) => {
+                text_view.select_match(&start, &end);
+                text_view.scroll_to_match(&start);
+            }
+            None => {
+                let cursor = buffer.get_iter_at_mark(&buffer.get_insert());
+                buffer.place_cursor(&cursor);
+            }
+        }
+    });
+
+    search_entry.connect_search_changed(glib::clone!(@strong search => move |search_entry| {
+        search(search_entry, None);
+    }));
+    search_entry.connect_activate(
+        glib::clone!(@strong search, @weak text_view => move |search_entry| {
+            // Continues from the end of the selected match.
+            let buffer = text_view.get_buffer();
+            let cursor = buffer.get_iter_at_mark(&buffer.get_insert());
+            search(search_entry, Some(cursor));
+        }),
+    );
+    case_sensitive.connect_toggled(glib::clone!(@weak search_entry => move |_| {
+        search_entry.emit_by_name("search-changed", &[]).unwrap();
+    }));
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    vbox.set_margin_top(6);
+    vbox.set_margin_bottom(6);
+    vbox.set_margin_start(6);
+    vbox.set_margin_end(6);
+    vbox.append(&search_bar);
+    vbox.append(&scrolled_window);
+    window.set_child(Some(&vbox));
+
+    window.show();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.text_search"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    std::process::exit(application.run_default());
+}
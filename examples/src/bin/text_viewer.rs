@@ -5,59 +5,52 @@
 use gtk::glib;
 use gtk::prelude::*;
 
-use std::env::args;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::BufReader;
 
 use gtk::{
-    Application, ApplicationWindow, Builder, Button, FileChooserAction, FileChooserDialog,
-    ResponseType, TextView,
+    Application, ApplicationWindow, Builder, Button, FileChooserAction, FileChooserNative, TextView,
 };
 
 pub fn build_ui(application: &Application) {
     let ui_src = include_str!("ui/text_viewer.ui");
-    let builder = Builder::new();
-    builder
-        .add_from_string(ui_src)
-        .expect("Couldn't add from string");
+    let builder = Builder::from_string(ui_src).expect("Couldn't build the UI");
 
-    let window: ApplicationWindow = builder.get_object("window").expect("Couldn't get window");
+    let window: ApplicationWindow = builder.try_object("window").expect("Couldn't get window");
     window.set_application(Some(application));
     let open_button: Button = builder
-        .get_object("open_button")
-        .expect("Couldn't get builder");
+        .try_object("open_button")
+        .expect("Couldn't get open_button");
     let text_view: TextView = builder
-        .get_object("text_view")
+        .try_object("text_view")
         .expect("Couldn't get text_view");
 
     open_button.connect_clicked(glib::clone!(@weak window, @weak text_view => move |_| {
-
-        let file_chooser = FileChooserDialog::new(
+        let file_chooser = FileChooserNative::new(
             Some("Open File"),
-            Some(&window),
+            None::<&ApplicationWindow>,
             FileChooserAction::Open,
-            &[("Open", ResponseType::Ok), ("Cancel", ResponseType::Cancel)],
+            Some("Open"),
+            Some("Cancel"),
         );
 
-        file_chooser.connect_response(move |d: &FileChooserDialog, response: ResponseType| {
-            if response == ResponseType::Ok {
-                let file = d.get_file().expect("Couldn't get file");
-
-                let filename = file.get_path().expect("Couldn't get file path");
-                let file = File::open(&filename.as_path()).expect("Couldn't open file");
+        glib::MainContext::default().spawn_local(async move {
+            // The future keeps the chooser alive until it is closed.
+            let file = match file_chooser.open_future(Some(&window)).await {
+                Ok(file) => file,
+                Err(_) => return,
+            };
 
-                let mut reader = BufReader::new(file);
-                let mut contents = String::new();
-                let _ = reader.read_to_string(&mut contents);
+            let filename = file.get_path().expect("Couldn't get file path");
+            let file = File::open(&filename.as_path()).expect("Couldn't open file");
 
-                text_view.get_buffer().set_text(&contents);
-            }
+            let mut reader = BufReader::new(file);
+            let mut contents = String::new();
+            let _ = reader.read_to_string(&mut contents);
 
-            d.close();
+            text_view.get_buffer().set_text(&contents);
         });
-
-        file_chooser.show();
     }));
 
     window.show();
@@ -74,5 +67,5 @@ fn main() {
         build_ui(app);
     });
 
-    application.run(&args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
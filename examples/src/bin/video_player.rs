@@ -1,6 +1,8 @@
 //! # Video Player
 //!
-//! This sample demonstrates how to use GtkVideo to play videos
+//! This sample demonstrates how to use GtkVideo to play videos, how to follow
+//! the position of its `gtk::MediaStream` with a slider, and how to add a
+//! stateful action to a window with `gtk::gio_actions`.
 
 use glib::clone;
 use glib::subclass::prelude::*;
@@ -15,6 +17,8 @@ mod imp {
     pub struct VideoPlayerWindow {
         #[template_child(id = "video")]
         pub video: TemplateChild<gtk::Video>,
+        #[template_child(id = "position")]
+        pub position: TemplateChild<gtk::Scale>,
         pub dialog: gtk::FileChooserNative,
     }
 
@@ -50,6 +54,7 @@ mod imp {
             Self {
                 dialog,
                 video: TemplateChild::default(),
+                position: TemplateChild::default(),
             }
         }
 
@@ -67,6 +72,7 @@ mod imp {
     impl ObjectImpl for VideoPlayerWindow {
         fn constructed(&self, obj: &Self::Type) {
             obj.init_actions();
+            obj.init_position();
             self.parent_constructed(obj);
         }
     }
@@ -102,11 +108,62 @@ impl VideoPlayerWindow {
             self_.dialog.show();
         }));
         self.add_action(&open);
+
+        // The menu shows the state of the action with a check box, and
+        // toggles it when activated.
+        let settings = gtk::Settings::get_default().expect("No default settings");
+        let dark_mode = settings.get_property_gtk_application_prefer_dark_theme();
+        gtk::gio_actions::add_stateful_action_typed(
+            self,
+            "dark-mode",
+            dark_mode,
+            move |dark_mode: bool| {
+                settings.set_property_gtk_application_prefer_dark_theme(dark_mode);
+                true
+            },
+        );
+    }
+
+    fn init_position(&self) {
+        let self_ = imp::VideoPlayerWindow::from_instance(self);
+        // The value of the slider is the timestamp, in microseconds.
+        self_.position.get().connect_change_value(
+            clone!(@weak self as win => @default-return glib::signal::Inhibit(false), move |_, _, value| {
+                let self_ = imp::VideoPlayerWindow::from_instance(&win);
+                if let Some(stream) = self_.video.get().get_media_stream() {
+                    stream.seek(value as i64);
+                }
+                glib::signal::Inhibit(false)
+            }),
+        );
     }
 
     fn set_video(&self, video: gio::File) {
         let self_ = imp::VideoPlayerWindow::from_instance(self);
         self_.video.get().set_file(Some(&video));
+
+        // The video creates a new stream for each file, the handlers go
+        // away with the previous one.
+        let stream = match self_.video.get().get_media_stream() {
+            Some(stream) => stream,
+            None => return,
+        };
+        // The duration is only known once the stream is prepared.
+        stream.connect_property_duration_notify(clone!(@weak self as win => move |stream| {
+            win.update_position(stream);
+        }));
+        stream.connect_property_timestamp_notify(clone!(@weak self as win => move |stream| {
+            win.update_position(stream);
+        }));
+        self.update_position(&stream);
+    }
+
+    fn update_position(&self, stream: &gtk::MediaStream) {
+        let self_ = imp::VideoPlayerWindow::from_instance(self);
+        let position = self_.position.get();
+        position.set_sensitive(stream.is_seekable());
+        position.set_range(0.0, stream.get_duration() as f64);
+        position.set_value(stream.get_timestamp() as f64);
     }
 }
 
@@ -122,5 +179,5 @@ fn main() {
         win.show();
     });
 
-    application.run(&std::env::args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
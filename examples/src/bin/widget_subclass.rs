@@ -1,5 +1,4 @@
 use std::cell::RefCell;
-use std::env;
 
 use gtk::glib;
 use gtk::prelude::*;
@@ -109,5 +108,5 @@ fn main() {
         window.show();
     });
 
-    application.run(&env::args().collect::<Vec<_>>());
+    std::process::exit(application.run_default());
 }
@@ -207,9 +207,12 @@ impl Surface {
     }
 
     #[doc(alias = "gdk_surface_set_input_region")]
-    pub fn set_input_region(&self, region: &mut cairo::Region) {
+    pub fn set_input_region(&self, region: Option<&cairo::Region>) {
         unsafe {
-            ffi::gdk_surface_set_input_region(self.to_glib_none().0, region.to_glib_none_mut().0);
+            ffi::gdk_surface_set_input_region(
+                self.to_glib_none().0,
+                mut_override(region.to_glib_none().0),
+            );
         }
     }
 
@@ -248,11 +251,13 @@ impl Surface {
         }
     }
 
-    pub fn connect_event<F: Fn(&Surface, &Event) -> bool + 'static>(
+    pub fn connect_event<F: Fn(&Surface, &Event) -> glib::signal::Inhibit + 'static>(
         &self,
         f: F,
     ) -> SignalHandlerId {
-        unsafe extern "C" fn event_trampoline<F: Fn(&Surface, &Event) -> bool + 'static>(
+        unsafe extern "C" fn event_trampoline<
+            F: Fn(&Surface, &Event) -> glib::signal::Inhibit + 'static,
+        >(
             this: *mut ffi::GdkSurface,
             event: *mut ffi::GdkEvent,
             f: glib::ffi::gpointer,
@@ -321,12 +326,12 @@ impl Surface {
         }
     }
 
-    pub fn connect_render<F: Fn(&Surface, &cairo::Region) -> bool + 'static>(
+    pub fn connect_render<F: Fn(&Surface, &cairo::Region) -> glib::signal::Inhibit + 'static>(
         &self,
         f: F,
     ) -> SignalHandlerId {
         unsafe extern "C" fn render_trampoline<
-            F: Fn(&Surface, &cairo::Region) -> bool + 'static,
+            F: Fn(&Surface, &cairo::Region) -> glib::signal::Inhibit + 'static,
         >(
             this: *mut ffi::GdkSurface,
             region: *mut cairo::ffi::cairo_region_t,
@@ -57,6 +57,11 @@ impl Clipboard {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Like [`read_async`] but returns a future resolving to the stream and
+    /// the negotiated mime type. Dropping the future cancels the read.
+    ///
+    /// [`read_async`]: #method.read_async
     pub fn read_async_future(
         &self,
         mime_types: &[&str],
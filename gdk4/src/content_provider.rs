@@ -0,0 +1,16 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::ContentProvider;
+
+impl ContentProvider {
+    // rustdoc-stripper-ignore-next
+    /// Like [`new_for_bytes`] for data living as long as the program, which
+    /// isn't copied.
+    ///
+    /// [`new_for_bytes`]: #method.new_for_bytes
+    #[doc(alias = "gdk_content_provider_new_for_bytes")]
+    pub fn for_bytes_static(mime_type: &str, data: &'static [u8]) -> ContentProvider {
+        skip_assert_initialized!();
+        Self::new_for_bytes(mime_type, &glib::Bytes::from_static(data))
+    }
+}
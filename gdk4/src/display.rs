@@ -1,8 +1,10 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{keys::Key, Display, Event, ModifierType};
+use crate::{keys::Key, Display, Event, KeymapKey, ModifierType};
 use glib::translate::*;
 use std::mem;
+use std::ptr;
+use std::slice;
 
 impl Display {
     #[doc(alias = "gdk_display_translate_key")]
@@ -39,6 +41,67 @@ impl Display {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Returns the keys of the keyboard mapping bound to the hardware
+    /// `keycode`, with their keyval.
+    #[doc(alias = "gdk_display_map_keycode")]
+    pub fn map_keycode(&self, keycode: u32) -> Option<Vec<(KeymapKey, Key)>> {
+        unsafe {
+            let mut keys = ptr::null_mut();
+            let mut keyvals = ptr::null_mut();
+            let mut n_entries = mem::MaybeUninit::uninit();
+            let ret = from_glib(ffi::gdk_display_map_keycode(
+                self.to_glib_none().0,
+                keycode,
+                &mut keys,
+                &mut keyvals,
+                n_entries.as_mut_ptr(),
+            ));
+            if ret {
+                let n_entries = n_entries.assume_init() as usize;
+                let entries = slice::from_raw_parts(keys as *const KeymapKey, n_entries)
+                    .iter()
+                    .copied()
+                    .zip(
+                        slice::from_raw_parts(keyvals, n_entries)
+                            .iter()
+                            .map(|&keyval| Key::from(keyval)),
+                    )
+                    .collect();
+                glib::ffi::g_free(keys as glib::ffi::gpointer);
+                glib::ffi::g_free(keyvals as glib::ffi::gpointer);
+                Some(entries)
+            } else {
+                None
+            }
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the keys of the keyboard mapping producing `keyval`.
+    #[doc(alias = "gdk_display_map_keyval")]
+    pub fn map_keyval(&self, keyval: Key) -> Option<Vec<KeymapKey>> {
+        unsafe {
+            let mut keys = ptr::null_mut();
+            let mut n_keys = mem::MaybeUninit::uninit();
+            let ret = from_glib(ffi::gdk_display_map_keyval(
+                self.to_glib_none().0,
+                keyval.to_glib(),
+                &mut keys,
+                n_keys.as_mut_ptr(),
+            ));
+            if ret {
+                let keymap_keys =
+                    slice::from_raw_parts(keys as *const KeymapKey, n_keys.assume_init() as usize)
+                        .to_vec();
+                glib::ffi::g_free(keys as glib::ffi::gpointer);
+                Some(keymap_keys)
+            } else {
+                None
+            }
+        }
+    }
+
     #[doc(alias = "gdk_display_put_event")]
     pub fn put_event<P: AsRef<Event>>(&self, event: &P) {
         unsafe {
@@ -34,9 +34,19 @@ impl StaticType for Event {
 }
 
 impl Event {
+    // rustdoc-stripper-ignore-next
+    /// Returns whether the event is of the kind `T`, like `KeyEvent`, as
+    /// given by its event type.
+    pub fn is<T: EventKind>(&self) -> bool {
+        T::event_types().contains(&self.get_event_type())
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Converts the event to the kind `T`, like `KeyEvent`, or returns it
+    /// back if it is of another kind.
     pub fn downcast<T: EventKind>(self) -> Result<T, Event> {
         unsafe {
-            if T::event_types().contains(&self.get_event_type()) {
+            if self.is::<T>() {
                 Ok(from_glib_full(self.to_glib_full()))
             } else {
                 Err(self)
@@ -44,9 +54,12 @@ impl Event {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Borrows the event as the kind `T`, like `KeyEvent`, or returns `None`
+    /// if it is of another kind.
     pub fn downcast_ref<T: EventKind>(&self) -> Option<&T> {
         unsafe {
-            if T::event_types().contains(&self.get_event_type()) {
+            if self.is::<T>() {
                 Some(&*(self as *const Event as *const T))
             } else {
                 None
@@ -0,0 +1,33 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use glib::translate::*;
+
+// GdkFileList is missing from the GIR data, it's a boxed GSList of GFile.
+#[allow(non_camel_case_types)]
+type GdkFileList = glib::ffi::GSList;
+
+extern "C" {
+    fn gdk_file_list_get_type() -> glib::ffi::GType;
+}
+
+glib::wrapper! {
+    // rustdoc-stripper-ignore-next
+    /// The value type of dropped or pasted files, like the ones coming from
+    /// a file manager.
+    #[derive(Debug)]
+    pub struct FileList(Boxed<GdkFileList>);
+
+    match fn {
+        copy => |ptr| glib::gobject_ffi::g_boxed_copy(gdk_file_list_get_type(), ptr as *mut _) as *mut GdkFileList,
+        free => |ptr| glib::gobject_ffi::g_boxed_free(gdk_file_list_get_type(), ptr as *mut _),
+        get_type => || gdk_file_list_get_type(),
+    }
+}
+
+impl FileList {
+    pub fn files(&self) -> Vec<gio::File> {
+        unsafe {
+            FromGlibPtrContainer::from_glib_none(self.to_glib_none().0 as *mut glib::ffi::GSList)
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::GLContext;
+
+impl GLContext {
+    // rustdoc-stripper-ignore-next
+    /// Realizes the context if needed, and makes it the current one until
+    /// the returned guard is dropped.
+    ///
+    /// The context that was current before is made current again when the
+    /// guard is dropped, or none if there was none.
+    pub fn make_current_guard(&self) -> Result<GLContextGuard, glib::Error> {
+        // Does nothing if the context is already realized.
+        self.realize()?;
+        let previous = GLContext::get_current();
+        self.make_current();
+        Ok(GLContextGuard {
+            context: self.clone(),
+            previous,
+        })
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Keeps a `GLContext` current, see [`GLContext::make_current_guard`].
+///
+/// [`GLContext::make_current_guard`]: struct.GLContext.html#method.make_current_guard
+#[derive(Debug)]
+#[must_use = "the context stops being current when the guard is dropped"]
+pub struct GLContextGuard {
+    context: GLContext,
+    previous: Option<GLContext>,
+}
+
+impl GLContextGuard {
+    pub fn context(&self) -> &GLContext {
+        &self.context
+    }
+}
+
+impl Drop for GLContextGuard {
+    fn drop(&mut self) {
+        match self.previous {
+            Some(ref previous) => previous.make_current(),
+            None => GLContext::clear_current(),
+        }
+    }
+}
@@ -5,8 +5,8 @@ use glib::translate::*;
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub struct KeymapKey {
-    group: i32,
     keycode: u32,
+    group: i32,
     level: i32,
 }
 
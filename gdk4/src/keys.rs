@@ -44,6 +44,18 @@ impl ToGlib for Key {
 }
 
 impl Key {
+    // rustdoc-stripper-ignore-next
+    /// Returns the keyval as a bare `u32`, like the ones used by the C API.
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+
+    #[doc(alias = "gdk_unicode_to_keyval")]
+    pub fn from_unicode(c: char) -> Self {
+        skip_assert_initialized!();
+        unsafe { ffi::gdk_unicode_to_keyval(c as u32) }.into()
+    }
+
     #[doc(alias = "gdk_keyval_from_name")]
     pub fn from_name(name: &str) -> Self {
         skip_assert_initialized!();
@@ -4668,3 +4680,19 @@ pub mod constants {
     pub const zerosuperior: Key = Key(ffi::GDK_KEY_zerosuperior as u32);
     pub const zstroke: Key = Key(ffi::GDK_KEY_zstroke as u32);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Key::from_unicode('a'), constants::a);
+        assert_eq!(constants::a.to_unicode(), Some('a'));
+        assert_eq!(constants::a.to_upper(), constants::A);
+        assert_eq!(constants::Escape.to_unicode(), None);
+        assert_eq!(constants::Escape.raw(), ffi::GDK_KEY_Escape as u32);
+        assert_eq!(Key::from_name("Escape"), constants::Escape);
+        assert_eq!(constants::Escape.name().as_deref(), Some("Escape"));
+    }
+}
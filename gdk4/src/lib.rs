@@ -43,17 +43,22 @@ pub mod subclass;
 mod cairo_interaction;
 mod clipboard;
 mod content_deserializer;
+mod content_provider;
 mod display;
 mod draw_context;
 mod drop;
 mod event;
+mod file_list;
 mod functions;
+mod gl_context;
 mod keymap_key;
 pub mod keys;
+mod memory_texture;
 mod popup_layout;
 mod rectangle;
 mod rgba;
 mod surface;
+mod texture;
 mod time_coord;
 mod toplevel;
 
@@ -64,7 +69,10 @@ pub use alias::*;
 pub use functions::*;
 
 pub use event::*;
+pub use file_list::FileList;
+pub use gl_context::GLContextGuard;
 pub use keymap_key::KeymapKey;
+pub use memory_texture::MemoryTextureBuffer;
 pub use popup_layout::PopupLayoutExtManual;
 pub use rectangle::Rectangle;
 pub use rgba::{RgbaParseError, RGBA};
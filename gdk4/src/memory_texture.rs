@@ -0,0 +1,145 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{MemoryFormat, MemoryTexture};
+
+impl MemoryTexture {
+    // rustdoc-stripper-ignore-next
+    /// Creates a buffer of pixels that can be modified, and turned into
+    /// textures.
+    ///
+    /// Textures are immutable, [`MemoryTextureBuffer::unmap`] creates a new
+    /// one with the current pixels, which replaces the previous texture
+    /// where it is displayed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `format` isn't a pixel format, or if `data` is too small for
+    /// `height` rows of `width` pixels, `stride` bytes apart.
+    ///
+    /// [`MemoryTextureBuffer::unmap`]: struct.MemoryTextureBuffer.html#method.unmap
+    pub fn from_bytes_mut(
+        width: i32,
+        height: i32,
+        format: MemoryFormat,
+        data: Vec<u8>,
+        stride: usize,
+    ) -> MemoryTextureBuffer {
+        assert_initialized_main_thread!();
+        assert!(width >= 0 && height >= 0, "Invalid texture size");
+        let row_size = (width as usize)
+            .checked_mul(bytes_per_pixel(format))
+            .expect("Texture size overflows");
+        assert!(stride >= row_size, "Stride smaller than a row");
+        if height > 0 {
+            let size = stride
+                .checked_mul(height as usize - 1)
+                .and_then(|size| size.checked_add(row_size))
+                .expect("Texture size overflows");
+            assert!(data.len() >= size, "Not enough data for the texture size");
+        }
+
+        let texture =
+            MemoryTexture::new(width, height, format, &glib::Bytes::from(&data[..]), stride);
+        MemoryTextureBuffer {
+            width,
+            height,
+            format,
+            data,
+            stride,
+            texture,
+        }
+    }
+}
+
+fn bytes_per_pixel(format: MemoryFormat) -> usize {
+    match format {
+        MemoryFormat::B8g8r8a8Premultiplied
+        | MemoryFormat::A8r8g8b8Premultiplied
+        | MemoryFormat::R8g8b8a8Premultiplied
+        | MemoryFormat::B8g8r8a8
+        | MemoryFormat::A8r8g8b8
+        | MemoryFormat::R8g8b8a8
+        | MemoryFormat::A8b8g8r8 => 4,
+        MemoryFormat::R8g8b8 | MemoryFormat::B8g8r8 => 3,
+        MemoryFormat::NFormats | MemoryFormat::__Unknown(_) => {
+            panic!("Invalid memory format {}", format)
+        }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Pixels that can be modified and turned into [`MemoryTexture`]s, created
+/// with [`MemoryTexture::from_bytes_mut`].
+///
+/// [`MemoryTexture`]: struct.MemoryTexture.html
+/// [`MemoryTexture::from_bytes_mut`]: struct.MemoryTexture.html#method.from_bytes_mut
+#[derive(Debug)]
+pub struct MemoryTextureBuffer {
+    width: i32,
+    height: i32,
+    format: MemoryFormat,
+    data: Vec<u8>,
+    stride: usize,
+    texture: MemoryTexture,
+}
+
+impl MemoryTextureBuffer {
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn format(&self) -> MemoryFormat {
+        self.format
+    }
+
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the pixels, the changes only show up in the texture after
+    /// [`unmap`].
+    ///
+    /// [`unmap`]: #method.unmap
+    pub fn map_writable(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a texture with a copy of the current pixels, and returns it.
+    ///
+    /// The texture returned by [`texture`] until then is left untouched, the
+    /// new one has to be given to the widgets displaying it, like with
+    /// `gtk::Picture::set_paintable`.
+    ///
+    /// [`texture`]: #method.texture
+    pub fn unmap(&mut self) -> MemoryTexture {
+        self.texture = MemoryTexture::new(
+            self.width,
+            self.height,
+            self.format,
+            &glib::Bytes::from(&self.data[..]),
+            self.stride,
+        );
+        self.texture.clone()
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the texture created by the last [`unmap`], or with the initial
+    /// pixels.
+    ///
+    /// [`unmap`]: #method.unmap
+    pub fn texture(&self) -> &MemoryTexture {
+        &self.texture
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the pixels, leaving the buffer.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
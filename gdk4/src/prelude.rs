@@ -6,6 +6,7 @@ pub use crate::auto::traits::*;
 
 pub use crate::cairo_interaction::{GdkCairoContextExt, GdkCairoSurfaceExt};
 pub use crate::draw_context::DrawContextExtManual;
+pub use crate::texture::TextureExtManual;
 #[doc(hidden)]
 pub use gdk_pixbuf::prelude::*;
 #[doc(hidden)]
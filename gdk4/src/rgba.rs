@@ -7,9 +7,17 @@ use std::hash::{Hash, Hasher};
 use std::mem;
 use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct RgbaParseError;
 
+impl fmt::Display for RgbaParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Failed to parse RGBA color")
+    }
+}
+
+impl std::error::Error for RgbaParseError {}
+
 #[derive(Clone, Copy, Debug)]
 #[repr(C)]
 pub struct RGBA {
@@ -20,56 +28,100 @@ pub struct RGBA {
 }
 
 impl RGBA {
-    // TODO: is_clear, is_opaque
+    pub const BLACK: RGBA = RGBA {
+        red: 0f32,
+        green: 0f32,
+        blue: 0f32,
+        alpha: 1f32,
+    };
 
-    pub fn black() -> RGBA {
+    pub const BLUE: RGBA = RGBA {
+        red: 0f32,
+        green: 0f32,
+        blue: 1f32,
+        alpha: 1f32,
+    };
+
+    pub const GREEN: RGBA = RGBA {
+        red: 0f32,
+        green: 1f32,
+        blue: 0f32,
+        alpha: 1f32,
+    };
+
+    pub const RED: RGBA = RGBA {
+        red: 1f32,
+        green: 0f32,
+        blue: 0f32,
+        alpha: 1f32,
+    };
+
+    pub const WHITE: RGBA = RGBA {
+        red: 1f32,
+        green: 1f32,
+        blue: 1f32,
+        alpha: 1f32,
+    };
+
+    pub const TRANSPARENT: RGBA = RGBA {
+        red: 0f32,
+        green: 0f32,
+        blue: 0f32,
+        alpha: 0f32,
+    };
+
+    pub fn new(red: f32, green: f32, blue: f32, alpha: f32) -> RGBA {
         skip_assert_initialized!();
         RGBA {
-            red: 0f32,
-            green: 0f32,
-            blue: 0f32,
-            alpha: 1f32,
+            red,
+            green,
+            blue,
+            alpha,
         }
     }
 
-    pub fn blue() -> RGBA {
+    // rustdoc-stripper-ignore-next
+    /// Creates an opaque color from 8-bit channels.
+    pub fn from_rgb_u8(red: u8, green: u8, blue: u8) -> RGBA {
         skip_assert_initialized!();
         RGBA {
-            red: 0f32,
-            green: 0f32,
-            blue: 1f32,
+            red: f32::from(red) / 255f32,
+            green: f32::from(green) / 255f32,
+            blue: f32::from(blue) / 255f32,
             alpha: 1f32,
         }
     }
 
+    // TODO: is_clear, is_opaque
+
+    #[deprecated(note = "Use `RGBA::BLACK` instead")]
+    pub fn black() -> RGBA {
+        skip_assert_initialized!();
+        RGBA::BLACK
+    }
+
+    #[deprecated(note = "Use `RGBA::BLUE` instead")]
+    pub fn blue() -> RGBA {
+        skip_assert_initialized!();
+        RGBA::BLUE
+    }
+
+    #[deprecated(note = "Use `RGBA::GREEN` instead")]
     pub fn green() -> RGBA {
         skip_assert_initialized!();
-        RGBA {
-            red: 0f32,
-            green: 1f32,
-            blue: 0f32,
-            alpha: 1f32,
-        }
+        RGBA::GREEN
     }
 
+    #[deprecated(note = "Use `RGBA::RED` instead")]
     pub fn red() -> RGBA {
         skip_assert_initialized!();
-        RGBA {
-            red: 1f32,
-            green: 0f32,
-            blue: 0f32,
-            alpha: 1f32,
-        }
+        RGBA::RED
     }
 
+    #[deprecated(note = "Use `RGBA::WHITE` instead")]
     pub fn white() -> RGBA {
         skip_assert_initialized!();
-        RGBA {
-            red: 1f32,
-            green: 1f32,
-            blue: 1f32,
-            alpha: 1f32,
-        }
+        RGBA::WHITE
     }
 }
 
@@ -219,3 +271,32 @@ impl glib::value::SetValueOptional for RGBA {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_round_trip() {
+        for s in &["rgb(255,0,0)", "rgba(0,128,255,0.5)", "rgb(18,52,86)"] {
+            let rgba = s.parse::<RGBA>().unwrap();
+            assert_eq!(rgba.to_string(), *s);
+            assert_eq!(rgba.to_string().parse::<RGBA>(), Ok(rgba));
+        }
+
+        let rgba = "#123456".parse::<RGBA>().unwrap();
+        assert_eq!(rgba.to_string(), "rgb(18,52,86)");
+        assert_eq!(
+            RGBA::from_rgb_u8(0x12, 0x34, 0x56).to_string(),
+            "rgb(18,52,86)"
+        );
+        assert_eq!("white".parse::<RGBA>(), Ok(RGBA::WHITE));
+        assert_eq!("black".parse::<RGBA>(), Ok(RGBA::BLACK));
+        assert_eq!("transparent".parse::<RGBA>(), Ok(RGBA::TRANSPARENT));
+        assert_eq!(
+            "rgba(0,0,255,0.25)".parse::<RGBA>(),
+            Ok(RGBA::new(0.0, 0.0, 1.0, 0.25))
+        );
+        assert_eq!("not a color".parse::<RGBA>(), Err(RgbaParseError));
+    }
+}
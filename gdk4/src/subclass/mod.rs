@@ -1,12 +1,32 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use glib::subclass::types::ObjectSubclass;
+use glib::translate::*;
+use glib::StaticType;
+
 pub mod paintable;
 
+// The interface of the parent type if it implements it, or else the default
+// implementation from GDK.
+pub(crate) unsafe fn parent_iface<T: ObjectSubclass, I: StaticType, F>() -> *const F {
+    let iface_type = I::static_type().to_glib();
+    let data = T::type_data();
+    let parent_iface = glib::gobject_ffi::g_type_interface_peek(
+        data.as_ref().get_parent_class() as glib::ffi::gpointer,
+        iface_type,
+    );
+    if parent_iface.is_null() {
+        glib::gobject_ffi::g_type_default_interface_peek(iface_type) as *const F
+    } else {
+        parent_iface as *const F
+    }
+}
+
 pub mod prelude {
     #[doc(hidden)]
     pub use gio::subclass::prelude::*;
     #[doc(hidden)]
     pub use glib::subclass::prelude::*;
 
-    pub use super::paintable::PaintableImpl;
+    pub use super::paintable::{PaintableImpl, PaintableImplExt};
 }
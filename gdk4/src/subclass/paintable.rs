@@ -1,94 +1,130 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::subclass::parent_iface;
 use crate::{Paintable, PaintableFlags, Snapshot};
 use glib::subclass::prelude::*;
 use glib::translate::*;
 use glib::Cast;
 
-pub trait PaintableImpl: ObjectImpl {
+// rustdoc-stripper-ignore-next
+/// The virtual methods of `GdkPaintable`.
+///
+/// The snapshot given to [`snapshot`] is a `gtk::Snapshot` when drawing in
+/// GTK widgets, it can be downcast to one to use its drawing functions:
+/// `snapshot.downcast_ref::<gtk::Snapshot>().unwrap()`.
+///
+/// [`PaintableExt::invalidate_contents`] and
+/// [`PaintableExt::invalidate_size`] have to be called when the contents
+/// or the size changes, so that the widgets showing the paintable are
+/// updated.
+///
+/// [`snapshot`]: #tymethod.snapshot
+/// [`PaintableExt::invalidate_contents`]: ../../prelude/trait.PaintableExt.html#tymethod.invalidate_contents
+/// [`PaintableExt::invalidate_size`]: ../../prelude/trait.PaintableExt.html#tymethod.invalidate_size
+pub trait PaintableImpl: PaintableImplExt + ObjectImpl {
     fn get_current_image(&self, paintable: &Self::Type) -> Paintable {
-        unsafe {
-            let type_ = ffi::gdk_paintable_get_type();
-            let iface = glib::gobject_ffi::g_type_default_interface_ref(type_)
-                as *mut ffi::GdkPaintableInterface;
-            assert!(!iface.is_null());
-
-            let ret = ((*iface).get_current_image.as_ref().unwrap())(
-                paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0,
-            );
-
-            glib::gobject_ffi::g_type_default_interface_unref(iface as glib::ffi::gpointer);
-
-            from_glib_full(ret)
-        }
+        self.parent_get_current_image(paintable)
     }
+
     fn get_flags(&self, paintable: &Self::Type) -> PaintableFlags {
-        unsafe {
-            let type_ = ffi::gdk_paintable_get_type();
-            let iface = glib::gobject_ffi::g_type_default_interface_ref(type_)
-                as *mut ffi::GdkPaintableInterface;
-            assert!(!iface.is_null());
+        self.parent_get_flags(paintable)
+    }
 
-            let ret = ((*iface).get_flags.as_ref().unwrap())(
-                paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0,
-            );
+    fn get_intrinsic_width(&self, paintable: &Self::Type) -> i32 {
+        self.parent_get_intrinsic_width(paintable)
+    }
 
-            glib::gobject_ffi::g_type_default_interface_unref(iface as glib::ffi::gpointer);
+    fn get_intrinsic_height(&self, paintable: &Self::Type) -> i32 {
+        self.parent_get_intrinsic_height(paintable)
+    }
 
-            from_glib(ret)
-        }
+    fn get_intrinsic_aspect_ratio(&self, paintable: &Self::Type) -> f64 {
+        self.parent_get_intrinsic_aspect_ratio(paintable)
     }
-    fn get_intrinsic_width(&self, paintable: &Self::Type) -> i32 {
-        unsafe {
-            let type_ = ffi::gdk_paintable_get_type();
-            let iface = glib::gobject_ffi::g_type_default_interface_ref(type_)
-                as *mut ffi::GdkPaintableInterface;
-            assert!(!iface.is_null());
 
-            let ret = ((*iface).get_intrinsic_width.as_ref().unwrap())(
-                paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0,
-            );
+    fn snapshot(&self, paintable: &Self::Type, snapshot: &Snapshot, width: f64, height: f64);
+}
 
-            glib::gobject_ffi::g_type_default_interface_unref(iface as glib::ffi::gpointer);
+pub trait PaintableImplExt: ObjectSubclass {
+    fn parent_get_current_image(&self, paintable: &Self::Type) -> Paintable;
+    fn parent_get_flags(&self, paintable: &Self::Type) -> PaintableFlags;
+    fn parent_get_intrinsic_width(&self, paintable: &Self::Type) -> i32;
+    fn parent_get_intrinsic_height(&self, paintable: &Self::Type) -> i32;
+    fn parent_get_intrinsic_aspect_ratio(&self, paintable: &Self::Type) -> f64;
+    fn parent_snapshot(&self, paintable: &Self::Type, snapshot: &Snapshot, width: f64, height: f64);
+}
 
-            ret
+impl<T: PaintableImpl> PaintableImplExt for T {
+    fn parent_get_current_image(&self, paintable: &Self::Type) -> Paintable {
+        unsafe {
+            let parent_iface = parent_iface::<T, Paintable, ffi::GdkPaintableInterface>();
+            let f = (*parent_iface)
+                .get_current_image
+                .expect("No parent iface impl for \"get_current_image\"");
+            from_glib_full(f(paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0))
         }
     }
-    fn get_intrinsic_height(&self, paintable: &Self::Type) -> i32 {
-        unsafe {
-            let type_ = ffi::gdk_paintable_get_type();
-            let iface = glib::gobject_ffi::g_type_default_interface_ref(type_)
-                as *mut ffi::GdkPaintableInterface;
-            assert!(!iface.is_null());
-
-            let ret = ((*iface).get_intrinsic_height.as_ref().unwrap())(
-                paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0,
-            );
 
-            glib::gobject_ffi::g_type_default_interface_unref(iface as glib::ffi::gpointer);
+    fn parent_get_flags(&self, paintable: &Self::Type) -> PaintableFlags {
+        unsafe {
+            let parent_iface = parent_iface::<T, Paintable, ffi::GdkPaintableInterface>();
+            let f = (*parent_iface)
+                .get_flags
+                .expect("No parent iface impl for \"get_flags\"");
+            from_glib(f(paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0))
+        }
+    }
 
-            ret
+    fn parent_get_intrinsic_width(&self, paintable: &Self::Type) -> i32 {
+        unsafe {
+            let parent_iface = parent_iface::<T, Paintable, ffi::GdkPaintableInterface>();
+            let f = (*parent_iface)
+                .get_intrinsic_width
+                .expect("No parent iface impl for \"get_intrinsic_width\"");
+            f(paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0)
         }
     }
-    fn get_intrinsic_aspect_ratio(&self, paintable: &Self::Type) -> f64 {
-        {
-            unsafe {
-                let type_ = ffi::gdk_paintable_get_type();
-                let iface = glib::gobject_ffi::g_type_default_interface_ref(type_)
-                    as *mut ffi::GdkPaintableInterface;
-                assert!(!iface.is_null());
 
-                let ret = ((*iface).get_intrinsic_aspect_ratio.as_ref().unwrap())(
-                    paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0,
-                );
+    fn parent_get_intrinsic_height(&self, paintable: &Self::Type) -> i32 {
+        unsafe {
+            let parent_iface = parent_iface::<T, Paintable, ffi::GdkPaintableInterface>();
+            let f = (*parent_iface)
+                .get_intrinsic_height
+                .expect("No parent iface impl for \"get_intrinsic_height\"");
+            f(paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0)
+        }
+    }
 
-                glib::gobject_ffi::g_type_default_interface_unref(iface as glib::ffi::gpointer);
+    fn parent_get_intrinsic_aspect_ratio(&self, paintable: &Self::Type) -> f64 {
+        unsafe {
+            let parent_iface = parent_iface::<T, Paintable, ffi::GdkPaintableInterface>();
+            let f = (*parent_iface)
+                .get_intrinsic_aspect_ratio
+                .expect("No parent iface impl for \"get_intrinsic_aspect_ratio\"");
+            f(paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0)
+        }
+    }
 
-                ret
-            }
+    fn parent_snapshot(
+        &self,
+        paintable: &Self::Type,
+        snapshot: &Snapshot,
+        width: f64,
+        height: f64,
+    ) {
+        unsafe {
+            let parent_iface = parent_iface::<T, Paintable, ffi::GdkPaintableInterface>();
+            let f = (*parent_iface)
+                .snapshot
+                .expect("No parent iface impl for \"snapshot\"");
+            f(
+                paintable.unsafe_cast_ref::<Paintable>().to_glib_none().0,
+                snapshot.to_glib_none().0,
+                width,
+                height,
+            )
         }
     }
-    fn snapshot(&self, paintable: &Self::Type, snapshot: &Snapshot, width: f64, height: f64);
 }
 
 unsafe impl<T: PaintableImpl> IsImplementable<T> for Paintable {
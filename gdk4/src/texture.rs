@@ -0,0 +1,121 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{MemoryFormat, MemoryTexture, Texture, TextureExt};
+use glib::object::{Cast, IsA};
+use glib::translate::*;
+use std::slice;
+
+impl Texture {
+    // rustdoc-stripper-ignore-next
+    /// Creates a texture with a copy of the pixels of `surface`.
+    ///
+    /// Only `cairo::Format::ARgb32` and `cairo::Format::Rgb24` surfaces are
+    /// supported, an error is returned for other formats.
+    ///
+    /// Textures are created from `gdk_pixbuf::Pixbuf`s with
+    /// [`new_for_pixbuf`].
+    ///
+    /// [`new_for_pixbuf`]: #method.new_for_pixbuf
+    pub fn from_cairo_surface(surface: &cairo::ImageSurface) -> Result<Texture, glib::BoolError> {
+        assert_initialized_main_thread!();
+        let format = surface.get_format();
+        if format != cairo::Format::ARgb32 && format != cairo::Format::Rgb24 {
+            return Err(glib::bool_error!(
+                "Unsupported cairo surface format {:?}",
+                format
+            ));
+        }
+
+        surface.flush();
+        let width = surface.get_width();
+        let height = surface.get_height();
+        let stride = surface.get_stride() as usize;
+        let mut data = unsafe {
+            let ptr = cairo::ffi::cairo_image_surface_get_data(surface.to_raw_none());
+            if ptr.is_null() {
+                return Err(glib::bool_error!("Cairo surface has no data"));
+            }
+            slice::from_raw_parts(ptr, stride * height as usize).to_vec()
+        };
+
+        // Pixels are native endian 32 bits ARGB, the alpha byte of RGB24 is
+        // undefined.
+        let alpha_offset = if cfg!(target_endian = "little") { 3 } else { 0 };
+        if format == cairo::Format::Rgb24 {
+            for row in data.chunks_mut(stride) {
+                for pixel in row[..width as usize * 4].chunks_mut(4) {
+                    pixel[alpha_offset] = 0xff;
+                }
+            }
+        }
+        let memory_format = if cfg!(target_endian = "little") {
+            MemoryFormat::B8g8r8a8Premultiplied
+        } else {
+            MemoryFormat::A8r8g8b8Premultiplied
+        };
+
+        Ok(MemoryTexture::new(
+            width,
+            height,
+            memory_format,
+            &glib::Bytes::from_owned(data),
+            stride,
+        )
+        .upcast())
+    }
+}
+
+pub trait TextureExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Downloads the texture into `data`, with rows `stride` bytes apart.
+    ///
+    /// The pixels are stored as 4 bytes premultiplied ARGB in native
+    /// endianness, like `cairo::Format::ARgb32`, which is
+    /// `MemoryFormat::B8g8r8a8Premultiplied` on little endian machines and
+    /// `MemoryFormat::A8r8g8b8Premultiplied` on big endian ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is smaller than `4 * width` or `data` is smaller
+    /// than `stride * height`.
+    #[doc(alias = "gdk_texture_download")]
+    fn download(&self, data: &mut [u8], stride: usize);
+
+    // rustdoc-stripper-ignore-next
+    /// Downloads the texture into a new buffer without padding between the
+    /// rows, see [`download`] for the format.
+    ///
+    /// [`download`]: #tymethod.download
+    fn download_to_vec(&self) -> Vec<u8>;
+}
+
+impl<O: IsA<Texture>> TextureExtManual for O {
+    fn download(&self, data: &mut [u8], stride: usize) {
+        let width = self.get_width() as usize;
+        let height = self.get_height() as usize;
+        assert!(
+            stride >= width * 4,
+            "Stride {} is too small for a texture of width {}",
+            stride,
+            width
+        );
+        assert!(
+            data.len() >= stride * height,
+            "Buffer of {} bytes is too small for a texture of height {} with stride {}",
+            data.len(),
+            height,
+            stride
+        );
+
+        unsafe {
+            ffi::gdk_texture_download(self.as_ref().to_glib_none().0, data.as_mut_ptr(), stride);
+        }
+    }
+
+    fn download_to_vec(&self) -> Vec<u8> {
+        let stride = self.get_width() as usize * 4;
+        let mut data = vec![0; stride * self.get_height() as usize];
+        self.download(&mut data, stride);
+        data
+    }
+}
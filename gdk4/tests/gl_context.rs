@@ -0,0 +1,39 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gdk4 as gdk;
+use std::env;
+
+#[test]
+fn gl_context() {
+    let display = gdk::Display::get_default()
+        .or_else(|| gdk::Display::open(&env::var("DISPLAY").unwrap_or_default()))
+        .expect("Failed to open a display");
+    let surface = gdk::Surface::new_toplevel(&display);
+
+    let context = match surface.create_gl_context() {
+        Ok(context) => context,
+        // The display doesn't support OpenGL.
+        Err(_) => return,
+    };
+    context.set_required_version(3, 3);
+    assert_eq!(context.get_required_version(), (3, 3));
+    context.set_debug_enabled(true);
+    assert!(context.get_debug_enabled());
+
+    // Headless displays may fail to create the context, the error has to be
+    // reported before anything is drawn.
+    if context.realize().is_ok() {
+        let (major, minor) = context.get_version();
+        assert!(context.get_use_es() || context.is_legacy() || (major, minor) >= (3, 3));
+
+        let guard = context.make_current_guard().unwrap();
+        assert_eq!(
+            gdk::GLContext::get_current().as_ref(),
+            Some(guard.context())
+        );
+        drop(guard);
+        assert!(gdk::GLContext::get_current().is_none());
+    }
+
+    surface.destroy();
+}
@@ -0,0 +1,60 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gdk::prelude::*;
+use gdk4 as gdk;
+use std::panic;
+
+// The format `download` returns the pixels in.
+fn native_format() -> gdk::MemoryFormat {
+    if cfg!(target_endian = "little") {
+        gdk::MemoryFormat::B8g8r8a8Premultiplied
+    } else {
+        gdk::MemoryFormat::A8r8g8b8Premultiplied
+    }
+}
+
+fn panics<F: FnOnce() + panic::UnwindSafe>(f: F) -> bool {
+    panic::catch_unwind(f).is_err()
+}
+
+// Memory textures don't need a display.
+#[test]
+fn memory_texture() {
+    // Two rows of two pixels with 4 bytes of padding after each row.
+    let mut buffer = gdk::MemoryTexture::from_bytes_mut(2, 2, native_format(), vec![0; 24], 12);
+    let initial = buffer.texture().clone();
+    assert_eq!(initial.get_width(), 2);
+    assert_eq!(initial.get_height(), 2);
+    assert_eq!(initial.download_to_vec(), vec![0; 16]);
+
+    for pixel in buffer.map_writable()[12..20].chunks_mut(4) {
+        pixel.copy_from_slice(&[0xff; 4]);
+    }
+    let texture = buffer.unmap();
+    assert_eq!(&texture, buffer.texture());
+    let mut expected = vec![0; 8];
+    expected.extend_from_slice(&[0xff; 8]);
+    assert_eq!(texture.download_to_vec(), expected);
+    // The previous texture keeps the pixels it was created with.
+    assert_eq!(initial.download_to_vec(), vec![0; 16]);
+    assert_eq!(buffer.into_data().len(), 24);
+
+    // The last row doesn't need the padding.
+    gdk::MemoryTexture::from_bytes_mut(2, 2, gdk::MemoryFormat::R8g8b8, vec![0; 14], 8);
+
+    assert!(panics(|| {
+        gdk::MemoryTexture::from_bytes_mut(2, 2, native_format(), vec![0; 15], 8);
+    }));
+    assert!(panics(|| {
+        gdk::MemoryTexture::from_bytes_mut(2, 2, native_format(), vec![0; 16], 7);
+    }));
+    assert!(panics(|| {
+        gdk::MemoryTexture::from_bytes_mut(-1, 2, native_format(), vec![0; 16], 8);
+    }));
+    assert!(panics(|| {
+        gdk::MemoryTexture::from_bytes_mut(2, 2, gdk::MemoryFormat::NFormats, vec![0; 16], 8);
+    }));
+    assert!(panics(|| {
+        gdk::MemoryTexture::from_bytes_mut(1, i32::MAX, native_format(), vec![0; 16], usize::MAX);
+    }));
+}
@@ -16,13 +16,6 @@ glib::wrapper! {
     }
 }
 
-impl RenderNode {
-    //#[doc(alias = "gsk_render_node_deserialize")]
-    //pub fn deserialize(bytes: &glib::Bytes, error_func: /*Unimplemented*/FnMut(/*Ignored*/ParseLocation, /*Ignored*/ParseLocation, &glib::Error), user_data: /*Unimplemented*/Option<Fundamental: Pointer>) -> Option<RenderNode> {
-    //    unsafe { TODO: call ffi:gsk_render_node_deserialize() }
-    //}
-}
-
 pub const NONE_RENDER_NODE: Option<&RenderNode> = None;
 
 pub trait RenderNodeExt: 'static {
@@ -36,7 +29,7 @@ pub trait RenderNodeExt: 'static {
     fn get_node_type(&self) -> RenderNodeType;
 
     #[doc(alias = "gsk_render_node_serialize")]
-    fn serialize(&self) -> Option<glib::Bytes>;
+    fn serialize(&self) -> glib::Bytes;
 
     #[doc(alias = "gsk_render_node_write_to_file")]
     fn write_to_file(&self, filename: &str) -> Result<(), glib::Error>;
@@ -71,7 +64,7 @@ impl<O: IsA<RenderNode>> RenderNodeExt for O {
         }
     }
 
-    fn serialize(&self) -> Option<glib::Bytes> {
+    fn serialize(&self) -> glib::Bytes {
         unsafe {
             from_glib_full(ffi::gsk_render_node_serialize(
                 self.as_ref().to_glib_none().0,
@@ -0,0 +1,13 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{ContainerNode, RenderNode};
+
+impl ContainerNode {
+    // rustdoc-stripper-ignore-next
+    /// Returns all the children of the container, in order.
+    pub fn get_children(&self) -> Vec<RenderNode> {
+        (0..self.get_n_children())
+            .filter_map(|idx| self.get_child(idx))
+            .collect()
+    }
+}
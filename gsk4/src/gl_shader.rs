@@ -0,0 +1,121 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{GLShader, GLUniformType, ShaderArgsBuilder};
+
+impl GLShader {
+    // rustdoc-stripper-ignore-next
+    /// Starts building the values of the uniforms of the shader, set by name.
+    pub fn uniform_builder(&self) -> GLShaderUniformBuilder {
+        GLShaderUniformBuilder::new(self)
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Builds the arguments of a [`GLShader`], as given to `GLShaderNode::new`
+/// or `gtk::Snapshot::push_gl_shader`, with the uniforms set by name.
+///
+/// The uniforms left unset are zero. Setting a uniform the shader doesn't
+/// declare, or with the wrong type, makes [`build`] fail.
+///
+/// [`GLShader`]: struct.GLShader.html
+/// [`build`]: #method.build
+#[derive(Debug)]
+pub struct GLShaderUniformBuilder {
+    shader: GLShader,
+    builder: ShaderArgsBuilder,
+    error: Option<String>,
+}
+
+impl GLShaderUniformBuilder {
+    pub fn new(shader: &GLShader) -> Self {
+        Self {
+            shader: shader.clone(),
+            builder: ShaderArgsBuilder::new(shader, None),
+            error: None,
+        }
+    }
+
+    pub fn set_float(mut self, name: &str, value: f32) -> Self {
+        if let Some(idx) = self.uniform(name, GLUniformType::Float) {
+            self.builder.set_float(idx, value);
+        }
+        self
+    }
+
+    pub fn set_int(mut self, name: &str, value: i32) -> Self {
+        if let Some(idx) = self.uniform(name, GLUniformType::Int) {
+            self.builder.set_int(idx, value);
+        }
+        self
+    }
+
+    pub fn set_uint(mut self, name: &str, value: u32) -> Self {
+        if let Some(idx) = self.uniform(name, GLUniformType::Uint) {
+            self.builder.set_uint(idx, value);
+        }
+        self
+    }
+
+    pub fn set_bool(mut self, name: &str, value: bool) -> Self {
+        if let Some(idx) = self.uniform(name, GLUniformType::Bool) {
+            self.builder.set_bool(idx, value);
+        }
+        self
+    }
+
+    pub fn set_vec2(mut self, name: &str, value: &graphene::Vec2) -> Self {
+        if let Some(idx) = self.uniform(name, GLUniformType::Vec2) {
+            self.builder.set_vec2(idx, value);
+        }
+        self
+    }
+
+    pub fn set_vec3(mut self, name: &str, value: &graphene::Vec3) -> Self {
+        if let Some(idx) = self.uniform(name, GLUniformType::Vec3) {
+            self.builder.set_vec3(idx, value);
+        }
+        self
+    }
+
+    pub fn set_vec4(mut self, name: &str, value: &graphene::Vec4) -> Self {
+        if let Some(idx) = self.uniform(name, GLUniformType::Vec4) {
+            self.builder.set_vec4(idx, value);
+        }
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the packed values of the uniforms, or the error of the first
+    /// uniform that couldn't be set.
+    #[doc(alias = "gsk_shader_args_builder_to_args")]
+    pub fn build(self) -> Result<glib::Bytes, glib::BoolError> {
+        if let Some(error) = self.error {
+            return Err(glib::bool_error!("{}", error));
+        }
+        self.builder
+            .to_args()
+            .ok_or_else(|| glib::bool_error!("Failed to pack the shader arguments"))
+    }
+
+    // Returns the index of the uniform `name` if it has the type `type_`, or
+    // keeps the error.
+    fn uniform(&mut self, name: &str, type_: GLUniformType) -> Option<i32> {
+        if self.error.is_some() {
+            return None;
+        }
+        let idx = self.shader.find_uniform_by_name(name);
+        if idx < 0 {
+            self.error = Some(format!("The shader has no uniform `{}`", name));
+            return None;
+        }
+        let uniform_type = self.shader.get_uniform_type(idx);
+        if uniform_type != type_ {
+            self.error = Some(format!(
+                "Uniform `{}` is a {:?}, not a {:?}",
+                name, uniform_type, type_
+            ));
+            return None;
+        }
+        Some(idx)
+    }
+}
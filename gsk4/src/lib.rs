@@ -44,8 +44,13 @@ pub use auto::*;
 mod border_node;
 mod color_stop;
 mod conic_gradient_node;
+mod container_node;
+mod gl_shader;
 mod linear_gradient_node;
+mod parse_location;
 mod radial_gradient_node;
+mod render_node;
+mod render_node_visitor;
 mod repeating_linear_gradient_node;
 mod repeating_radial_gradient_node;
 mod rounded_rect;
@@ -54,5 +59,9 @@ mod shadow_node;
 
 pub use border_node::BorderNodeManualExt;
 pub use color_stop::ColorStop;
+pub use gl_shader::GLShaderUniformBuilder;
+pub use parse_location::ParseLocation;
+pub use render_node::IsRenderNode;
+pub use render_node_visitor::{walk, RenderNodeVisitor};
 pub use rounded_rect::RoundedRect;
 pub use shadow::Shadow;
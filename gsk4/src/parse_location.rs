@@ -0,0 +1,40 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use glib::translate::*;
+
+// rustdoc-stripper-ignore-next
+/// A location in the text parsed by [`RenderNode::deserialize`].
+///
+/// [`RenderNode::deserialize`]: struct.RenderNode.html#method.deserialize
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ParseLocation(ffi::GskParseLocation);
+
+impl ParseLocation {
+    pub fn get_bytes(&self) -> usize {
+        self.0.bytes
+    }
+
+    pub fn get_chars(&self) -> usize {
+        self.0.chars
+    }
+
+    pub fn get_lines(&self) -> usize {
+        self.0.lines
+    }
+
+    pub fn get_line_bytes(&self) -> usize {
+        self.0.line_bytes
+    }
+
+    pub fn get_line_chars(&self) -> usize {
+        self.0.line_chars
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrNone<*const ffi::GskParseLocation> for ParseLocation {
+    unsafe fn from_glib_none(ptr: *const ffi::GskParseLocation) -> Self {
+        *(ptr as *const ParseLocation)
+    }
+}
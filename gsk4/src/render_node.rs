@@ -0,0 +1,152 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{ParseLocation, RenderNode, RenderNodeExt, RenderNodeType};
+use glib::translate::*;
+use glib::{Cast, IsA};
+
+impl RenderNode {
+    // rustdoc-stripper-ignore-next
+    /// Loads a node tree serialized with [`serialize`].
+    ///
+    /// The parser recovers from errors, this fails with the first error it
+    /// reported. Use [`deserialize_with_error_func`] to get the partially
+    /// parsed tree and the locations of all the errors instead.
+    ///
+    /// [`serialize`]: prelude/trait.RenderNodeExt.html#tymethod.serialize
+    /// [`deserialize_with_error_func`]: #method.deserialize_with_error_func
+    #[doc(alias = "gsk_render_node_deserialize")]
+    pub fn deserialize(bytes: &glib::Bytes) -> Result<RenderNode, glib::Error> {
+        let mut first_error = None;
+        let node = RenderNode::deserialize_with_error_func(bytes, |_start, _end, error| {
+            if first_error.is_none() {
+                first_error = Some(error.clone());
+            }
+        });
+
+        match (node, first_error) {
+            (Some(node), None) => Ok(node),
+            (_, Some(error)) => Err(error),
+            (None, None) => Err(glib::Error::new(
+                crate::SerializationError::InvalidData,
+                "Failed to deserialize the render node",
+            )),
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Loads a node tree serialized with [`serialize`], calling `error_func`
+    /// with the start and end location of every parsing error.
+    ///
+    /// [`serialize`]: prelude/trait.RenderNodeExt.html#tymethod.serialize
+    #[doc(alias = "gsk_render_node_deserialize")]
+    pub fn deserialize_with_error_func<F: FnMut(&ParseLocation, &ParseLocation, &glib::Error)>(
+        bytes: &glib::Bytes,
+        mut error_func: F,
+    ) -> Option<RenderNode> {
+        assert_initialized_main_thread!();
+        unsafe extern "C" fn parse_error_func<
+            F: FnMut(&ParseLocation, &ParseLocation, &glib::Error),
+        >(
+            start: *const ffi::GskParseLocation,
+            end: *const ffi::GskParseLocation,
+            error: *const glib::ffi::GError,
+            user_data: glib::ffi::gpointer,
+        ) {
+            let error_func = &mut *(user_data as *mut F);
+            let error: Borrowed<glib::Error> = from_glib_borrow(error as *mut glib::ffi::GError);
+
+            error_func(&from_glib_none(start), &from_glib_none(end), &error);
+        }
+
+        unsafe {
+            from_glib_full(ffi::gsk_render_node_deserialize(
+                bytes.to_glib_none().0,
+                Some(parse_error_func::<F>),
+                &mut error_func as *mut F as glib::ffi::gpointer,
+            ))
+        }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// A render node type, identified by its [`RenderNodeType`].
+///
+/// # Safety
+///
+/// `NODE_TYPE` has to be the type GSK reports for nodes of `Self`, it's
+/// used to check the downcasts of [`RenderNode`].
+///
+/// [`RenderNodeType`]: enum.RenderNodeType.html
+/// [`RenderNode`]: struct.RenderNode.html
+pub unsafe trait IsRenderNode: IsA<RenderNode> {
+    const NODE_TYPE: RenderNodeType;
+}
+
+impl RenderNode {
+    // rustdoc-stripper-ignore-next
+    /// Whether this node is a `T`, according to its node type.
+    pub fn is<T: IsRenderNode>(&self) -> bool {
+        self.get_node_type() == T::NODE_TYPE
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Casts the node to a `T`, or gives it back if it's of another type.
+    pub fn downcast<T: IsRenderNode>(self) -> Result<T, RenderNode> {
+        if self.is::<T>() {
+            Ok(unsafe { self.unsafe_cast() })
+        } else {
+            Err(self)
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Casts a reference to the node to a `T`, if it's one.
+    pub fn downcast_ref<T: IsRenderNode>(&self) -> Option<&T> {
+        if self.is::<T>() {
+            Some(unsafe { self.unsafe_cast_ref() })
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! render_node_types {
+    ($($name:ident,)+) => {
+        $(
+            unsafe impl IsRenderNode for crate::$name {
+                const NODE_TYPE: RenderNodeType = RenderNodeType::$name;
+            }
+        )+
+    };
+}
+
+render_node_types!(
+    BlendNode,
+    BlurNode,
+    BorderNode,
+    CairoNode,
+    ClipNode,
+    ColorMatrixNode,
+    ColorNode,
+    ConicGradientNode,
+    ContainerNode,
+    CrossFadeNode,
+    DebugNode,
+    InsetShadowNode,
+    LinearGradientNode,
+    OpacityNode,
+    OutsetShadowNode,
+    RadialGradientNode,
+    RepeatNode,
+    RepeatingLinearGradientNode,
+    RepeatingRadialGradientNode,
+    RoundedClipNode,
+    ShadowNode,
+    TextNode,
+    TextureNode,
+    TransformNode,
+);
+
+unsafe impl IsRenderNode for crate::GLShaderNode {
+    const NODE_TYPE: RenderNodeType = RenderNodeType::GlShaderNode;
+}
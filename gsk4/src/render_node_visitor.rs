@@ -0,0 +1,267 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{
+    BlendNode, BlurNode, BorderNode, CairoNode, ClipNode, ColorMatrixNode, ColorNode,
+    ConicGradientNode, ContainerNode, CrossFadeNode, DebugNode, GLShaderNode, InsetShadowNode,
+    LinearGradientNode, OpacityNode, OutsetShadowNode, RadialGradientNode, RenderNode,
+    RenderNodeExt, RenderNodeType, RepeatNode, RepeatingLinearGradientNode,
+    RepeatingRadialGradientNode, RoundedClipNode, ShadowNode, TextNode, TextureNode, TransformNode,
+};
+use glib::Cast;
+
+// rustdoc-stripper-ignore-next
+/// Callbacks for [`walk`], one per render node type.
+///
+/// All of them continue the traversal by default, returning `Err` from one
+/// stops it with the error value.
+///
+/// [`walk`]: fn.walk.html
+pub trait RenderNodeVisitor<B = ()> {
+    fn visit_blend_node(&mut self, _node: &BlendNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_blur_node(&mut self, _node: &BlurNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_border_node(&mut self, _node: &BorderNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_cairo_node(&mut self, _node: &CairoNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_clip_node(&mut self, _node: &ClipNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_color_matrix_node(&mut self, _node: &ColorMatrixNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_color_node(&mut self, _node: &ColorNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_conic_gradient_node(&mut self, _node: &ConicGradientNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_container_node(&mut self, _node: &ContainerNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_cross_fade_node(&mut self, _node: &CrossFadeNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_debug_node(&mut self, _node: &DebugNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_gl_shader_node(&mut self, _node: &GLShaderNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_inset_shadow_node(&mut self, _node: &InsetShadowNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_linear_gradient_node(&mut self, _node: &LinearGradientNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_opacity_node(&mut self, _node: &OpacityNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_outset_shadow_node(&mut self, _node: &OutsetShadowNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_radial_gradient_node(&mut self, _node: &RadialGradientNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_repeat_node(&mut self, _node: &RepeatNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_repeating_linear_gradient_node(
+        &mut self,
+        _node: &RepeatingLinearGradientNode,
+    ) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_repeating_radial_gradient_node(
+        &mut self,
+        _node: &RepeatingRadialGradientNode,
+    ) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_rounded_clip_node(&mut self, _node: &RoundedClipNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_shadow_node(&mut self, _node: &ShadowNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_text_node(&mut self, _node: &TextNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_texture_node(&mut self, _node: &TextureNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    fn visit_transform_node(&mut self, _node: &TransformNode) -> Result<(), B> {
+        Ok(())
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Called for node types unknown to these bindings, whose children
+    /// aren't visited.
+    fn visit_unknown_node(&mut self, _node: &RenderNode) -> Result<(), B> {
+        Ok(())
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Visits `node` and then its children, depth first, until a callback of
+/// `visitor` returns an `Err`, which is returned.
+pub fn walk<B, V: RenderNodeVisitor<B> + ?Sized>(
+    node: &RenderNode,
+    visitor: &mut V,
+) -> Result<(), B> {
+    // The node type was checked, so the casts are fine.
+    unsafe {
+        match node.get_node_type() {
+            RenderNodeType::BlendNode => {
+                let node = node.unsafe_cast_ref::<BlendNode>();
+                visitor.visit_blend_node(node)?;
+                walk_child(node.get_bottom_child(), visitor)?;
+                walk_child(node.get_top_child(), visitor)?;
+                Ok(())
+            }
+            RenderNodeType::BlurNode => {
+                let node = node.unsafe_cast_ref::<BlurNode>();
+                visitor.visit_blur_node(node)?;
+                walk_child(node.get_child(), visitor)?;
+                Ok(())
+            }
+            RenderNodeType::BorderNode => visitor.visit_border_node(node.unsafe_cast_ref()),
+            RenderNodeType::CairoNode => visitor.visit_cairo_node(node.unsafe_cast_ref()),
+            RenderNodeType::ClipNode => {
+                let node = node.unsafe_cast_ref::<ClipNode>();
+                visitor.visit_clip_node(node)?;
+                walk_child(node.get_child(), visitor)?;
+                Ok(())
+            }
+            RenderNodeType::ColorMatrixNode => {
+                let node = node.unsafe_cast_ref::<ColorMatrixNode>();
+                visitor.visit_color_matrix_node(node)?;
+                walk_child(node.get_child(), visitor)?;
+                Ok(())
+            }
+            RenderNodeType::ColorNode => visitor.visit_color_node(node.unsafe_cast_ref()),
+            RenderNodeType::ConicGradientNode => {
+                visitor.visit_conic_gradient_node(node.unsafe_cast_ref())
+            }
+            RenderNodeType::ContainerNode => {
+                let node = node.unsafe_cast_ref::<ContainerNode>();
+                visitor.visit_container_node(node)?;
+                for idx in 0..node.get_n_children() {
+                    walk_child(node.get_child(idx), visitor)?;
+                }
+                Ok(())
+            }
+            RenderNodeType::CrossFadeNode => {
+                let node = node.unsafe_cast_ref::<CrossFadeNode>();
+                visitor.visit_cross_fade_node(node)?;
+                walk_child(node.get_start_child(), visitor)?;
+                walk_child(node.get_end_child(), visitor)?;
+                Ok(())
+            }
+            RenderNodeType::DebugNode => {
+                let node = node.unsafe_cast_ref::<DebugNode>();
+                visitor.visit_debug_node(node)?;
+                walk_child(node.get_child(), visitor)?;
+                Ok(())
+            }
+            RenderNodeType::GlShaderNode => {
+                let node = node.unsafe_cast_ref::<GLShaderNode>();
+                visitor.visit_gl_shader_node(node)?;
+                for idx in 0..node.get_n_children() {
+                    walk_child(node.get_child(idx), visitor)?;
+                }
+                Ok(())
+            }
+            RenderNodeType::InsetShadowNode => {
+                visitor.visit_inset_shadow_node(node.unsafe_cast_ref())
+            }
+            RenderNodeType::LinearGradientNode => {
+                visitor.visit_linear_gradient_node(node.unsafe_cast_ref())
+            }
+            RenderNodeType::OpacityNode => {
+                let node = node.unsafe_cast_ref::<OpacityNode>();
+                visitor.visit_opacity_node(node)?;
+                walk_child(node.get_child(), visitor)?;
+                Ok(())
+            }
+            RenderNodeType::OutsetShadowNode => {
+                visitor.visit_outset_shadow_node(node.unsafe_cast_ref())
+            }
+            RenderNodeType::RadialGradientNode => {
+                visitor.visit_radial_gradient_node(node.unsafe_cast_ref())
+            }
+            RenderNodeType::RepeatNode => {
+                let node = node.unsafe_cast_ref::<RepeatNode>();
+                visitor.visit_repeat_node(node)?;
+                walk_child(node.get_child(), visitor)?;
+                Ok(())
+            }
+            RenderNodeType::RepeatingLinearGradientNode => {
+                visitor.visit_repeating_linear_gradient_node(node.unsafe_cast_ref())
+            }
+            RenderNodeType::RepeatingRadialGradientNode => {
+                visitor.visit_repeating_radial_gradient_node(node.unsafe_cast_ref())
+            }
+            RenderNodeType::RoundedClipNode => {
+                let node = node.unsafe_cast_ref::<RoundedClipNode>();
+                visitor.visit_rounded_clip_node(node)?;
+                walk_child(node.get_child(), visitor)?;
+                Ok(())
+            }
+            RenderNodeType::ShadowNode => {
+                let node = node.unsafe_cast_ref::<ShadowNode>();
+                visitor.visit_shadow_node(node)?;
+                walk_child(node.get_child(), visitor)?;
+                Ok(())
+            }
+            RenderNodeType::TextNode => visitor.visit_text_node(node.unsafe_cast_ref()),
+            RenderNodeType::TextureNode => visitor.visit_texture_node(node.unsafe_cast_ref()),
+            RenderNodeType::TransformNode => {
+                let node = node.unsafe_cast_ref::<TransformNode>();
+                visitor.visit_transform_node(node)?;
+                walk_child(node.get_child(), visitor)?;
+                Ok(())
+            }
+            _ => visitor.visit_unknown_node(node),
+        }
+    }
+}
+
+fn walk_child<B, V: RenderNodeVisitor<B> + ?Sized>(
+    child: Option<RenderNode>,
+    visitor: &mut V,
+) -> Result<(), B> {
+    match child {
+        Some(child) => walk(&child, visitor),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,43 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gsk::{glib, graphene};
+use gsk4 as gsk;
+
+const SOURCE: &str = "
+uniform float u_time;
+uniform vec2 u_center;
+
+void mainImage(out vec4 fragColor, in vec2 fragCoord, in vec2 resolution, in vec2 uv) {
+  fragColor = vec4(u_center, u_time, 1.0);
+}
+";
+
+#[test]
+fn uniform_builder() {
+    let shader = gsk::GLShader::from_bytes(&glib::Bytes::from_static(SOURCE.as_bytes()));
+    let time = shader.find_uniform_by_name("u_time");
+    let center = shader.find_uniform_by_name("u_center");
+
+    let args = shader
+        .uniform_builder()
+        .set_float("u_time", 1.5)
+        .set_vec2("u_center", &graphene::Vec2::new(0.25, 0.75))
+        .build()
+        .unwrap();
+    assert_eq!(args.len(), shader.get_args_size());
+    assert_eq!(shader.get_arg_float(&args, time), 1.5);
+    let mut value = graphene::Vec2::new(0.0, 0.0);
+    shader.get_arg_vec2(&args, center, &mut value);
+    assert_eq!((value.get_x(), value.get_y()), (0.25, 0.75));
+
+    assert!(shader
+        .uniform_builder()
+        .set_float("u_unknown", 1.0)
+        .build()
+        .is_err());
+    assert!(shader
+        .uniform_builder()
+        .set_int("u_time", 1)
+        .build()
+        .is_err());
+}
@@ -3,7 +3,7 @@
 use proc_macro2::TokenStream;
 use proc_macro_error::{abort, abort_call_site};
 use quote::quote;
-use syn::Data;
+use syn::{Data, Meta, NestedMeta};
 
 use std::string::ToString;
 
@@ -41,6 +41,30 @@ fn gen_template_child_bindings(fields: &syn::Fields) -> TokenStream {
     }
 }
 
+// Whether the struct has a `#[template(callbacks)]` attribute.
+fn has_template_callbacks(attrs: &[syn::Attribute]) -> bool {
+    let mut callbacks = false;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("template")) {
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list,
+            Ok(meta) => abort!(
+                meta,
+                "expected a list of arguments like `#[template(callbacks)]`"
+            ),
+            Err(err) => abort!(err.span(), err.to_string()),
+        };
+        for meta in &list.nested {
+            match meta {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("callbacks") => {
+                    callbacks = true
+                }
+                meta => abort!(meta, "unknown attribute argument, expected `callbacks`"),
+            }
+        }
+    }
+    callbacks
+}
+
 pub fn impl_composite_template(input: &syn::DeriveInput) -> TokenStream {
     let name = &input.ident;
     let crate_ident = crate_ident_new();
@@ -51,6 +75,13 @@ pub fn impl_composite_template(input: &syn::DeriveInput) -> TokenStream {
     };
 
     let template_children = gen_template_child_bindings(&fields);
+    let template_callbacks = if has_template_callbacks(&input.attrs) {
+        Some(quote! {
+            <Self as #crate_ident::subclass::widget::CompositeTemplateCallbacks>::bind_template_callbacks(klass);
+        })
+    } else {
+        None
+    };
 
     quote! {
         impl #crate_ident::subclass::widget::CompositeTemplate for #name {
@@ -58,6 +89,7 @@ pub fn impl_composite_template(input: &syn::DeriveInput) -> TokenStream {
                 unsafe {
                     #template_children
                 }
+                #template_callbacks
             }
         }
     }
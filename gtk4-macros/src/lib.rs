@@ -2,16 +2,62 @@
 
 mod attribute_parser;
 mod composite_template_derive;
+mod template_callbacks_attribute;
 mod util;
 
 use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, DeriveInput, ItemImpl};
 
-#[proc_macro_derive(CompositeTemplate, attributes(template_child))]
+/// Implements `CompositeTemplate` for the private struct of a widget.
+///
+/// `bind_template_children()` binds the fields marked with
+/// `#[template_child]` to the objects of the template with the same id, or
+/// the one given with `#[template_child(id = "object_id")]`.
+///
+/// With `#[template(callbacks)]` on the struct, it also registers the
+/// `#[template_callback]` methods of the struct's `#[template_callbacks]`
+/// impl block, so `klass.bind_template_callbacks()` doesn't need to be
+/// called.
+#[proc_macro_derive(CompositeTemplate, attributes(template, template_child))]
 #[proc_macro_error]
 pub fn composite_template_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let gen = composite_template_derive::impl_composite_template(&input);
     gen.into()
 }
+
+/// Binds the `#[template_callback]` methods of an `impl` block to the
+/// `<signal>` handlers of a composite template.
+///
+/// The block gets an implementation of `CompositeTemplateCallbacks`, the
+/// callbacks are then registered from `class_init()` after setting the
+/// template with `klass.bind_template_callbacks()`, or by
+/// `bind_template_children()` when the `CompositeTemplate` derive has a
+/// `#[template(callbacks)]` attribute. The handler name defaults
+/// to the method name and can be changed with
+/// `#[template_callback(name = "handler")]`.
+///
+/// The arguments of the signal are converted from `glib::Value`s to the
+/// argument types of the method, `Option<T>` accepts `None` values. Methods
+/// taking `&self` must be in an `impl` block of the `ObjectSubclass`, they
+/// get it from the first argument which has to be the template object, as
+/// with `swapped="true"` handlers. The return value is converted back to a
+/// `glib::Value`.
+///
+/// ```ignore
+/// #[gtk::template_callbacks]
+/// impl MyWidget {
+///     #[template_callback]
+///     fn on_button_clicked(&self, button: gtk::Button) {
+///         // ...
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn template_callbacks(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemImpl);
+    let gen = template_callbacks_attribute::impl_template_callbacks(input);
+    gen.into()
+}
@@ -0,0 +1,274 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::abort;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    FnArg, GenericArgument, ImplItem, ImplItemMethod, ItemImpl, Lit, Meta, NestedMeta,
+    PathArguments, ReturnType, Type,
+};
+
+use crate::util::*;
+
+// Returns the name of the callback if `method` has a `#[template_callback]`
+// attribute, and removes the attribute.
+fn take_callback_attr(method: &mut ImplItemMethod) -> Option<String> {
+    let index = method
+        .attrs
+        .iter()
+        .position(|attr| attr.path.is_ident("template_callback"))?;
+    let attr = method.attrs.remove(index);
+    let mut name = method.sig.ident.to_string();
+
+    match attr.parse_meta() {
+        Ok(Meta::Path(_)) => (),
+        Ok(Meta::List(list)) => {
+            for meta in &list.nested {
+                match meta {
+                    NestedMeta::Meta(Meta::NameValue(name_value))
+                        if name_value.path.is_ident("name") =>
+                    {
+                        match &name_value.lit {
+                            Lit::Str(s) => name = s.value(),
+                            lit => abort!(lit, "invalid value type: Expected str literal"),
+                        }
+                    }
+                    meta => abort!(
+                        meta,
+                        "unknown attribute argument, expected `name = \"handler\"`"
+                    ),
+                }
+            }
+        }
+        Ok(meta) => abort!(
+            meta,
+            "invalid attribute argument type, expected `name = value` list or nothing"
+        ),
+        Err(err) => abort!(err.span(), err.to_string()),
+    }
+
+    if method
+        .attrs
+        .iter()
+        .any(|a| a.path.is_ident("template_callback"))
+    {
+        abort!(
+            method.sig.ident,
+            "multiple `#[template_callback]` attributes on the same method are not supported"
+        );
+    }
+
+    Some(name)
+}
+
+// Returns `U` if `ty` is `Option<U>`.
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_value_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "Value"),
+        _ => false,
+    }
+}
+
+// Aborts if `ty` can't be borrowed from a `glib::Value`, only `&str` can.
+fn check_arg_type(ty: &Type) {
+    if let Type::Reference(reference) = ty {
+        let is_str = reference.mutability.is_none()
+            && matches!(&*reference.elem, Type::Path(path) if path.path.is_ident("str"));
+        if !is_str {
+            abort!(
+                reference.and_token,
+                "template callback arguments must be owned values, like `gtk::Button`, or `&str`"
+            );
+        }
+    }
+}
+
+fn gen_callback(self_ty: &Type, name: &str, method: &ImplItemMethod) -> TokenStream {
+    let crate_ident = crate_ident_new();
+    let sig = &method.sig;
+    let ident = &sig.ident;
+
+    if !sig.generics.params.is_empty() {
+        abort!(sig.generics, "template callbacks can't be generic");
+    }
+    if let Some(asyncness) = &sig.asyncness {
+        abort!(asyncness, "template callbacks can't be async");
+    }
+
+    let mut receiver = None;
+    let mut arg_types = Vec::new();
+    for input in &sig.inputs {
+        match input {
+            FnArg::Receiver(r) => {
+                if r.reference.is_none() {
+                    abort!(
+                        r.self_token,
+                        "template callbacks must take `&self` or no receiver at all"
+                    );
+                }
+                if let Some(mutability) = &r.mutability {
+                    abort!(
+                        mutability,
+                        "template callbacks must take `&self` or no receiver at all"
+                    );
+                }
+                receiver = Some(r);
+            }
+            FnArg::Typed(pat_type) => {
+                check_arg_type(&pat_type.ty);
+                arg_types.push(&*pat_type.ty)
+            }
+        }
+    }
+
+    let n_values = arg_types.len() + receiver.is_some() as usize;
+    let first_arg = receiver.is_some() as usize;
+
+    let this = receiver.map(|_| {
+        quote! {
+            let this = match values[0].get::<<#self_ty as #crate_ident::glib::subclass::types::ObjectSubclass>::Type>() {
+                Ok(Some(this)) => this,
+                _ => panic!(
+                    "The first argument of template callback `{}` is not a {}, is the handler swapped?",
+                    #name,
+                    <<#self_ty as #crate_ident::glib::subclass::types::ObjectSubclass>::Type as #crate_ident::glib::StaticType>::static_type(),
+                ),
+            };
+            let this = <#self_ty as #crate_ident::glib::subclass::types::ObjectSubclass>::from_instance(&this);
+        }
+    });
+
+    let arg_names = (0..arg_types.len())
+        .map(|i| format_ident!("arg{}", i))
+        .collect::<Vec<_>>();
+    let args = arg_types.iter().zip(&arg_names).enumerate().map(|(i, (ty, arg_name))| {
+        let index = i + first_arg;
+        match option_inner_type(ty) {
+            Some(inner) => quote! {
+                let #arg_name: #ty = values[#index].get::<#inner>().unwrap_or_else(|err| {
+                    panic!("Wrong type for argument {} of template callback `{}`: {}", #index, #name, err)
+                });
+            },
+            None => quote! {
+                let #arg_name: #ty = match values[#index].get::<#ty>() {
+                    Ok(Some(value)) => value,
+                    Ok(None) => panic!("Argument {} of template callback `{}` is None", #index, #name),
+                    Err(err) => panic!("Wrong type for argument {} of template callback `{}`: {}", #index, #name, err),
+                };
+            },
+        }
+    });
+
+    let call = if receiver.is_some() {
+        quote! { this.#ident(#(#arg_names),*) }
+    } else {
+        quote! { <#self_ty>::#ident(#(#arg_names),*) }
+    };
+    let ret = match &sig.output {
+        ReturnType::Default => quote! {
+            #call;
+            None
+        },
+        ReturnType::Type(_, ty) if is_value_type(ty) => quote! {
+            Some(#call)
+        },
+        ReturnType::Type(..) => quote! {
+            Some(#crate_ident::glib::ToValue::to_value(&#call))
+        },
+    };
+
+    let check_len = if n_values > 0 {
+        Some(quote! {
+            assert!(
+                values.len() >= #n_values,
+                "Template callback `{}` takes {} arguments but got {}",
+                #name,
+                #n_values,
+                values.len(),
+            );
+        })
+    } else {
+        None
+    };
+
+    quote! {
+        (#name, {
+            #[allow(unused_variables)]
+            fn callback(values: &[#crate_ident::glib::Value]) -> Option<#crate_ident::glib::Value> {
+                #check_len
+                #this
+                #(#args)*
+                #ret
+            }
+            callback as fn(&[#crate_ident::glib::Value]) -> Option<#crate_ident::glib::Value>
+        }),
+    }
+}
+
+pub fn impl_template_callbacks(mut input: ItemImpl) -> TokenStream {
+    let crate_ident = crate_ident_new();
+
+    if let Some((_, path, _)) = &input.trait_ {
+        abort!(
+            path,
+            "`#[template_callbacks]` only supports inherent impl blocks"
+        );
+    }
+    if !input.generics.params.is_empty() {
+        abort!(
+            input.generics,
+            "`#[template_callbacks]` doesn't support generic impl blocks"
+        );
+    }
+
+    let self_ty = input.self_ty.clone();
+    let mut callbacks = Vec::new();
+    for item in &mut input.items {
+        if let ImplItem::Method(method) = item {
+            if let Some(name) = take_callback_attr(method) {
+                callbacks.push(gen_callback(&self_ty, &name, method));
+            }
+        }
+    }
+
+    if callbacks.is_empty() {
+        abort!(
+            Span::call_site(),
+            "no `#[template_callback]` method in the impl block"
+        );
+    }
+
+    let impl_tokens = input.to_token_stream();
+
+    quote! {
+        #impl_tokens
+
+        impl #crate_ident::subclass::widget::CompositeTemplateCallbacks for #self_ty {
+            const CALLBACKS: &'static [(&'static str, fn(&[#crate_ident::glib::Value]) -> Option<#crate_ident::glib::Value>)] = &[
+                #(#callbacks)*
+            ];
+        }
+    }
+}
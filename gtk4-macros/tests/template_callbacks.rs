@@ -0,0 +1,7 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[test]
+fn template_callbacks_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
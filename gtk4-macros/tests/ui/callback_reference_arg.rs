@@ -0,0 +1,9 @@
+struct Callbacks;
+
+#[gtk4::template_callbacks]
+impl Callbacks {
+    #[template_callback]
+    fn on_clicked(&self, button: &gtk4::Button) {}
+}
+
+fn main() {}
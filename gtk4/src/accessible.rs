@@ -0,0 +1,282 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Typed values for the [`AccessibleExtManual`] update methods.
+//!
+//! [`AccessibleExtManual`]: ../prelude/trait.AccessibleExtManual.html
+
+use crate::{
+    Accessible, AccessibleAutocomplete, AccessibleExt, AccessibleInvalidState, AccessibleProperty,
+    AccessibleRelation, AccessibleSort, AccessibleState, AccessibleTristate, Orientation,
+};
+use glib::object::{IsA, ObjectType};
+use glib::translate::*;
+use glib::ToValue;
+use std::ptr;
+
+#[derive(Debug)]
+pub enum Property<'a> {
+    Autocomplete(AccessibleAutocomplete),
+    Description(&'a str),
+    HasPopup(bool),
+    KeyShortcuts(&'a str),
+    Label(&'a str),
+    Level(i32),
+    Modal(bool),
+    MultiLine(bool),
+    MultiSelectable(bool),
+    Orientation(Orientation),
+    Placeholder(&'a str),
+    ReadOnly(bool),
+    Required(bool),
+    RoleDescription(&'a str),
+    Sort(AccessibleSort),
+    ValueMax(f64),
+    ValueMin(f64),
+    ValueNow(f64),
+    ValueText(&'a str),
+}
+
+// Token and tristate values are passed as plain integers, GTK doesn't accept
+// the enum types for them.
+impl<'a> Property<'a> {
+    fn to_property_value(&self) -> (AccessibleProperty, glib::Value) {
+        use Property::*;
+
+        match *self {
+            Autocomplete(v) => (AccessibleProperty::Autocomplete, v.to_glib().to_value()),
+            Description(v) => (AccessibleProperty::Description, v.to_value()),
+            HasPopup(v) => (AccessibleProperty::HasPopup, v.to_value()),
+            KeyShortcuts(v) => (AccessibleProperty::KeyShortcuts, v.to_value()),
+            Label(v) => (AccessibleProperty::Label, v.to_value()),
+            Level(v) => (AccessibleProperty::Level, v.to_value()),
+            Modal(v) => (AccessibleProperty::Modal, v.to_value()),
+            MultiLine(v) => (AccessibleProperty::MultiLine, v.to_value()),
+            MultiSelectable(v) => (AccessibleProperty::MultiSelectable, v.to_value()),
+            Orientation(v) => (AccessibleProperty::Orientation, v.to_glib().to_value()),
+            Placeholder(v) => (AccessibleProperty::Placeholder, v.to_value()),
+            ReadOnly(v) => (AccessibleProperty::ReadOnly, v.to_value()),
+            Required(v) => (AccessibleProperty::Required, v.to_value()),
+            RoleDescription(v) => (AccessibleProperty::RoleDescription, v.to_value()),
+            Sort(v) => (AccessibleProperty::Sort, v.to_glib().to_value()),
+            ValueMax(v) => (AccessibleProperty::ValueMax, v.to_value()),
+            ValueMin(v) => (AccessibleProperty::ValueMin, v.to_value()),
+            ValueNow(v) => (AccessibleProperty::ValueNow, v.to_value()),
+            ValueText(v) => (AccessibleProperty::ValueText, v.to_value()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Relation<'a> {
+    ActiveDescendant(&'a Accessible),
+    ColCount(i32),
+    ColIndex(i32),
+    ColIndexText(&'a str),
+    ColSpan(i32),
+    Controls(&'a [&'a Accessible]),
+    DescribedBy(&'a [&'a Accessible]),
+    Details(&'a [&'a Accessible]),
+    ErrorMessage(&'a Accessible),
+    FlowTo(&'a [&'a Accessible]),
+    LabelledBy(&'a [&'a Accessible]),
+    Owns(&'a [&'a Accessible]),
+    PosInSet(i32),
+    RowCount(i32),
+    RowIndex(i32),
+    RowIndexText(&'a str),
+    RowSpan(i32),
+    SetSize(i32),
+}
+
+impl<'a> Relation<'a> {
+    fn to_relation_value(&self) -> (AccessibleRelation, glib::Value) {
+        use Relation::*;
+
+        match *self {
+            ActiveDescendant(v) => (AccessibleRelation::ActiveDescendant, v.to_value()),
+            ColCount(v) => (AccessibleRelation::ColCount, v.to_value()),
+            ColIndex(v) => (AccessibleRelation::ColIndex, v.to_value()),
+            ColIndexText(v) => (AccessibleRelation::ColIndexText, v.to_value()),
+            ColSpan(v) => (AccessibleRelation::ColSpan, v.to_value()),
+            Controls(v) => (AccessibleRelation::Controls, reference_list_value(v)),
+            DescribedBy(v) => (AccessibleRelation::DescribedBy, reference_list_value(v)),
+            Details(v) => (AccessibleRelation::Details, reference_list_value(v)),
+            ErrorMessage(v) => (AccessibleRelation::ErrorMessage, v.to_value()),
+            FlowTo(v) => (AccessibleRelation::FlowTo, reference_list_value(v)),
+            LabelledBy(v) => (AccessibleRelation::LabelledBy, reference_list_value(v)),
+            Owns(v) => (AccessibleRelation::Owns, reference_list_value(v)),
+            PosInSet(v) => (AccessibleRelation::PosInSet, v.to_value()),
+            RowCount(v) => (AccessibleRelation::RowCount, v.to_value()),
+            RowIndex(v) => (AccessibleRelation::RowIndex, v.to_value()),
+            RowIndexText(v) => (AccessibleRelation::RowIndexText, v.to_value()),
+            RowSpan(v) => (AccessibleRelation::RowSpan, v.to_value()),
+            SetSize(v) => (AccessibleRelation::SetSize, v.to_value()),
+        }
+    }
+}
+
+// Reference lists are passed as a GList in a pointer value. GTK takes the
+// list, the accessibles are only weakly referenced.
+fn reference_list_value(accessibles: &[&Accessible]) -> glib::Value {
+    unsafe {
+        let mut list = ptr::null_mut();
+        for accessible in accessibles.iter().rev() {
+            list = glib::ffi::g_list_prepend(list, accessible.as_ptr() as glib::ffi::gpointer);
+        }
+
+        let mut value = glib::Value::from_type(glib::Type::Pointer);
+        glib::gobject_ffi::g_value_set_pointer(value.to_glib_none_mut().0, list as *mut _);
+        value
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// `Expanded` and `Selected` accept `None` for the undefined value, which is
+/// also the default.
+#[derive(Clone, Copy, Debug)]
+pub enum State {
+    Busy(bool),
+    Checked(AccessibleTristate),
+    Disabled(bool),
+    Expanded(Option<bool>),
+    Hidden(bool),
+    Invalid(AccessibleInvalidState),
+    Pressed(AccessibleTristate),
+    Selected(Option<bool>),
+}
+
+impl State {
+    // `None` for undefined values, which are set by resetting the state.
+    fn to_state_value(self) -> (AccessibleState, Option<glib::Value>) {
+        use State::*;
+
+        match self {
+            Busy(v) => (AccessibleState::Busy, Some(v.to_value())),
+            Checked(v) => (AccessibleState::Checked, Some(v.to_glib().to_value())),
+            Disabled(v) => (AccessibleState::Disabled, Some(v.to_value())),
+            Expanded(v) => (AccessibleState::Expanded, v.map(|v| v.to_value())),
+            Hidden(v) => (AccessibleState::Hidden, Some(v.to_value())),
+            Invalid(v) => (AccessibleState::Invalid, Some(v.to_glib().to_value())),
+            Pressed(v) => (AccessibleState::Pressed, Some(v.to_glib().to_value())),
+            Selected(v) => (AccessibleState::Selected, v.map(|v| v.to_value())),
+        }
+    }
+}
+
+pub trait AccessibleExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Updates a list of accessible properties, the other ones are left
+    /// unchanged.
+    #[doc(alias = "gtk_accessible_update_property")]
+    #[doc(alias = "gtk_accessible_update_property_value")]
+    fn update_property(&self, properties: &[Property]);
+
+    // rustdoc-stripper-ignore-next
+    /// Updates a list of accessible relations, the other ones are left
+    /// unchanged.
+    #[doc(alias = "gtk_accessible_update_relation")]
+    #[doc(alias = "gtk_accessible_update_relation_value")]
+    fn update_relation(&self, relations: &[Relation]);
+
+    // rustdoc-stripper-ignore-next
+    /// Updates a list of accessible states, the other ones are left
+    /// unchanged.
+    #[doc(alias = "gtk_accessible_update_state")]
+    #[doc(alias = "gtk_accessible_update_state_value")]
+    fn update_state(&self, states: &[State]);
+}
+
+impl<O: IsA<Accessible>> AccessibleExtManual for O {
+    fn update_property(&self, properties: &[Property]) {
+        let (mut properties, values): (Vec<ffi::GtkAccessibleProperty>, Vec<glib::Value>) =
+            properties
+                .iter()
+                .map(|property| {
+                    let (property, value) = property.to_property_value();
+                    (property.to_glib(), value)
+                })
+                .unzip();
+
+        unsafe {
+            ffi::gtk_accessible_update_property_value(
+                self.as_ref().to_glib_none().0,
+                properties.len() as i32,
+                properties.as_mut_ptr(),
+                values.as_ptr() as *const glib::gobject_ffi::GValue,
+            );
+        }
+    }
+
+    fn update_relation(&self, relations: &[Relation]) {
+        let (mut relations, values): (Vec<ffi::GtkAccessibleRelation>, Vec<glib::Value>) =
+            relations
+                .iter()
+                .map(|relation| {
+                    let (relation, value) = relation.to_relation_value();
+                    (relation.to_glib(), value)
+                })
+                .unzip();
+
+        unsafe {
+            ffi::gtk_accessible_update_relation_value(
+                self.as_ref().to_glib_none().0,
+                relations.len() as i32,
+                relations.as_mut_ptr(),
+                values.as_ptr() as *const glib::gobject_ffi::GValue,
+            );
+        }
+    }
+
+    fn update_state(&self, states: &[State]) {
+        let mut defined_states = Vec::with_capacity(states.len());
+        let mut values = Vec::with_capacity(states.len());
+        for state in states {
+            match state.to_state_value() {
+                (state, Some(value)) => {
+                    defined_states.push(state.to_glib());
+                    values.push(value);
+                }
+                (state, None) => self.reset_state(state),
+            }
+        }
+
+        unsafe {
+            ffi::gtk_accessible_update_state_value(
+                self.as_ref().to_glib_none().0,
+                defined_states.len() as i32,
+                defined_states.as_mut_ptr(),
+                values.as_ptr() as *const glib::gobject_ffi::GValue,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn property_values() {
+        let (property, value) = Property::Label("Close").to_property_value();
+        assert_eq!(property, AccessibleProperty::Label);
+        assert_eq!(value.get::<&str>().unwrap(), Some("Close"));
+
+        let (property, value) = Property::Level(3).to_property_value();
+        assert_eq!(property, AccessibleProperty::Level);
+        assert_eq!(value.get_some::<i32>().unwrap(), 3);
+    }
+
+    #[test]
+    fn state_values() {
+        let (state, value) = State::Checked(AccessibleTristate::Mixed).to_state_value();
+        assert_eq!(state, AccessibleState::Checked);
+        assert_eq!(
+            value.unwrap().get_some::<i32>().unwrap(),
+            AccessibleTristate::Mixed.to_glib()
+        );
+
+        let (state, value) = State::Expanded(None).to_state_value();
+        assert_eq!(state, AccessibleState::Expanded);
+        assert!(value.is_none());
+    }
+}
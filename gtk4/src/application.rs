@@ -101,4 +101,20 @@ impl Application {
             .ok_or_else(|| glib::bool_error!("Failed to create application"))
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Runs the application with the command line arguments of the process,
+    /// and returns its exit status.
+    ///
+    /// `gio::prelude::ApplicationExtManual::run` takes the arguments to use
+    /// instead.
+    #[doc(alias = "g_application_run")]
+    pub fn run_default(&self) -> i32 {
+        let args = std::env::args().collect::<Vec<_>>();
+        gio::prelude::ApplicationExtManual::run(self, &args)
+    }
+
+    pub fn builder() -> ApplicationBuilder {
+        ApplicationBuilder::default()
+    }
 }
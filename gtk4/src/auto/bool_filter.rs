@@ -2,6 +2,7 @@
 // from gir-files (https://github.com/gtk-rs/gir-files)
 // DO NOT EDIT
 
+use crate::Expression;
 use crate::Filter;
 use glib::object::Cast;
 use glib::object::IsA;
@@ -24,25 +25,28 @@ glib::wrapper! {
 }
 
 impl BoolFilter {
-    //#[doc(alias = "gtk_bool_filter_new")]
-    //pub fn new(expression: /*Ignored*/Option<&Expression>) -> BoolFilter {
-    //    unsafe { TODO: call ffi:gtk_bool_filter_new() }
-    //}
+    #[doc(alias = "gtk_bool_filter_new")]
+    pub fn new(expression: Option<&Expression>) -> BoolFilter {
+        assert_initialized_main_thread!();
+        unsafe { from_glib_full(ffi::gtk_bool_filter_new(expression.to_glib_full())) }
+    }
 
-    //#[doc(alias = "gtk_bool_filter_get_expression")]
-    //pub fn get_expression(&self) -> /*Ignored*/Option<Expression> {
-    //    unsafe { TODO: call ffi:gtk_bool_filter_get_expression() }
-    //}
+    #[doc(alias = "gtk_bool_filter_get_expression")]
+    pub fn get_expression(&self) -> Option<Expression> {
+        unsafe { from_glib_none(ffi::gtk_bool_filter_get_expression(self.to_glib_none().0)) }
+    }
 
     #[doc(alias = "gtk_bool_filter_get_invert")]
     pub fn get_invert(&self) -> bool {
         unsafe { from_glib(ffi::gtk_bool_filter_get_invert(self.to_glib_none().0)) }
     }
 
-    //#[doc(alias = "gtk_bool_filter_set_expression")]
-    //pub fn set_expression(&self, expression: /*Ignored*/&Expression) {
-    //    unsafe { TODO: call ffi:gtk_bool_filter_set_expression() }
-    //}
+    #[doc(alias = "gtk_bool_filter_set_expression")]
+    pub fn set_expression(&self, expression: &Expression) {
+        unsafe {
+            ffi::gtk_bool_filter_set_expression(self.to_glib_none().0, expression.to_glib_none().0);
+        }
+    }
 
     #[doc(alias = "gtk_bool_filter_set_invert")]
     pub fn set_invert(&self, invert: bool) {
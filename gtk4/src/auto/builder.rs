@@ -32,28 +32,6 @@ impl Builder {
         unsafe { from_glib_full(ffi::gtk_builder_new()) }
     }
 
-    #[doc(alias = "gtk_builder_new_from_resource")]
-    pub fn from_resource(resource_path: &str) -> Builder {
-        assert_initialized_main_thread!();
-        unsafe {
-            from_glib_full(ffi::gtk_builder_new_from_resource(
-                resource_path.to_glib_none().0,
-            ))
-        }
-    }
-
-    #[doc(alias = "gtk_builder_new_from_string")]
-    pub fn from_string(string: &str) -> Builder {
-        assert_initialized_main_thread!();
-        let length = string.len() as isize;
-        unsafe {
-            from_glib_full(ffi::gtk_builder_new_from_string(
-                string.to_glib_none().0,
-                length,
-            ))
-        }
-    }
-
     #[doc(alias = "gtk_builder_add_from_resource")]
     pub fn add_from_resource(&self, resource_path: &str) -> Result<(), glib::Error> {
         unsafe {
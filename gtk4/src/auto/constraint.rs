@@ -21,52 +21,6 @@ glib::wrapper! {
 }
 
 impl Constraint {
-    #[doc(alias = "gtk_constraint_new")]
-    pub fn new<P: IsA<ConstraintTarget>, Q: IsA<ConstraintTarget>>(
-        target: Option<&P>,
-        target_attribute: ConstraintAttribute,
-        relation: ConstraintRelation,
-        source: Option<&Q>,
-        source_attribute: ConstraintAttribute,
-        multiplier: f64,
-        constant: f64,
-        strength: i32,
-    ) -> Constraint {
-        assert_initialized_main_thread!();
-        unsafe {
-            from_glib_full(ffi::gtk_constraint_new(
-                target.map(|p| p.as_ref()).to_glib_none().0,
-                target_attribute.to_glib(),
-                relation.to_glib(),
-                source.map(|p| p.as_ref()).to_glib_none().0,
-                source_attribute.to_glib(),
-                multiplier,
-                constant,
-                strength,
-            ))
-        }
-    }
-
-    #[doc(alias = "gtk_constraint_new_constant")]
-    pub fn new_constant<P: IsA<ConstraintTarget>>(
-        target: Option<&P>,
-        target_attribute: ConstraintAttribute,
-        relation: ConstraintRelation,
-        constant: f64,
-        strength: i32,
-    ) -> Constraint {
-        assert_initialized_main_thread!();
-        unsafe {
-            from_glib_full(ffi::gtk_constraint_new_constant(
-                target.map(|p| p.as_ref()).to_glib_none().0,
-                target_attribute.to_glib(),
-                relation.to_glib(),
-                constant,
-                strength,
-            ))
-        }
-    }
-
     #[doc(alias = "gtk_constraint_get_constant")]
     pub fn get_constant(&self) -> f64 {
         unsafe { ffi::gtk_constraint_get_constant(self.to_glib_none().0) }
@@ -96,11 +50,6 @@ impl Constraint {
         }
     }
 
-    #[doc(alias = "gtk_constraint_get_strength")]
-    pub fn get_strength(&self) -> i32 {
-        unsafe { ffi::gtk_constraint_get_strength(self.to_glib_none().0) }
-    }
-
     #[doc(alias = "gtk_constraint_get_target")]
     pub fn get_target(&self) -> Option<ConstraintTarget> {
         unsafe { from_glib_none(ffi::gtk_constraint_get_target(self.to_glib_none().0)) }
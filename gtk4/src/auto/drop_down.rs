@@ -7,6 +7,7 @@ use crate::AccessibleRole;
 use crate::Align;
 use crate::Buildable;
 use crate::ConstraintTarget;
+use crate::Expression;
 use crate::LayoutManager;
 use crate::ListItemFactory;
 use crate::Overflow;
@@ -32,10 +33,20 @@ glib::wrapper! {
 }
 
 impl DropDown {
-    //#[doc(alias = "gtk_drop_down_new")]
-    //pub fn new<P: IsA<gio::ListModel>>(model: Option<&P>, expression: /*Ignored*/Option<&Expression>) -> DropDown {
-    //    unsafe { TODO: call ffi:gtk_drop_down_new() }
-    //}
+    #[doc(alias = "gtk_drop_down_new")]
+    pub fn new<P: IsA<gio::ListModel>>(
+        model: Option<&P>,
+        expression: Option<&Expression>,
+    ) -> DropDown {
+        assert_initialized_main_thread!();
+        unsafe {
+            Widget::from_glib_none(ffi::gtk_drop_down_new(
+                model.map(|p| p.as_ref()).to_glib_full(),
+                expression.to_glib_full(),
+            ))
+            .unsafe_cast()
+        }
+    }
 
     #[doc(alias = "gtk_drop_down_new_from_strings")]
     pub fn from_strings(strings: &[&str]) -> DropDown {
@@ -53,10 +64,10 @@ impl DropDown {
         unsafe { from_glib(ffi::gtk_drop_down_get_enable_search(self.to_glib_none().0)) }
     }
 
-    //#[doc(alias = "gtk_drop_down_get_expression")]
-    //pub fn get_expression(&self) -> /*Ignored*/Option<Expression> {
-    //    unsafe { TODO: call ffi:gtk_drop_down_get_expression() }
-    //}
+    #[doc(alias = "gtk_drop_down_get_expression")]
+    pub fn get_expression(&self) -> Option<Expression> {
+        unsafe { from_glib_none(ffi::gtk_drop_down_get_expression(self.to_glib_none().0)) }
+    }
 
     #[doc(alias = "gtk_drop_down_get_factory")]
     pub fn get_factory(&self) -> Option<ListItemFactory> {
@@ -90,10 +101,12 @@ impl DropDown {
         }
     }
 
-    //#[doc(alias = "gtk_drop_down_set_expression")]
-    //pub fn set_expression(&self, expression: /*Ignored*/Option<&Expression>) {
-    //    unsafe { TODO: call ffi:gtk_drop_down_set_expression() }
-    //}
+    #[doc(alias = "gtk_drop_down_set_expression")]
+    pub fn set_expression(&self, expression: Option<&Expression>) {
+        unsafe {
+            ffi::gtk_drop_down_set_expression(self.to_glib_none().0, expression.to_glib_none().0);
+        }
+    }
 
     #[doc(alias = "gtk_drop_down_set_factory")]
     pub fn set_factory<P: IsA<ListItemFactory>>(&self, factory: Option<&P>) {
@@ -104,7 +104,7 @@ impl IconTheme {
         scale: i32,
         direction: TextDirection,
         flags: IconLookupFlags,
-    ) -> Option<IconPaintable> {
+    ) -> IconPaintable {
         unsafe {
             from_glib_full(ffi::gtk_icon_theme_lookup_by_gicon(
                 self.to_glib_none().0,
@@ -126,7 +126,7 @@ impl IconTheme {
         scale: i32,
         direction: TextDirection,
         flags: IconLookupFlags,
-    ) -> Option<IconPaintable> {
+    ) -> IconPaintable {
         unsafe {
             from_glib_full(ffi::gtk_icon_theme_lookup_icon(
                 self.to_glib_none().0,
@@ -175,7 +175,7 @@ impl IconTheme {
     }
 
     #[doc(alias = "gtk_icon_theme_get_for_display")]
-    pub fn get_for_display(display: &gdk::Display) -> Option<IconTheme> {
+    pub fn get_for_display(display: &gdk::Display) -> IconTheme {
         assert_initialized_main_thread!();
         unsafe {
             from_glib_none(ffi::gtk_icon_theme_get_for_display(
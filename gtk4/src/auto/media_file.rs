@@ -38,11 +38,11 @@ impl MediaFile {
     }
 
     #[doc(alias = "gtk_media_file_new_for_filename")]
-    pub fn new_for_filename(filename: &str) -> MediaFile {
+    pub fn new_for_filename<P: AsRef<std::path::Path>>(filename: P) -> MediaFile {
         assert_initialized_main_thread!();
         unsafe {
             from_glib_full(ffi::gtk_media_file_new_for_filename(
-                filename.to_glib_none().0,
+                filename.as_ref().to_glib_none().0,
             ))
         }
     }
@@ -90,7 +90,7 @@ pub trait MediaFileExt: 'static {
     fn set_file<P: IsA<gio::File>>(&self, file: Option<&P>);
 
     #[doc(alias = "gtk_media_file_set_filename")]
-    fn set_filename(&self, filename: Option<&str>);
+    fn set_filename<P: AsRef<std::path::Path>>(&self, filename: Option<P>);
 
     #[doc(alias = "gtk_media_file_set_input_stream")]
     fn set_input_stream<P: IsA<gio::InputStream>>(&self, stream: Option<&P>);
@@ -132,11 +132,11 @@ impl<O: IsA<MediaFile>> MediaFileExt for O {
         }
     }
 
-    fn set_filename(&self, filename: Option<&str>) {
+    fn set_filename<P: AsRef<std::path::Path>>(&self, filename: Option<P>) {
         unsafe {
             ffi::gtk_media_file_set_filename(
                 self.as_ref().to_glib_none().0,
-                filename.to_glib_none().0,
+                filename.as_ref().map(|p| p.as_ref()).to_glib_none().0,
             );
         }
     }
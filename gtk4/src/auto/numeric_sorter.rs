@@ -2,6 +2,7 @@
 // from gir-files (https://github.com/gtk-rs/gir-files)
 // DO NOT EDIT
 
+use crate::Expression;
 use crate::SortType;
 use crate::Sorter;
 use glib::object::Cast;
@@ -25,15 +26,20 @@ glib::wrapper! {
 }
 
 impl NumericSorter {
-    //#[doc(alias = "gtk_numeric_sorter_new")]
-    //pub fn new(expression: /*Ignored*/Option<&Expression>) -> NumericSorter {
-    //    unsafe { TODO: call ffi:gtk_numeric_sorter_new() }
-    //}
+    #[doc(alias = "gtk_numeric_sorter_new")]
+    pub fn new(expression: Option<&Expression>) -> NumericSorter {
+        assert_initialized_main_thread!();
+        unsafe { from_glib_full(ffi::gtk_numeric_sorter_new(expression.to_glib_full())) }
+    }
 
-    //#[doc(alias = "gtk_numeric_sorter_get_expression")]
-    //pub fn get_expression(&self) -> /*Ignored*/Option<Expression> {
-    //    unsafe { TODO: call ffi:gtk_numeric_sorter_get_expression() }
-    //}
+    #[doc(alias = "gtk_numeric_sorter_get_expression")]
+    pub fn get_expression(&self) -> Option<Expression> {
+        unsafe {
+            from_glib_none(ffi::gtk_numeric_sorter_get_expression(
+                self.to_glib_none().0,
+            ))
+        }
+    }
 
     #[doc(alias = "gtk_numeric_sorter_get_sort_order")]
     pub fn get_sort_order(&self) -> SortType {
@@ -44,10 +50,15 @@ impl NumericSorter {
         }
     }
 
-    //#[doc(alias = "gtk_numeric_sorter_set_expression")]
-    //pub fn set_expression(&self, expression: /*Ignored*/Option<&Expression>) {
-    //    unsafe { TODO: call ffi:gtk_numeric_sorter_set_expression() }
-    //}
+    #[doc(alias = "gtk_numeric_sorter_set_expression")]
+    pub fn set_expression(&self, expression: Option<&Expression>) {
+        unsafe {
+            ffi::gtk_numeric_sorter_set_expression(
+                self.to_glib_none().0,
+                expression.to_glib_none().0,
+            );
+        }
+    }
 
     #[doc(alias = "gtk_numeric_sorter_set_sort_order")]
     pub fn set_sort_order(&self, sort_order: SortType) {
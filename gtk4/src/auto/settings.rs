@@ -1289,12 +1289,6 @@ impl Settings {
         }
     }
 
-    #[doc(alias = "gtk_settings_get_default")]
-    pub fn get_default() -> Option<Settings> {
-        assert_initialized_main_thread!();
-        unsafe { from_glib_none(ffi::gtk_settings_get_default()) }
-    }
-
     #[doc(alias = "gtk_settings_get_for_display")]
     pub fn get_for_display(display: &gdk::Display) -> Settings {
         assert_initialized_main_thread!();
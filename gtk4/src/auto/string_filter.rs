@@ -2,6 +2,7 @@
 // from gir-files (https://github.com/gtk-rs/gir-files)
 // DO NOT EDIT
 
+use crate::Expression;
 use crate::Filter;
 use crate::StringFilterMatchMode;
 use glib::object::Cast;
@@ -25,15 +26,16 @@ glib::wrapper! {
 }
 
 impl StringFilter {
-    //#[doc(alias = "gtk_string_filter_new")]
-    //pub fn new(expression: /*Ignored*/Option<&Expression>) -> StringFilter {
-    //    unsafe { TODO: call ffi:gtk_string_filter_new() }
-    //}
+    #[doc(alias = "gtk_string_filter_new")]
+    pub fn new(expression: Option<&Expression>) -> StringFilter {
+        assert_initialized_main_thread!();
+        unsafe { from_glib_full(ffi::gtk_string_filter_new(expression.to_glib_full())) }
+    }
 
-    //#[doc(alias = "gtk_string_filter_get_expression")]
-    //pub fn get_expression(&self) -> /*Ignored*/Expression {
-    //    unsafe { TODO: call ffi:gtk_string_filter_get_expression() }
-    //}
+    #[doc(alias = "gtk_string_filter_get_expression")]
+    pub fn get_expression(&self) -> Expression {
+        unsafe { from_glib_none(ffi::gtk_string_filter_get_expression(self.to_glib_none().0)) }
+    }
 
     #[doc(alias = "gtk_string_filter_get_ignore_case")]
     pub fn get_ignore_case(&self) -> bool {
@@ -54,10 +56,15 @@ impl StringFilter {
         unsafe { from_glib_none(ffi::gtk_string_filter_get_search(self.to_glib_none().0)) }
     }
 
-    //#[doc(alias = "gtk_string_filter_set_expression")]
-    //pub fn set_expression(&self, expression: /*Ignored*/&Expression) {
-    //    unsafe { TODO: call ffi:gtk_string_filter_set_expression() }
-    //}
+    #[doc(alias = "gtk_string_filter_set_expression")]
+    pub fn set_expression(&self, expression: &Expression) {
+        unsafe {
+            ffi::gtk_string_filter_set_expression(
+                self.to_glib_none().0,
+                expression.to_glib_none().0,
+            );
+        }
+    }
 
     #[doc(alias = "gtk_string_filter_set_ignore_case")]
     pub fn set_ignore_case(&self, ignore_case: bool) {
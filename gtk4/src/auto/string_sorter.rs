@@ -2,6 +2,7 @@
 // from gir-files (https://github.com/gtk-rs/gir-files)
 // DO NOT EDIT
 
+use crate::Expression;
 use crate::Sorter;
 use glib::object::Cast;
 use glib::object::IsA;
@@ -24,15 +25,16 @@ glib::wrapper! {
 }
 
 impl StringSorter {
-    //#[doc(alias = "gtk_string_sorter_new")]
-    //pub fn new(expression: /*Ignored*/Option<&Expression>) -> StringSorter {
-    //    unsafe { TODO: call ffi:gtk_string_sorter_new() }
-    //}
+    #[doc(alias = "gtk_string_sorter_new")]
+    pub fn new(expression: Option<&Expression>) -> StringSorter {
+        assert_initialized_main_thread!();
+        unsafe { from_glib_full(ffi::gtk_string_sorter_new(expression.to_glib_full())) }
+    }
 
-    //#[doc(alias = "gtk_string_sorter_get_expression")]
-    //pub fn get_expression(&self) -> /*Ignored*/Option<Expression> {
-    //    unsafe { TODO: call ffi:gtk_string_sorter_get_expression() }
-    //}
+    #[doc(alias = "gtk_string_sorter_get_expression")]
+    pub fn get_expression(&self) -> Option<Expression> {
+        unsafe { from_glib_none(ffi::gtk_string_sorter_get_expression(self.to_glib_none().0)) }
+    }
 
     #[doc(alias = "gtk_string_sorter_get_ignore_case")]
     pub fn get_ignore_case(&self) -> bool {
@@ -43,10 +45,15 @@ impl StringSorter {
         }
     }
 
-    //#[doc(alias = "gtk_string_sorter_set_expression")]
-    //pub fn set_expression(&self, expression: /*Ignored*/Option<&Expression>) {
-    //    unsafe { TODO: call ffi:gtk_string_sorter_set_expression() }
-    //}
+    #[doc(alias = "gtk_string_sorter_set_expression")]
+    pub fn set_expression(&self, expression: Option<&Expression>) {
+        unsafe {
+            ffi::gtk_string_sorter_set_expression(
+                self.to_glib_none().0,
+                expression.to_glib_none().0,
+            );
+        }
+    }
 
     #[doc(alias = "gtk_string_sorter_set_ignore_case")]
     pub fn set_ignore_case(&self, ignore_case: bool) {
@@ -120,9 +120,12 @@ impl Video {
     }
 
     #[doc(alias = "gtk_video_set_filename")]
-    pub fn set_filename(&self, filename: Option<&str>) {
+    pub fn set_filename<P: AsRef<std::path::Path>>(&self, filename: Option<P>) {
         unsafe {
-            ffi::gtk_video_set_filename(self.to_glib_none().0, filename.to_glib_none().0);
+            ffi::gtk_video_set_filename(
+                self.to_glib_none().0,
+                filename.as_ref().map(|p| p.as_ref()).to_glib_none().0,
+            );
         }
     }
 
@@ -1,20 +1,51 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::Builder;
-use glib::object::{Cast, IsA};
+use glib::object::{Cast, IsA, ObjectExt};
 use glib::translate::*;
-use glib::Object;
+use glib::{Object, StaticType};
+use std::error;
+use std::fmt;
 use std::path::Path;
 
 impl Builder {
+    // rustdoc-stripper-ignore-next
+    /// Builds the UI definition in `file_path`.
+    ///
+    /// Unlike `gtk_builder_new_from_file`, which aborts, this returns the
+    /// error of an invalid definition.
     #[doc(alias = "gtk_builder_new_from_file")]
-    pub fn new_from_file<T: AsRef<Path>>(file_path: T) -> Builder {
+    pub fn from_file<T: AsRef<Path>>(file_path: T) -> Result<Builder, glib::Error> {
         assert_initialized_main_thread!();
-        unsafe {
-            from_glib_full(ffi::gtk_builder_new_from_file(
-                file_path.as_ref().to_glib_none().0,
-            ))
-        }
+        let builder = Builder::new();
+        builder.add_from_file(file_path)?;
+        Ok(builder)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Builds the UI definition in the resource at `resource_path`.
+    ///
+    /// Unlike `gtk_builder_new_from_resource`, which aborts, this returns
+    /// the error of an invalid definition.
+    #[doc(alias = "gtk_builder_new_from_resource")]
+    pub fn from_resource(resource_path: &str) -> Result<Builder, glib::Error> {
+        assert_initialized_main_thread!();
+        let builder = Builder::new();
+        builder.add_from_resource(resource_path)?;
+        Ok(builder)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Builds the UI definition in `string`.
+    ///
+    /// Unlike `gtk_builder_new_from_string`, which aborts, this returns the
+    /// error of an invalid definition.
+    #[doc(alias = "gtk_builder_new_from_string")]
+    pub fn from_string(string: &str) -> Result<Builder, glib::Error> {
+        assert_initialized_main_thread!();
+        let builder = Builder::new();
+        builder.add_from_string(string)?;
+        Ok(builder)
     }
 }
 
@@ -22,19 +53,41 @@ pub trait BuilderExtManual: 'static {
     #[doc(alias = "gtk_builder_get_object")]
     fn get_object<T: IsA<Object>>(&self, name: &str) -> Option<T>;
 
+    // rustdoc-stripper-ignore-next
+    /// Like [`get_object`], but tells apart a missing object from one of
+    /// another type than `T`.
+    ///
+    /// [`get_object`]: #tymethod.get_object
+    #[doc(alias = "gtk_builder_get_object")]
+    fn try_object<T: IsA<Object>>(&self, name: &str) -> Result<T, BuilderObjectError>;
+
     #[doc(alias = "gtk_builder_add_from_file")]
     fn add_from_file<T: AsRef<Path>>(&self, file_path: T) -> Result<(), glib::Error>;
 }
 
 impl<O: IsA<Builder>> BuilderExtManual for O {
     fn get_object<T: IsA<Object>>(&self, name: &str) -> Option<T> {
-        unsafe {
+        self.try_object(name).ok()
+    }
+
+    fn try_object<T: IsA<Object>>(&self, name: &str) -> Result<T, BuilderObjectError> {
+        let object = unsafe {
             Option::<Object>::from_glib_none(ffi::gtk_builder_get_object(
                 self.upcast_ref().to_glib_none().0,
                 name.to_glib_none().0,
             ))
-            .and_then(|obj| obj.dynamic_cast::<T>().ok())
-        }
+        };
+        let object = object.ok_or_else(|| BuilderObjectError::NotFound {
+            id: name.to_owned(),
+        })?;
+        let actual = object.get_type();
+        object
+            .dynamic_cast::<T>()
+            .map_err(|_| BuilderObjectError::WrongType {
+                id: name.to_owned(),
+                expected: T::static_type(),
+                actual,
+            })
     }
 
     fn add_from_file<T: AsRef<Path>>(&self, file_path: T) -> Result<(), glib::Error> {
@@ -53,3 +106,40 @@ impl<O: IsA<Builder>> BuilderExtManual for O {
         }
     }
 }
+
+// rustdoc-stripper-ignore-next
+/// The reason [`BuilderExtManual::try_object`] didn't return an object.
+///
+/// [`BuilderExtManual::try_object`]: prelude/trait.BuilderExtManual.html#tymethod.try_object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuilderObjectError {
+    // rustdoc-stripper-ignore-next
+    /// The UI definition has no object with this id.
+    NotFound { id: String },
+    // rustdoc-stripper-ignore-next
+    /// The object with this id isn't of the expected type.
+    WrongType {
+        id: String,
+        expected: glib::Type,
+        actual: glib::Type,
+    },
+}
+
+impl fmt::Display for BuilderObjectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuilderObjectError::NotFound { id } => write!(f, "No object with id \"{}\"", id),
+            BuilderObjectError::WrongType {
+                id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Object with id \"{}\" is a {}, not a {}",
+                id, actual, expected
+            ),
+        }
+    }
+}
+
+impl error::Error for BuilderObjectError {}
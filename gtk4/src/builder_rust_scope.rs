@@ -0,0 +1,136 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::subclass::prelude::*;
+use crate::{Builder, BuilderClosureFlags, BuilderError, BuilderScope};
+use glib::{Object, ObjectExt, ToValue};
+use std::sync::Arc;
+
+mod imp {
+    use super::*;
+    use glib::subclass;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    pub(super) type Callback = Arc<dyn Fn(&[glib::Value]) -> Option<glib::Value> + Send + Sync>;
+
+    #[derive(Default)]
+    pub struct BuilderRustScope {
+        pub(super) callbacks: RefCell<HashMap<String, Callback>>,
+    }
+
+    impl ObjectSubclass for BuilderRustScope {
+        const NAME: &'static str = "GtkBuilderRustScope";
+        type Type = super::BuilderRustScope;
+        type ParentType = Object;
+        type Instance = subclass::simple::InstanceStruct<Self>;
+        type Class = subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn type_init(type_: &mut subclass::InitializingType<Self>) {
+            type_.add_interface::<BuilderScope>();
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for BuilderRustScope {}
+
+    impl BuilderScopeImpl for BuilderRustScope {
+        fn create_closure(
+            &self,
+            _builder_scope: &Self::Type,
+            builder: &Builder,
+            function_name: &str,
+            flags: BuilderClosureFlags,
+            object: Option<&Object>,
+        ) -> Result<glib::Closure, glib::Error> {
+            let callback = self
+                .callbacks
+                .borrow()
+                .get(function_name)
+                .cloned()
+                .ok_or_else(|| {
+                    glib::Error::new(
+                        BuilderError::InvalidFunction,
+                        &format!("No function named `{}`", function_name),
+                    )
+                })?;
+            let swapped = flags.contains(BuilderClosureFlags::SWAPPED);
+            // Like GtkBuilderCScope, fall back to the object being built,
+            // which is the widget for templates.
+            let object = object.cloned().or_else(|| builder.get_current_object());
+
+            Ok(match object {
+                Some(object) => {
+                    let object = glib::SendWeakRef::from(object.downgrade());
+                    glib::Closure::new(move |args| {
+                        let object = object.upgrade()?;
+                        let mut values = Vec::with_capacity(args.len() + 1);
+                        if swapped {
+                            values.push(object.to_value());
+                            values.extend(args.iter().skip(1).cloned());
+                            values.extend(args.first().cloned());
+                        } else {
+                            values.extend(args.iter().cloned());
+                            values.push(object.to_value());
+                        }
+                        callback(&values)
+                    })
+                }
+                None if swapped => glib::Closure::new(move |args| {
+                    let mut values = args.iter().skip(1).cloned().collect::<Vec<_>>();
+                    values.extend(args.first().cloned());
+                    callback(&values)
+                }),
+                None => glib::Closure::new(move |args| callback(args)),
+            })
+        }
+    }
+}
+
+glib::wrapper! {
+    // rustdoc-stripper-ignore-next
+    /// A [`BuilderScope`] resolving the handlers of `<signal>` elements to
+    /// Rust callbacks registered by name.
+    ///
+    /// The callbacks get the values of the signal arguments. The object of
+    /// the handler, by default the object being built, is passed as the last
+    /// argument, or as the first one for swapped handlers, like GtkBuilderCScope
+    /// does.
+    ///
+    /// Widget templates get one through
+    /// [`WidgetClassSubclassExt::add_template_callback`].
+    ///
+    /// [`BuilderScope`]: struct.BuilderScope.html
+    /// [`WidgetClassSubclassExt::add_template_callback`]: subclass/widget/trait.WidgetClassSubclassExt.html#method.add_template_callback
+    pub struct BuilderRustScope(ObjectSubclass<imp::BuilderRustScope>)
+        @implements BuilderScope;
+}
+
+impl BuilderRustScope {
+    pub fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create BuilderRustScope")
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Registers `callback` as the handler named `name`, replacing any
+    /// previous one.
+    pub fn add_callback<N: Into<String>, F>(&self, name: N, callback: F)
+    where
+        F: Fn(&[glib::Value]) -> Option<glib::Value> + Send + Sync + 'static,
+    {
+        let imp = imp::BuilderRustScope::from_instance(self);
+        imp.callbacks
+            .borrow_mut()
+            .insert(name.into(), Arc::new(callback));
+    }
+}
+
+impl Default for BuilderRustScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
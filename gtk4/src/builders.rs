@@ -0,0 +1,237 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::*;
+
+// The generated builders can only be created with `XBuilder::new()`, this
+// adds `X::builder()` for each of them.
+macro_rules! builder_fns {
+    ($($name:ident => $builder:ident,)*) => {
+        $(
+            impl $name {
+                // rustdoc-stripper-ignore-next
+                /// Creates a builder to set the properties of a new object
+                /// of this type.
+                pub fn builder() -> $builder {
+                    $builder::default()
+                }
+            }
+        )*
+    };
+}
+
+builder_fns! {
+    AboutDialog => AboutDialogBuilder,
+    ActionBar => ActionBarBuilder,
+    Adjustment => AdjustmentBuilder,
+    AlternativeTrigger => AlternativeTriggerBuilder,
+    AppChooserButton => AppChooserButtonBuilder,
+    AppChooserDialog => AppChooserDialogBuilder,
+    AppChooserWidget => AppChooserWidgetBuilder,
+    ApplicationWindow => ApplicationWindowBuilder,
+    AspectFrame => AspectFrameBuilder,
+    Assistant => AssistantBuilder,
+    AssistantPage => AssistantPageBuilder,
+    BookmarkList => BookmarkListBuilder,
+    BoolFilter => BoolFilterBuilder,
+    Box => BoxBuilder,
+    BoxLayout => BoxLayoutBuilder,
+    Builder => BuilderBuilder,
+    BuilderListItemFactory => BuilderListItemFactoryBuilder,
+    Button => ButtonBuilder,
+    Calendar => CalendarBuilder,
+    CellAreaBox => CellAreaBoxBuilder,
+    CellAreaContext => CellAreaContextBuilder,
+    CellRendererAccel => CellRendererAccelBuilder,
+    CellRendererCombo => CellRendererComboBuilder,
+    CellRendererPixbuf => CellRendererPixbufBuilder,
+    CellRendererProgress => CellRendererProgressBuilder,
+    CellRendererSpin => CellRendererSpinBuilder,
+    CellRendererSpinner => CellRendererSpinnerBuilder,
+    CellRendererText => CellRendererTextBuilder,
+    CellRendererToggle => CellRendererToggleBuilder,
+    CellView => CellViewBuilder,
+    CenterBox => CenterBoxBuilder,
+    CheckButton => CheckButtonBuilder,
+    ColorButton => ColorButtonBuilder,
+    ColorChooserDialog => ColorChooserDialogBuilder,
+    ColorChooserWidget => ColorChooserWidgetBuilder,
+    ColumnView => ColumnViewBuilder,
+    ColumnViewColumn => ColumnViewColumnBuilder,
+    ComboBox => ComboBoxBuilder,
+    ComboBoxText => ComboBoxTextBuilder,
+    Constraint => ConstraintBuilder,
+    ConstraintGuide => ConstraintGuideBuilder,
+    Dialog => DialogBuilder,
+    DirectoryList => DirectoryListBuilder,
+    DragIcon => DragIconBuilder,
+    DragSource => DragSourceBuilder,
+    DrawingArea => DrawingAreaBuilder,
+    DropDown => DropDownBuilder,
+    DropTarget => DropTargetBuilder,
+    DropTargetAsync => DropTargetAsyncBuilder,
+    Entry => EntryBuilder,
+    EntryBuffer => EntryBufferBuilder,
+    EntryCompletion => EntryCompletionBuilder,
+    EventControllerScroll => EventControllerScrollBuilder,
+    Expander => ExpanderBuilder,
+    FileChooserDialog => FileChooserDialogBuilder,
+    FileChooserNative => FileChooserNativeBuilder,
+    FileChooserWidget => FileChooserWidgetBuilder,
+    FileFilter => FileFilterBuilder,
+    FilterListModel => FilterListModelBuilder,
+    Fixed => FixedBuilder,
+    FixedLayoutChild => FixedLayoutChildBuilder,
+    FlattenListModel => FlattenListModelBuilder,
+    FlowBox => FlowBoxBuilder,
+    FlowBoxChild => FlowBoxChildBuilder,
+    FontButton => FontButtonBuilder,
+    FontChooserDialog => FontChooserDialogBuilder,
+    FontChooserWidget => FontChooserWidgetBuilder,
+    Frame => FrameBuilder,
+    GestureLongPress => GestureLongPressBuilder,
+    GesturePan => GesturePanBuilder,
+    GestureSingle => GestureSingleBuilder,
+    GLArea => GLAreaBuilder,
+    Grid => GridBuilder,
+    GridLayout => GridLayoutBuilder,
+    GridLayoutChild => GridLayoutChildBuilder,
+    GridView => GridViewBuilder,
+    HeaderBar => HeaderBarBuilder,
+    IconPaintable => IconPaintableBuilder,
+    IconTheme => IconThemeBuilder,
+    IconView => IconViewBuilder,
+    Image => ImageBuilder,
+    InfoBar => InfoBarBuilder,
+    Label => LabelBuilder,
+    LevelBar => LevelBarBuilder,
+    LinkButton => LinkButtonBuilder,
+    ListBox => ListBoxBuilder,
+    ListBoxRow => ListBoxRowBuilder,
+    ListItem => ListItemBuilder,
+    ListView => ListViewBuilder,
+    LockButton => LockButtonBuilder,
+    MapListModel => MapListModelBuilder,
+    MediaControls => MediaControlsBuilder,
+    MenuButton => MenuButtonBuilder,
+    MessageDialog => MessageDialogBuilder,
+    MountOperation => MountOperationBuilder,
+    MultiSelection => MultiSelectionBuilder,
+    NamedAction => NamedActionBuilder,
+    NoSelection => NoSelectionBuilder,
+    Notebook => NotebookBuilder,
+    NotebookPage => NotebookPageBuilder,
+    NumericSorter => NumericSorterBuilder,
+    Overlay => OverlayBuilder,
+    OverlayLayoutChild => OverlayLayoutChildBuilder,
+    PadController => PadControllerBuilder,
+    Paned => PanedBuilder,
+    PasswordEntry => PasswordEntryBuilder,
+    Picture => PictureBuilder,
+    Popover => PopoverBuilder,
+    PopoverMenu => PopoverMenuBuilder,
+    PopoverMenuBar => PopoverMenuBarBuilder,
+    PrintOperation => PrintOperationBuilder,
+    ProgressBar => ProgressBarBuilder,
+    Range => RangeBuilder,
+    RecentManager => RecentManagerBuilder,
+    Revealer => RevealerBuilder,
+    Scale => ScaleBuilder,
+    ScaleButton => ScaleButtonBuilder,
+    Scrollbar => ScrollbarBuilder,
+    ScrolledWindow => ScrolledWindowBuilder,
+    SearchBar => SearchBarBuilder,
+    SearchEntry => SearchEntryBuilder,
+    SelectionFilterModel => SelectionFilterModelBuilder,
+    Separator => SeparatorBuilder,
+    Settings => SettingsBuilder,
+    Shortcut => ShortcutBuilder,
+    ShortcutController => ShortcutControllerBuilder,
+    ShortcutLabel => ShortcutLabelBuilder,
+    ShortcutsGroup => ShortcutsGroupBuilder,
+    ShortcutsSection => ShortcutsSectionBuilder,
+    ShortcutsShortcut => ShortcutsShortcutBuilder,
+    ShortcutsWindow => ShortcutsWindowBuilder,
+    SignalAction => SignalActionBuilder,
+    SingleSelection => SingleSelectionBuilder,
+    SizeGroup => SizeGroupBuilder,
+    SliceListModel => SliceListModelBuilder,
+    SortListModel => SortListModelBuilder,
+    SpinButton => SpinButtonBuilder,
+    Spinner => SpinnerBuilder,
+    Stack => StackBuilder,
+    StackPage => StackPageBuilder,
+    StackSidebar => StackSidebarBuilder,
+    StackSwitcher => StackSwitcherBuilder,
+    Statusbar => StatusbarBuilder,
+    StringFilter => StringFilterBuilder,
+    StringSorter => StringSorterBuilder,
+    StyleContext => StyleContextBuilder,
+    Switch => SwitchBuilder,
+    Text => TextBuilder,
+    TextBuffer => TextBufferBuilder,
+    TextMark => TextMarkBuilder,
+    TextTag => TextTagBuilder,
+    TextView => TextViewBuilder,
+    ToggleButton => ToggleButtonBuilder,
+    TreeExpander => TreeExpanderBuilder,
+    TreeListModel => TreeListModelBuilder,
+    TreeListRow => TreeListRowBuilder,
+    TreeListRowSorter => TreeListRowSorterBuilder,
+    TreeModelFilter => TreeModelFilterBuilder,
+    TreeModelSort => TreeModelSortBuilder,
+    TreeSelection => TreeSelectionBuilder,
+    TreeView => TreeViewBuilder,
+    TreeViewColumn => TreeViewColumnBuilder,
+    Video => VideoBuilder,
+    Viewport => ViewportBuilder,
+    VolumeButton => VolumeButtonBuilder,
+    WidgetPaintable => WidgetPaintableBuilder,
+    Window => WindowBuilder,
+    WindowControls => WindowControlsBuilder,
+    WindowHandle => WindowHandleBuilder,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::any::Any;
+
+    // Builders are plain values, that can be kept, cloned and type erased before
+    // building any widget. Nothing is built so GTK isn't initialized, this only
+    // has to compile.
+    struct Buttons {
+        builder: ButtonBuilder,
+    }
+
+    impl Buttons {
+        fn new() -> Self {
+            Self {
+                builder: Button::builder()
+                    .label("Button")
+                    .margin_start(6)
+                    .hexpand(true)
+                    .css_classes(vec!["suggested-action".to_owned()]),
+            }
+        }
+
+        #[allow(dead_code)]
+        fn build(&self) -> Button {
+            self.builder.clone().build()
+        }
+    }
+
+    fn assert_storable<T: Clone + 'static>(_builder: &T) {}
+
+    #[test]
+    fn builders() {
+        let buttons = Buttons::new();
+        assert_storable(&buttons.builder);
+
+        let builders: Vec<Box<dyn Any>> = vec![
+            Box::new(buttons.builder.clone()),
+            Box::new(Window::builder().title("Builders").default_width(200)),
+            Box::new(Label::builder().label("Label").halign(Align::Start)),
+        ];
+        assert!(builders[1].downcast_ref::<WindowBuilder>().is_some());
+    }
+}
@@ -0,0 +1,39 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{CallbackAction, Widget};
+use glib::translate::*;
+
+impl CallbackAction {
+    // rustdoc-stripper-ignore-next
+    /// Creates an action calling `callback` with the widget it is activated
+    /// on, and the arguments of the shortcut if it has any.
+    ///
+    /// `callback` returns whether the action was activated successfully.
+    #[doc(alias = "gtk_callback_action_new")]
+    pub fn new<F: Fn(&Widget, Option<&glib::Variant>) -> bool + 'static>(callback: F) -> Self {
+        assert_initialized_main_thread!();
+        unsafe {
+            from_glib_full(ffi::gtk_callback_action_new(
+                Some(trampoline::<F>),
+                Box::into_raw(Box::new(callback)) as *mut _,
+                Some(destroy_closure::<F>),
+            ))
+        }
+    }
+}
+
+unsafe extern "C" fn destroy_closure<F: Fn(&Widget, Option<&glib::Variant>) -> bool + 'static>(
+    ptr: glib::ffi::gpointer,
+) {
+    Box::<F>::from_raw(ptr as *mut _);
+}
+
+unsafe extern "C" fn trampoline<F: Fn(&Widget, Option<&glib::Variant>) -> bool + 'static>(
+    widget: *mut ffi::GtkWidget,
+    args: *mut glib::ffi::GVariant,
+    f: glib::ffi::gpointer,
+) -> glib::ffi::gboolean {
+    let f: &F = &*(f as *const F);
+    let args: Borrowed<Option<glib::Variant>> = from_glib_borrow(args);
+    f(&from_glib_borrow(widget), args.as_ref().as_ref()).to_glib()
+}
@@ -0,0 +1,72 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{
+    Constraint, ConstraintAttribute, ConstraintRelation, ConstraintStrength, ConstraintTarget,
+};
+use glib::translate::*;
+use glib::IsA;
+
+impl Constraint {
+    // rustdoc-stripper-ignore-next
+    /// Creates a constraint of the form
+    /// `target.target_attribute relation source.source_attribute * multiplier + constant`.
+    ///
+    /// A `None` target or source is the widget using the layout. Any strength
+    /// between `ConstraintStrength::Weak` and `ConstraintStrength::Strong`
+    /// can be given with [`ConstraintStrength::custom`].
+    ///
+    /// [`ConstraintStrength::custom`]: enum.ConstraintStrength.html#method.custom
+    #[doc(alias = "gtk_constraint_new")]
+    pub fn new<P: IsA<ConstraintTarget>, Q: IsA<ConstraintTarget>>(
+        target: Option<&P>,
+        target_attribute: ConstraintAttribute,
+        relation: ConstraintRelation,
+        source: Option<&Q>,
+        source_attribute: ConstraintAttribute,
+        multiplier: f64,
+        constant: f64,
+        strength: ConstraintStrength,
+    ) -> Constraint {
+        assert_initialized_main_thread!();
+        unsafe {
+            from_glib_full(ffi::gtk_constraint_new(
+                target.map(|p| p.as_ref()).to_glib_none().0,
+                target_attribute.to_glib(),
+                relation.to_glib(),
+                source.map(|p| p.as_ref()).to_glib_none().0,
+                source_attribute.to_glib(),
+                multiplier,
+                constant,
+                strength.to_glib(),
+            ))
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a constraint of the form
+    /// `target.target_attribute relation constant`.
+    #[doc(alias = "gtk_constraint_new_constant")]
+    pub fn new_constant<P: IsA<ConstraintTarget>>(
+        target: Option<&P>,
+        target_attribute: ConstraintAttribute,
+        relation: ConstraintRelation,
+        constant: f64,
+        strength: ConstraintStrength,
+    ) -> Constraint {
+        assert_initialized_main_thread!();
+        unsafe {
+            from_glib_full(ffi::gtk_constraint_new_constant(
+                target.map(|p| p.as_ref()).to_glib_none().0,
+                target_attribute.to_glib(),
+                relation.to_glib(),
+                constant,
+                strength.to_glib(),
+            ))
+        }
+    }
+
+    #[doc(alias = "gtk_constraint_get_strength")]
+    pub fn get_strength(&self) -> ConstraintStrength {
+        unsafe { from_glib(ffi::gtk_constraint_get_strength(self.to_glib_none().0)) }
+    }
+}
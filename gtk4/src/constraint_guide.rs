@@ -2,47 +2,41 @@
 
 use crate::ConstraintGuide;
 use glib::translate::*;
-use glib::IsA;
 
-pub trait ConstraintGuideExtManual {
+impl ConstraintGuide {
+    // rustdoc-stripper-ignore-next
+    /// Returns the maximum width and height of the guide.
     #[doc(alias = "gtk_constraint_guide_get_max_size")]
-    fn get_max_size(&self, width: i32, height: i32);
-
-    #[doc(alias = "gtk_constraint_guide_get_min_size")]
-    fn get_min_size(&self, width: i32, height: i32);
-
-    #[doc(alias = "gtk_constraint_guide_get_nat_size")]
-    fn get_nat_size(&self, width: i32, height: i32);
-}
-
-impl<O: IsA<ConstraintGuide>> ConstraintGuideExtManual for O {
-    fn get_max_size(&self, width: i32, height: i32) {
+    pub fn get_max_size(&self) -> (i32, i32) {
         unsafe {
-            ffi::gtk_constraint_guide_get_max_size(
-                self.as_ref().to_glib_none().0,
-                width as *mut _,
-                height as *mut _,
-            );
+            let mut width = 0;
+            let mut height = 0;
+            ffi::gtk_constraint_guide_get_max_size(self.to_glib_none().0, &mut width, &mut height);
+            (width, height)
         }
     }
 
-    fn get_min_size(&self, width: i32, height: i32) {
+    // rustdoc-stripper-ignore-next
+    /// Returns the minimum width and height of the guide.
+    #[doc(alias = "gtk_constraint_guide_get_min_size")]
+    pub fn get_min_size(&self) -> (i32, i32) {
         unsafe {
-            ffi::gtk_constraint_guide_get_min_size(
-                self.as_ref().to_glib_none().0,
-                width as *mut _,
-                height as *mut _,
-            );
+            let mut width = 0;
+            let mut height = 0;
+            ffi::gtk_constraint_guide_get_min_size(self.to_glib_none().0, &mut width, &mut height);
+            (width, height)
         }
     }
 
-    fn get_nat_size(&self, width: i32, height: i32) {
+    // rustdoc-stripper-ignore-next
+    /// Returns the natural width and height of the guide.
+    #[doc(alias = "gtk_constraint_guide_get_nat_size")]
+    pub fn get_nat_size(&self) -> (i32, i32) {
         unsafe {
-            ffi::gtk_constraint_guide_get_nat_size(
-                self.as_ref().to_glib_none().0,
-                width as *mut _,
-                height as *mut _,
-            );
+            let mut width = 0;
+            let mut height = 0;
+            ffi::gtk_constraint_guide_get_nat_size(self.to_glib_none().0, &mut width, &mut height);
+            (width, height)
         }
     }
 }
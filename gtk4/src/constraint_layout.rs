@@ -0,0 +1,59 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Constraint, ConstraintLayout, ConstraintTarget};
+use glib::translate::*;
+use std::ptr;
+
+impl ConstraintLayout {
+    // rustdoc-stripper-ignore-next
+    /// Creates constraints from a description in the [Visual Format Language]
+    /// and adds them to the layout.
+    ///
+    /// `views` maps the view names used in `lines` to the corresponding
+    /// targets, widgets or guides. Returns the parser error, usually in the
+    /// `ConstraintVflParserError` domain, if the description is invalid.
+    ///
+    /// [Visual Format Language]: https://developer.gnome.org/gtk4/stable/GtkConstraintLayout.html#vfl-syntax
+    #[doc(alias = "gtk_constraint_layout_add_constraints_from_descriptionv")]
+    pub fn add_constraints_from_description(
+        &self,
+        lines: &[&str],
+        hspacing: i32,
+        vspacing: i32,
+        views: &[(&str, &ConstraintTarget)],
+    ) -> Result<Vec<Constraint>, glib::Error> {
+        unsafe {
+            let hash_table = glib::ffi::g_hash_table_new_full(
+                Some(glib::ffi::g_str_hash),
+                Some(glib::ffi::g_str_equal),
+                Some(glib::ffi::g_free),
+                None,
+            );
+            for &(name, view) in views {
+                glib::ffi::g_hash_table_insert(
+                    hash_table,
+                    name.to_glib_full() as glib::ffi::gpointer,
+                    view.to_glib_none().0 as glib::ffi::gpointer,
+                );
+            }
+
+            let mut error = ptr::null_mut();
+            let ret = ffi::gtk_constraint_layout_add_constraints_from_descriptionv(
+                self.to_glib_none().0,
+                lines.to_glib_none().0,
+                lines.len(),
+                hspacing,
+                vspacing,
+                hash_table,
+                &mut error,
+            );
+            glib::ffi::g_hash_table_unref(hash_table);
+
+            if error.is_null() {
+                Ok(FromGlibPtrContainer::from_glib_container(ret))
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::CssProvider;
+use glib::{IsA, ObjectExt};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+impl CssProvider {
+    // rustdoc-stripper-ignore-next
+    /// Like [`load_from_data`], but returns the first error reported through
+    /// the `parsing-error` signal while loading.
+    ///
+    /// The provider still holds the rules that could be parsed.
+    ///
+    /// [`load_from_data`]: #method.load_from_data
+    pub fn load_from_data_checked(&self, data: &[u8]) -> Result<(), glib::Error> {
+        self.load_checked(|provider| provider.load_from_data(data))
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`load_from_file`], but returns the first error reported through
+    /// the `parsing-error` signal while loading.
+    ///
+    /// [`load_from_file`]: #method.load_from_file
+    pub fn load_from_file_checked<P: IsA<gio::File>>(&self, file: &P) -> Result<(), glib::Error> {
+        self.load_checked(|provider| provider.load_from_file(file))
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`load_from_path`], but returns the first error reported through
+    /// the `parsing-error` signal while loading.
+    ///
+    /// [`load_from_path`]: #method.load_from_path
+    pub fn load_from_path_checked(&self, path: &str) -> Result<(), glib::Error> {
+        self.load_checked(|provider| provider.load_from_path(path))
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`load_from_resource`], but returns the first error reported
+    /// through the `parsing-error` signal while loading.
+    ///
+    /// [`load_from_resource`]: #method.load_from_resource
+    pub fn load_from_resource_checked(&self, resource_path: &str) -> Result<(), glib::Error> {
+        self.load_checked(|provider| provider.load_from_resource(resource_path))
+    }
+
+    // Loading is synchronous, the errors are all reported before `load`
+    // returns.
+    fn load_checked<F: FnOnce(&Self)>(&self, load: F) -> Result<(), glib::Error> {
+        let first_error = Rc::new(RefCell::new(None));
+        let first_error_clone = first_error.clone();
+        let handler = self.connect_parsing_error(move |_, _, error| {
+            let mut first_error = first_error_clone.borrow_mut();
+            if first_error.is_none() {
+                *first_error = Some(error.clone());
+            }
+        });
+        load(self);
+        self.disconnect(handler);
+
+        let error = first_error.borrow_mut().take();
+        match error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::ptr::NonNull;
+
+// rustdoc-stripper-ignore-next
+/// The changes to the style of a widget, given to
+/// [`WidgetImpl::css_changed`].
+///
+/// GTK has no API telling what changed, it can only be passed on to the
+/// implementation of the parent with
+/// [`WidgetImplExt::parent_css_changed`].
+///
+/// [`WidgetImpl::css_changed`]: subclass/widget/trait.WidgetImpl.html#method.css_changed
+/// [`WidgetImplExt::parent_css_changed`]: subclass/widget/trait.WidgetImplExt.html#tymethod.parent_css_changed
+#[derive(Debug)]
+pub struct CssStyleChange(NonNull<ffi::GtkCssStyleChange>);
+
+impl CssStyleChange {
+    // GTK passes no change when the style of the widget is recomputed
+    // entirely, like when it is created.
+    pub(crate) unsafe fn from_ptr(ptr: *mut ffi::GtkCssStyleChange) -> Option<Self> {
+        NonNull::new(ptr).map(CssStyleChange)
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut ffi::GtkCssStyleChange {
+        self.0.as_ptr()
+    }
+}
@@ -1,9 +1,11 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Dialog, DialogExt, DialogFlags, ResponseType, Widget, WidgetExt, Window};
-use glib::object::Cast;
+use crate::{
+    Dialog, DialogExt, DialogFlags, GtkWindowExt, ResponseType, Widget, WidgetExt, Window,
+};
+use glib::object::{Cast, ObjectType};
 use glib::translate::*;
-use glib::{IsA, ObjectExt};
+use glib::{IsA, ObjectExt, SignalHandlerId};
 use std::cell::{Cell, RefCell};
 use std::future::Future;
 use std::pin::Pin;
@@ -45,6 +47,11 @@ pub trait DialogExtManual: 'static {
     /// Shows the dialog and returns a `Future` that resolves to the
     /// `ResponseType` on response.
     ///
+    /// The dialog is hidden once the response has been received, so it is
+    /// left to the caller to close it or show it again. If it is destroyed
+    /// before that, the `Future` resolves to `ResponseType::None`. It keeps
+    /// a strong reference to the dialog until it resolves.
+    ///
     /// ```no_run
     /// use gtk4::prelude::*;
     ///
@@ -63,6 +70,8 @@ pub trait DialogExtManual: 'static {
 
     // rustdoc-stripper-ignore-next
     /// Shows the dialog and calls the callback when a response has been received.
+    /// The dialog is hidden before the callback is called, which is left to
+    /// close it or show it again, and kept alive until then.
     ///
     /// **Important**: this function isn't blocking.
     ///
@@ -100,38 +109,95 @@ impl<O: IsA<Dialog> + IsA<Widget>> DialogExtManual for O {
     }
 
     fn run_future<'a>(&'a self) -> Pin<Box<dyn Future<Output = ResponseType> + 'a>> {
-        Box::pin(async move {
-            let (sender, receiver) = futures_channel::oneshot::channel();
+        Box::pin(run_dialog_future(self.upcast_ref::<Dialog>().clone()))
+    }
+
+    fn run_async<F: FnOnce(&Self, ResponseType) + 'static>(&self, f: F) {
+        run_dialog_async(self.upcast_ref::<Dialog>(), move |dialog, response_type| {
+            f(dialog.downcast_ref::<O>().unwrap(), response_type)
+        });
+    }
+}
 
-            let sender = Cell::new(Some(sender));
+// The dialogs emitting `response`, `Dialog` and `NativeDialog`, which share
+// the implementation of `run_future` and `run_async`.
+pub(crate) trait ResponseDialog: ObjectType + Clone {
+    fn dialog_connect_response<F: Fn(&Self, ResponseType) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId;
+    fn dialog_set_transient_for(&self, parent: Option<&Window>);
+    fn dialog_show(&self);
+    fn dialog_hide(&self);
+}
 
-            let response_handler = self.connect_response(move |_, response_type| {
-                if let Some(m) = sender.replace(None) {
-                    let _result = m.send(response_type);
-                }
-            });
+impl ResponseDialog for Dialog {
+    fn dialog_connect_response<F: Fn(&Self, ResponseType) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        self.connect_response(f)
+    }
 
-            self.show();
+    fn dialog_set_transient_for(&self, parent: Option<&Window>) {
+        self.set_transient_for(parent)
+    }
 
-            if let Ok(response) = receiver.await {
-                self.disconnect(response_handler);
-                response
-            } else {
-                ResponseType::None
-            }
-        })
+    fn dialog_show(&self) {
+        self.show()
     }
 
-    fn run_async<F: FnOnce(&Self, ResponseType) + 'static>(&self, f: F) {
-        let response_handler = Rc::new(RefCell::new(None));
-        let response_handler_clone = response_handler.clone();
-        let f = RefCell::new(Some(f));
-        *response_handler.borrow_mut() = Some(self.connect_response(move |s, response_type| {
+    fn dialog_hide(&self) {
+        self.hide()
+    }
+}
+
+// Shows `dialog` and resolves to its response, hiding it. Resolves to
+// `ResponseType::None` if the dialog is destroyed before responding.
+pub(crate) async fn run_dialog_future<D: ResponseDialog>(dialog: D) -> ResponseType {
+    let (sender, receiver) = futures_channel::oneshot::channel();
+
+    let sender = Cell::new(Some(sender));
+
+    let response_handler = dialog.dialog_connect_response(move |_, response_type| {
+        if let Some(m) = sender.replace(None) {
+            let _result = m.send(response_type);
+        }
+    });
+
+    dialog.dialog_show();
+
+    if let Ok(response) = receiver.await {
+        dialog.disconnect(response_handler);
+        dialog.dialog_hide();
+        response
+    } else {
+        // The handler was dropped without being called, the dialog has been
+        // destroyed.
+        ResponseType::None
+    }
+}
+
+// Shows `dialog` and calls `f` with its response, after hiding it.
+pub(crate) fn run_dialog_async<D: ResponseDialog, F: FnOnce(&D, ResponseType) + 'static>(
+    dialog: &D,
+    f: F,
+) {
+    let response_handler = Rc::new(RefCell::new(None));
+    let response_handler_clone = response_handler.clone();
+    let f = RefCell::new(Some(f));
+    // Keep the dialog alive until a response has been received, the
+    // reference is released together with the handler.
+    let dialog_ref = RefCell::new(Some(dialog.clone()));
+    *response_handler.borrow_mut() = Some(dialog.dialog_connect_response(
+        move |dialog, response_type| {
+            let _dialog = dialog_ref.borrow_mut().take();
             if let Some(handler) = response_handler_clone.borrow_mut().take() {
-                s.disconnect(handler);
+                dialog.disconnect(handler);
             }
-            (*f.borrow_mut()).take().expect("cannot get callback")(s, response_type);
-        }));
-        self.show();
-    }
+            dialog.dialog_hide();
+            (*f.borrow_mut()).take().expect("cannot get callback")(dialog, response_type);
+        },
+    ));
+    dialog.dialog_show();
 }
@@ -1,13 +1,35 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::prelude::{DrawingAreaExt, WidgetExt};
 use crate::DrawingArea;
-use glib::object::IsA;
+use glib::object::{IsA, ObjectExt};
 use glib::translate::*;
+use std::cell::{Cell, RefCell};
+use std::future::Future;
 use std::ptr;
+use std::rc::Rc;
 
 pub trait DrawingAreaExtManual: 'static {
     fn unset_draw_func(&self);
+
+    // rustdoc-stripper-ignore-next
+    /// Sets a draw function that renders the frames asynchronously.
+    ///
+    /// `draw_func` is called with the size of the drawing area and its
+    /// future is spawned on the default main context. Once it resolves, the
+    /// returned surface is kept and a redraw is queued. Every paint shows the
+    /// last completed frame, at the top left corner of the area, and starts
+    /// rendering the next one unless a future is still pending.
+    ///
+    /// As a new frame is requested right after the previous one is shown,
+    /// the future is expected to wait for new data, like from a channel,
+    /// before rendering.
+    fn set_draw_func_async<F, Fut>(&self, draw_func: F)
+    where
+        F: Fn(&DrawingArea, i32, i32) -> Fut + 'static,
+        Fut: Future<Output = cairo::Surface> + 'static;
 }
+
 impl<O: IsA<DrawingArea>> DrawingAreaExtManual for O {
     fn unset_draw_func(&self) {
         unsafe {
@@ -19,4 +41,36 @@ impl<O: IsA<DrawingArea>> DrawingAreaExtManual for O {
             )
         }
     }
+
+    fn set_draw_func_async<F, Fut>(&self, draw_func: F)
+    where
+        F: Fn(&DrawingArea, i32, i32) -> Fut + 'static,
+        Fut: Future<Output = cairo::Surface> + 'static,
+    {
+        let frame = Rc::new(RefCell::new(None::<cairo::Surface>));
+        let pending = Rc::new(Cell::new(false));
+
+        self.as_ref()
+            .set_draw_func(move |drawing_area, cr, width, height| {
+                if let Some(ref frame) = *frame.borrow() {
+                    cr.set_source_surface(frame, 0.0, 0.0);
+                    cr.paint();
+                }
+
+                if pending.replace(true) {
+                    return;
+                }
+                let future = draw_func(drawing_area, width, height);
+                let frame = frame.clone();
+                let pending = pending.clone();
+                let drawing_area = drawing_area.downgrade();
+                glib::MainContext::default().spawn_local(async move {
+                    frame.replace(Some(future.await));
+                    pending.set(false);
+                    if let Some(drawing_area) = drawing_area.upgrade() {
+                        drawing_area.queue_draw();
+                    }
+                });
+            });
+    }
 }
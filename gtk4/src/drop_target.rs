@@ -0,0 +1,105 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::DropTarget;
+use glib::object::ObjectType as ObjectType_;
+use glib::SignalHandlerId;
+use glib::{signal::connect_raw, translate::*};
+use std::boxed::Box as Box_;
+use std::mem::transmute;
+use std::slice;
+
+impl DropTarget {
+    // rustdoc-stripper-ignore-next
+    /// Sets the types of the values this target accepts, an empty slice
+    /// means none.
+    #[doc(alias = "gtk_drop_target_set_gtypes")]
+    pub fn set_types(&self, types: &[glib::Type]) {
+        let mut types = types.iter().map(|t| t.to_glib()).collect::<Vec<_>>();
+        unsafe {
+            ffi::gtk_drop_target_set_gtypes(self.to_glib_none().0, types.as_mut_ptr(), types.len());
+        }
+    }
+
+    #[doc(alias = "gtk_drop_target_get_gtypes")]
+    pub fn get_types(&self) -> Vec<glib::Type> {
+        unsafe {
+            let mut n_types = 0;
+            let types = ffi::gtk_drop_target_get_gtypes(self.to_glib_none().0, &mut n_types);
+            if types.is_null() {
+                return Vec::new();
+            }
+            slice::from_raw_parts(types, n_types)
+                .iter()
+                .map(|t| from_glib(*t))
+                .collect()
+        }
+    }
+
+    pub fn connect_drop<F: Fn(&DropTarget, &glib::Value, f64, f64) -> bool + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        unsafe extern "C" fn drop_trampoline<
+            F: Fn(&DropTarget, &glib::Value, f64, f64) -> bool + 'static,
+        >(
+            this: *mut ffi::GtkDropTarget,
+            value: *const glib::gobject_ffi::GValue,
+            x: libc::c_double,
+            y: libc::c_double,
+            f: glib::ffi::gpointer,
+        ) -> glib::ffi::gboolean {
+            let f: &F = &*(f as *const F);
+            f(
+                &from_glib_borrow(this),
+                &*(value as *const glib::Value),
+                x,
+                y,
+            )
+            .to_glib()
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(
+                self.as_ptr() as *mut _,
+                b"drop\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(
+                    drop_trampoline::<F> as *const (),
+                )),
+                Box_::into_raw(f),
+            )
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`connect_drop`], but `f` is only called for dropped strings.
+    /// Other values are left to the other handlers.
+    ///
+    /// [`connect_drop`]: #method.connect_drop
+    pub fn connect_drop_text<F: Fn(&DropTarget, &str, f64, f64) -> bool + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        self.connect_drop(move |target, value, x, y| match value.get::<&str>() {
+            Ok(Some(text)) => f(target, text, x, y),
+            _ => false,
+        })
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`connect_drop`], but `f` is only called for dropped
+    /// [`gdk::FileList`]s. Other values are left to the other handlers.
+    ///
+    /// [`connect_drop`]: #method.connect_drop
+    /// [`gdk::FileList`]: ../gdk/struct.FileList.html
+    pub fn connect_drop_files<F: Fn(&DropTarget, Vec<gio::File>, f64, f64) -> bool + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        self.connect_drop(
+            move |target, value, x, y| match value.get::<gdk::FileList>() {
+                Ok(Some(files)) => f(target, files.files(), x, y),
+                _ => false,
+            },
+        )
+    }
+}
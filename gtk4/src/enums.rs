@@ -1,6 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::Ordering;
+use crate::{ConstraintStrength, Ordering};
+use glib::translate::*;
 use std::cmp;
 
 impl From<cmp::Ordering> for Ordering {
@@ -25,3 +26,21 @@ impl From<Ordering> for cmp::Ordering {
         }
     }
 }
+
+impl ConstraintStrength {
+    // rustdoc-stripper-ignore-next
+    /// A strength between the named ones, for example between
+    /// `ConstraintStrength::Weak` and `ConstraintStrength::Strong`.
+    ///
+    /// The value of a named strength gives that strength back.
+    pub fn custom(strength: i32) -> Self {
+        skip_assert_initialized!();
+        unsafe { from_glib(strength) }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// The numeric value of the strength, stronger ones being larger.
+    pub fn value(&self) -> i32 {
+        self.to_glib()
+    }
+}
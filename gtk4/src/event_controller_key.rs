@@ -3,10 +3,25 @@
 use crate::EventControllerKey;
 use gdk::keys::Key;
 use glib::object::ObjectType as ObjectType_;
-use glib::SignalHandlerId;
 use glib::{signal::connect_raw, translate::*};
+use glib::{ObjectExt, Quark, SignalHandlerId};
+use once_cell::sync::Lazy;
 use std::boxed::Box as Box_;
+use std::cell::RefCell;
+use std::collections::HashSet;
 use std::mem::transmute;
+use std::rc::Rc;
+
+static KEY_REPEAT_QUARK: Lazy<Quark> =
+    Lazy::new(|| Quark::from_string("gtk4-rs-event-controller-key-repeat"));
+
+// The keys held down, to tell apart auto-repeated presses, shared by all the
+// handlers of a controller.
+#[derive(Debug, Default)]
+struct KeyRepeat {
+    pressed_keycodes: HashSet<u32>,
+    is_repeat: bool,
+}
 
 impl EventControllerKey {
     pub fn connect_key_pressed<
@@ -81,4 +96,54 @@ impl EventControllerKey {
             )
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`connect_key_pressed`], with an additional argument that is
+    /// `true` when the press is an auto-repeat of a key held down.
+    ///
+    /// GDK doesn't flag repeated events, a press is considered repeated when
+    /// no release of the same keycode came in between. A key released while
+    /// the controller doesn't get the events, like once its widget lost the
+    /// focus, gets its next press reported as repeated.
+    ///
+    /// [`connect_key_pressed`]: #method.connect_key_pressed
+    pub fn connect_key_pressed_with_repeat<
+        F: Fn(&EventControllerKey, Key, u32, gdk::ModifierType, bool) -> glib::signal::Inhibit
+            + 'static,
+    >(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        let key_repeat = self.key_repeat();
+        self.connect_key_pressed(move |controller, keyval, keycode, state| {
+            let is_repeat = key_repeat.borrow().is_repeat;
+            f(controller, keyval, keycode, state, is_repeat)
+        })
+    }
+
+    // Connects the handlers keeping track of the keys held down, before the
+    // first handler that needs it.
+    fn key_repeat(&self) -> Rc<RefCell<KeyRepeat>> {
+        unsafe {
+            if let Some(key_repeat) = self.get_qdata::<Rc<RefCell<KeyRepeat>>>(*KEY_REPEAT_QUARK) {
+                return key_repeat.clone();
+            }
+        }
+
+        let key_repeat = Rc::new(RefCell::new(KeyRepeat::default()));
+        let pressed = key_repeat.clone();
+        self.connect_key_pressed(move |_, _, keycode, _| {
+            let mut pressed = pressed.borrow_mut();
+            pressed.is_repeat = !pressed.pressed_keycodes.insert(keycode);
+            glib::signal::Inhibit(false)
+        });
+        let released = key_repeat.clone();
+        self.connect_key_released(move |_, _, keycode, _| {
+            released.borrow_mut().pressed_keycodes.remove(&keycode);
+        });
+        unsafe {
+            self.set_qdata(*KEY_REPEAT_QUARK, key_repeat.clone());
+        }
+        key_repeat
+    }
 }
@@ -0,0 +1,387 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use glib::object::IsA;
+use glib::translate::*;
+use glib::{Object, StaticType, ToValue};
+use libc::c_uint;
+use std::boxed::Box as Box_;
+use std::fmt;
+use std::ops::Deref;
+use std::ptr;
+use std::slice;
+
+glib::wrapper! {
+    // rustdoc-stripper-ignore-next
+    /// A value computed from an object, used by sorters, filters and
+    /// widgets like [`DropDown`] to get what they need from list items.
+    ///
+    /// The expressions are built with [`PropertyExpression`],
+    /// [`ConstantExpression`], [`ObjectExpression`] and
+    /// [`ClosureExpression`], which all dereference to `Expression`.
+    ///
+    /// [`DropDown`]: struct.DropDown.html
+    /// [`PropertyExpression`]: struct.PropertyExpression.html
+    /// [`ConstantExpression`]: struct.ConstantExpression.html
+    /// [`ObjectExpression`]: struct.ObjectExpression.html
+    /// [`ClosureExpression`]: struct.ClosureExpression.html
+    #[derive(Debug)]
+    pub struct Expression(Shared<ffi::GtkExpression>);
+
+    match fn {
+        ref => |ptr| ffi::gtk_expression_ref(ptr),
+        unref => |ptr| ffi::gtk_expression_unref(ptr),
+        get_type => || ffi::gtk_expression_get_type(),
+    }
+}
+
+impl Expression {
+    #[doc(alias = "gtk_expression_get_value_type")]
+    pub fn get_value_type(&self) -> glib::Type {
+        unsafe { from_glib(ffi::gtk_expression_get_value_type(self.to_glib_none().0)) }
+    }
+
+    #[doc(alias = "gtk_expression_is_static")]
+    pub fn is_static(&self) -> bool {
+        unsafe { from_glib(ffi::gtk_expression_is_static(self.to_glib_none().0)) }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Evaluates the expression for `this`, returns `None` if it can't be
+    /// evaluated, for example when `this` is needed but is `None`.
+    #[doc(alias = "gtk_expression_evaluate")]
+    pub fn evaluate<T: IsA<Object>>(&self, this: Option<&T>) -> Option<glib::Value> {
+        unsafe {
+            let mut value = glib::Value::uninitialized();
+            let ret = from_glib(ffi::gtk_expression_evaluate(
+                self.to_glib_none().0,
+                this.map(|t| t.as_ref()).to_glib_none().0,
+                value.to_glib_none_mut().0,
+            ));
+            if ret {
+                Some(value)
+            } else {
+                None
+            }
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Keeps `property` of `target` set to the value of the expression for
+    /// `this`, until the returned watch is unwatched or `target` is
+    /// finalized.
+    #[doc(alias = "gtk_expression_bind")]
+    pub fn bind<T: IsA<Object>, U: IsA<Object>>(
+        &self,
+        target: &T,
+        property: &str,
+        this: Option<&U>,
+    ) -> ExpressionWatch {
+        unsafe {
+            // The watch is transfer none, and `bind` takes the expression.
+            let watch = ffi::gtk_expression_bind(
+                self.to_glib_full(),
+                target.as_ref().to_glib_none().0,
+                property.to_glib_none().0,
+                this.map(|t| t.as_ref()).to_glib_none().0,
+            );
+            ExpressionWatch::from_raw_none(watch as *mut ffi::_GtkExpressionWatch)
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Calls `notify` whenever the value of the expression for `this` may
+    /// have changed, until the returned watch is unwatched.
+    #[doc(alias = "gtk_expression_watch")]
+    pub fn watch<T: IsA<Object>, F: Fn() + 'static>(
+        &self,
+        this: Option<&T>,
+        notify: F,
+    ) -> ExpressionWatch {
+        unsafe extern "C" fn notify_trampoline<F: Fn() + 'static>(user_data: glib::ffi::gpointer) {
+            let f: &F = &*(user_data as *const F);
+            f()
+        }
+        unsafe extern "C" fn destroy_notify<F: Fn() + 'static>(user_data: glib::ffi::gpointer) {
+            Box_::from_raw(user_data as *mut F);
+        }
+
+        unsafe {
+            let watch = ffi::gtk_expression_watch(
+                self.to_glib_none().0,
+                this.map(|t| t.as_ref()).to_glib_none().0,
+                Some(notify_trampoline::<F>),
+                Box_::into_raw(Box_::new(notify)) as glib::ffi::gpointer,
+                Some(destroy_notify::<F>),
+            );
+            // The watch is transfer none, GTK keeps a reference until it is
+            // unwatched.
+            ExpressionWatch::from_raw_none(watch as *mut ffi::_GtkExpressionWatch)
+        }
+    }
+}
+
+impl AsRef<Expression> for Expression {
+    fn as_ref(&self) -> &Expression {
+        self
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// A watch returned by [`Expression::bind`] and [`Expression::watch`].
+///
+/// It holds its own reference on the watch, so dropping it doesn't stop
+/// watching the expression, only [`unwatch`] does.
+///
+/// [`Expression::bind`]: struct.Expression.html#method.bind
+/// [`Expression::watch`]: struct.Expression.html#method.watch
+/// [`unwatch`]: #method.unwatch
+pub struct ExpressionWatch(ptr::NonNull<ffi::_GtkExpressionWatch>);
+
+// The GIR data declares GtkExpressionWatch as a pointer type, so the
+// functions take a pointer to a pointer and the casts below are needed.
+impl ExpressionWatch {
+    unsafe fn from_raw_full(watch: *mut ffi::_GtkExpressionWatch) -> Self {
+        ExpressionWatch(ptr::NonNull::new(watch).expect("GTK returned no expression watch"))
+    }
+
+    unsafe fn from_raw_none(watch: *mut ffi::_GtkExpressionWatch) -> Self {
+        ffi::gtk_expression_watch_ref(watch as *mut ffi::GtkExpressionWatch);
+        Self::from_raw_full(watch)
+    }
+
+    fn as_ptr(&self) -> *mut ffi::GtkExpressionWatch {
+        self.0.as_ptr() as *mut ffi::GtkExpressionWatch
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Evaluates the watched expression, returns `None` if it can't be
+    /// evaluated or the watch was unwatched.
+    #[doc(alias = "gtk_expression_watch_evaluate")]
+    pub fn evaluate(&self) -> Option<glib::Value> {
+        unsafe {
+            let mut value = glib::Value::uninitialized();
+            let ret = from_glib(ffi::gtk_expression_watch_evaluate(
+                self.as_ptr(),
+                value.to_glib_none_mut().0,
+            ));
+            if ret {
+                Some(value)
+            } else {
+                None
+            }
+        }
+    }
+
+    #[doc(alias = "gtk_expression_watch_unwatch")]
+    pub fn unwatch(&self) {
+        unsafe {
+            ffi::gtk_expression_watch_unwatch(self.as_ptr());
+        }
+    }
+}
+
+impl Clone for ExpressionWatch {
+    fn clone(&self) -> Self {
+        unsafe { Self::from_raw_none(self.0.as_ptr()) }
+    }
+}
+
+impl Drop for ExpressionWatch {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::gtk_expression_watch_unref(self.as_ptr());
+        }
+    }
+}
+
+impl fmt::Debug for ExpressionWatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("ExpressionWatch").field(&self.0).finish()
+    }
+}
+
+macro_rules! expression_subtype {
+    ($name:ident, $ffi_name:ident) => {
+        #[derive(Clone, Debug)]
+        pub struct $name(Expression);
+
+        impl $name {
+            unsafe fn from_glib_full(ptr: *mut ffi::GtkExpression) -> Self {
+                $name(from_glib_full(ptr))
+            }
+
+            #[allow(dead_code)]
+            fn as_ptr(&self) -> *mut ffi::$ffi_name {
+                self.0.to_glib_none().0 as *mut ffi::$ffi_name
+            }
+
+            pub fn upcast(self) -> Expression {
+                self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = Expression;
+
+            fn deref(&self) -> &Expression {
+                &self.0
+            }
+        }
+
+        impl AsRef<Expression> for $name {
+            fn as_ref(&self) -> &Expression {
+                &self.0
+            }
+        }
+
+        impl From<$name> for Expression {
+            fn from(expression: $name) -> Expression {
+                expression.0
+            }
+        }
+    };
+}
+
+expression_subtype!(ClosureExpression, GtkClosureExpression);
+expression_subtype!(ConstantExpression, GtkConstantExpression);
+expression_subtype!(ObjectExpression, GtkObjectExpression);
+expression_subtype!(PropertyExpression, GtkPropertyExpression);
+
+impl PropertyExpression {
+    // rustdoc-stripper-ignore-next
+    /// Creates an expression looking up `property_name` on the object
+    /// `expression` evaluates to, or on `this` if `expression` is `None`.
+    /// The object must be of type `this_type`.
+    #[doc(alias = "gtk_property_expression_new")]
+    pub fn new(
+        this_type: glib::Type,
+        expression: Option<&Expression>,
+        property_name: &str,
+    ) -> Self {
+        assert_initialized_main_thread!();
+        unsafe {
+            Self::from_glib_full(ffi::gtk_property_expression_new(
+                this_type.to_glib(),
+                expression.to_glib_full(),
+                property_name.to_glib_none().0,
+            ))
+        }
+    }
+
+    #[doc(alias = "gtk_property_expression_get_expression")]
+    pub fn get_expression(&self) -> Option<Expression> {
+        unsafe { from_glib_none(ffi::gtk_property_expression_get_expression(self.as_ptr())) }
+    }
+
+    #[doc(alias = "gtk_property_expression_get_pspec")]
+    pub fn get_pspec(&self) -> glib::ParamSpec {
+        unsafe { from_glib_none(ffi::gtk_property_expression_get_pspec(self.as_ptr())) }
+    }
+}
+
+impl ConstantExpression {
+    #[doc(alias = "gtk_constant_expression_new")]
+    pub fn new<T: ToValue>(value: &T) -> Self {
+        assert_initialized_main_thread!();
+        Self::for_value(&value.to_value())
+    }
+
+    #[doc(alias = "gtk_constant_expression_new_for_value")]
+    pub fn for_value(value: &glib::Value) -> Self {
+        assert_initialized_main_thread!();
+        unsafe {
+            Self::from_glib_full(ffi::gtk_constant_expression_new_for_value(
+                value.to_glib_none().0,
+            ))
+        }
+    }
+
+    #[doc(alias = "gtk_constant_expression_get_value")]
+    pub fn get_value(&self) -> glib::Value {
+        unsafe { from_glib_none(ffi::gtk_constant_expression_get_value(self.as_ptr())) }
+    }
+}
+
+impl ObjectExpression {
+    // rustdoc-stripper-ignore-next
+    /// Creates an expression evaluating to `object`, which is only weakly
+    /// referenced.
+    #[doc(alias = "gtk_object_expression_new")]
+    pub fn new<T: IsA<Object>>(object: &T) -> Self {
+        assert_initialized_main_thread!();
+        unsafe {
+            Self::from_glib_full(ffi::gtk_object_expression_new(
+                object.as_ref().to_glib_none().0,
+            ))
+        }
+    }
+
+    #[doc(alias = "gtk_object_expression_get_object")]
+    pub fn get_object(&self) -> Option<Object> {
+        unsafe { from_glib_none(ffi::gtk_object_expression_get_object(self.as_ptr())) }
+    }
+}
+
+impl ClosureExpression {
+    // rustdoc-stripper-ignore-next
+    /// Creates an expression calling `f` with the object the expression is
+    /// evaluated for, `this`, followed by the values of `params`.
+    ///
+    /// `this` is a `glib::Object` value, which is `None` if the expression
+    /// is evaluated without one. The expression can't be evaluated if one
+    /// of the `params` can't be.
+    #[doc(alias = "gtk_cclosure_expression_new")]
+    pub fn new<R, F>(params: &[&Expression], f: F) -> Self
+    where
+        R: ToValue + StaticType,
+        F: Fn(&[glib::Value]) -> R + 'static,
+    {
+        assert_initialized_main_thread!();
+
+        // A C closure with a custom marshaller, unlike glib::Closure it
+        // doesn't need `f` to be `Send`.
+        unsafe extern "C" fn marshal<R: ToValue + StaticType, F: Fn(&[glib::Value]) -> R>(
+            closure: *mut glib::gobject_ffi::GClosure,
+            return_value: *mut glib::gobject_ffi::GValue,
+            n_param_values: c_uint,
+            param_values: *const glib::gobject_ffi::GValue,
+            _invocation_hint: glib::ffi::gpointer,
+            _marshal_data: glib::ffi::gpointer,
+        ) {
+            let f: &F = &*((*closure).data as *const F);
+            let values: &[glib::Value] = if n_param_values == 0 {
+                &[]
+            } else {
+                slice::from_raw_parts(param_values as *const glib::Value, n_param_values as usize)
+            };
+            let ret = f(values).to_value();
+            if !return_value.is_null() {
+                glib::gobject_ffi::g_value_copy(ret.to_glib_none().0, return_value);
+            }
+        }
+        // Never called, the marshaller calls `f` directly.
+        unsafe extern "C" fn callback() {}
+        unsafe extern "C" fn destroy_notify<F>(
+            data: glib::ffi::gpointer,
+            _closure: *mut glib::gobject_ffi::GClosure,
+        ) {
+            Box_::from_raw(data as *mut F);
+        }
+
+        let mut params = params
+            .iter()
+            .map(|p| p.to_glib_full())
+            .collect::<Vec<*mut ffi::GtkExpression>>();
+        unsafe {
+            Self::from_glib_full(ffi::gtk_cclosure_expression_new(
+                R::static_type().to_glib(),
+                Some(marshal::<R, F>),
+                params.len() as c_uint,
+                params.as_mut_ptr(),
+                Some(callback),
+                Box_::into_raw(Box_::new(f)) as glib::ffi::gpointer,
+                Some(destroy_notify::<F>),
+            ))
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::dialog::{run_dialog_future, ResponseDialog};
+use crate::{
+    FileChooser, FileChooserAction, FileChooserError, FileChooserExt, ResponseType, Window,
+};
+use gio::prelude::*;
+use glib::error::ErrorDomain;
+use glib::object::{Cast, IsA};
+use glib::Quark;
+use std::future::Future;
+use std::pin::Pin;
+
+pub trait FileChooserExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Returns the selected files, like `get_files` without going through a
+    /// `gio::ListModel`.
+    #[doc(alias = "gtk_file_chooser_get_files")]
+    fn get_files_vec(&self) -> Vec<gio::File>;
+}
+
+impl<O: IsA<FileChooser>> FileChooserExtManual for O {
+    fn get_files_vec(&self) -> Vec<gio::File> {
+        let files = match self.get_files() {
+            Some(files) => files,
+            None => return Vec::new(),
+        };
+        (0..files.get_n_items())
+            .filter_map(|i| files.get_object(i))
+            .filter_map(|file| file.downcast::<gio::File>().ok())
+            .collect()
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// The errors of the file chooser futures, like
+/// [`FileChooserNative::open_future`], besides the `FileChooserError`s.
+///
+/// [`FileChooserNative::open_future`]: struct.FileChooserNative.html#method.open_future
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileChooserFutureError {
+    // rustdoc-stripper-ignore-next
+    /// The chooser was closed without choosing a file.
+    Cancelled,
+}
+
+impl ErrorDomain for FileChooserFutureError {
+    fn domain() -> Quark {
+        skip_assert_initialized!();
+        Quark::from_string("gtk-rs-file-chooser-future-error-quark")
+    }
+
+    fn code(self) -> i32 {
+        match self {
+            FileChooserFutureError::Cancelled => 0,
+        }
+    }
+
+    fn from(code: i32) -> Option<Self> {
+        skip_assert_initialized!();
+        match code {
+            0 => Some(FileChooserFutureError::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+// The file picked in a file chooser closed with `response`. Responses other
+// than the ones of an accept button are a cancellation.
+fn chosen_file(chooser: &FileChooser, response: ResponseType) -> Result<gio::File, glib::Error> {
+    match response {
+        ResponseType::Accept | ResponseType::Ok | ResponseType::Yes | ResponseType::Apply => {
+            chooser.get_file().ok_or_else(|| {
+                glib::Error::new(FileChooserError::Nonexistent, "No file was chosen")
+            })
+        }
+        _ => Err(glib::Error::new(
+            FileChooserFutureError::Cancelled,
+            "The file chooser was cancelled",
+        )),
+    }
+}
+
+// Shows `dialog`, which is `chooser`, with `action` and transient for
+// `parent`, and resolves to the chosen file. The future keeps the dialog
+// alive until it is done.
+pub(crate) fn run_file_future<D: ResponseDialog + 'static>(
+    dialog: D,
+    chooser: &FileChooser,
+    action: FileChooserAction,
+    parent: Option<&Window>,
+) -> Pin<Box<dyn Future<Output = Result<gio::File, glib::Error>> + 'static>> {
+    chooser.set_action(action);
+    dialog.dialog_set_transient_for(parent);
+    let chooser = chooser.clone();
+    Box::pin(async move {
+        let response = run_dialog_future(dialog).await;
+        chosen_file(&chooser, response)
+    })
+}
@@ -1,9 +1,12 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{FileChooserAction, FileChooserDialog, ResponseType, Widget, Window};
+use crate::file_chooser::run_file_future;
+use crate::{Dialog, FileChooserAction, FileChooserDialog, ResponseType, Widget, Window};
 use glib::object::{Cast, IsA};
 use glib::translate::*;
 use libc::c_char;
+use std::future::Future;
+use std::pin::Pin;
 use std::ptr;
 
 impl FileChooserDialog {
@@ -66,3 +69,58 @@ impl FileChooserDialog {
         }
     }
 }
+
+impl FileChooserDialog {
+    // rustdoc-stripper-ignore-next
+    /// Shows the chooser to open a file, transient for `parent`, and returns
+    /// a `Future` that resolves to the chosen file.
+    ///
+    /// The chooser is hidden once a response has been received. The `Future`
+    /// resolves to a [`FileChooserFutureError::Cancelled`] error for the
+    /// responses of other buttons than `Accept`, `Ok`, `Yes` and `Apply`.
+    ///
+    /// [`FileChooserFutureError::Cancelled`]: enum.FileChooserFutureError.html#variant.Cancelled
+    pub fn open_future<P: IsA<Window>>(
+        &self,
+        parent: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<gio::File, glib::Error>> + 'static>> {
+        run_file_future(
+            self.clone().upcast::<Dialog>(),
+            self.upcast_ref(),
+            FileChooserAction::Open,
+            parent.map(|p| p.as_ref()),
+        )
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`open_future`], to choose where to save a file.
+    ///
+    /// [`open_future`]: #method.open_future
+    pub fn save_future<P: IsA<Window>>(
+        &self,
+        parent: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<gio::File, glib::Error>> + 'static>> {
+        run_file_future(
+            self.clone().upcast::<Dialog>(),
+            self.upcast_ref(),
+            FileChooserAction::Save,
+            parent.map(|p| p.as_ref()),
+        )
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`open_future`], to choose a folder.
+    ///
+    /// [`open_future`]: #method.open_future
+    pub fn select_folder_future<P: IsA<Window>>(
+        &self,
+        parent: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<gio::File, glib::Error>> + 'static>> {
+        run_file_future(
+            self.clone().upcast::<Dialog>(),
+            self.upcast_ref(),
+            FileChooserAction::SelectFolder,
+            parent.map(|p| p.as_ref()),
+        )
+    }
+}
@@ -0,0 +1,81 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::file_chooser::run_file_future;
+use crate::{FileChooserAction, FileChooserNative, NativeDialog, Window};
+use glib::object::{Cast, IsA};
+use std::future::Future;
+use std::pin::Pin;
+
+impl FileChooserNative {
+    // rustdoc-stripper-ignore-next
+    /// Shows the chooser to open a file, transient for `parent`, and returns
+    /// a `Future` that resolves to the chosen file.
+    ///
+    /// The `Future` keeps the chooser alive until it resolves. It resolves to
+    /// a [`FileChooserFutureError::Cancelled`] error if the chooser is
+    /// cancelled.
+    ///
+    /// ```no_run
+    /// use gtk4::prelude::*;
+    ///
+    /// # async fn run(window: gtk4::ApplicationWindow) {
+    /// let chooser = gtk4::FileChooserNative::new(
+    ///     Some("Open File"),
+    ///     None::<&gtk4::Window>,
+    ///     gtk4::FileChooserAction::Open,
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// match chooser.open_future(Some(&window)).await {
+    ///     Ok(file) => println!("Selected: {:?}", file.get_path()),
+    ///     Err(err) => println!("Nothing selected: {}", err),
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`FileChooserFutureError::Cancelled`]: enum.FileChooserFutureError.html#variant.Cancelled
+    pub fn open_future<P: IsA<Window>>(
+        &self,
+        parent: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<gio::File, glib::Error>> + 'static>> {
+        run_file_future(
+            self.clone().upcast::<NativeDialog>(),
+            self.upcast_ref(),
+            FileChooserAction::Open,
+            parent.map(|p| p.as_ref()),
+        )
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`open_future`], to choose where to save a file.
+    ///
+    /// [`open_future`]: #method.open_future
+    pub fn save_future<P: IsA<Window>>(
+        &self,
+        parent: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<gio::File, glib::Error>> + 'static>> {
+        run_file_future(
+            self.clone().upcast::<NativeDialog>(),
+            self.upcast_ref(),
+            FileChooserAction::Save,
+            parent.map(|p| p.as_ref()),
+        )
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`open_future`], to choose a folder.
+    ///
+    /// [`open_future`]: #method.open_future
+    pub fn select_folder_future<P: IsA<Window>>(
+        &self,
+        parent: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<gio::File, glib::Error>> + 'static>> {
+        run_file_future(
+            self.clone().upcast::<NativeDialog>(),
+            self.upcast_ref(),
+            FileChooserAction::SelectFolder,
+            parent.map(|p| p.as_ref()),
+        )
+    }
+}
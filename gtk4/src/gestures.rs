@@ -0,0 +1,465 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Gesture helpers for widgets drawing a canvas that can be panned, zoomed
+//! and rotated.
+
+use crate::prelude::*;
+use crate::{
+    EventController, EventControllerMotion, EventControllerScroll, EventControllerScrollFlags,
+    EventSequenceState, GestureClick, GestureDrag, GestureRotate, GestureZoom, Widget,
+};
+use glib::signal::Inhibit;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+// The zoom factor of a single step of the wheel, with Control held down.
+const SCROLL_ZOOM_STEP: f64 = 1.1;
+// The distance panned by a single step of the wheel, in pixels.
+const SCROLL_PAN_STEP: f64 = 50.0;
+
+// rustdoc-stripper-ignore-next
+/// The transformation from the coordinates of a canvas to the coordinates of
+/// its widget: the canvas is scaled by `scale`, rotated by `angle` radians
+/// clockwise, then moved by `offset_x` and `offset_y`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CanvasTransform {
+    pub offset_x: f64,
+    pub offset_y: f64,
+    pub scale: f64,
+    pub angle: f64,
+}
+
+impl Default for CanvasTransform {
+    fn default() -> Self {
+        Self {
+            offset_x: 0.0,
+            offset_y: 0.0,
+            scale: 1.0,
+            angle: 0.0,
+        }
+    }
+}
+
+impl CanvasTransform {
+    // rustdoc-stripper-ignore-next
+    /// Maps a point of the canvas to the widget.
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        let (sin, cos) = self.angle.sin_cos();
+        (
+            self.offset_x + self.scale * (x * cos - y * sin),
+            self.offset_y + self.scale * (x * sin + y * cos),
+        )
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Maps a point of the widget to the canvas.
+    pub fn invert(&self, x: f64, y: f64) -> (f64, f64) {
+        let (sin, cos) = self.angle.sin_cos();
+        let x = (x - self.offset_x) / self.scale;
+        let y = (y - self.offset_y) / self.scale;
+        (x * cos + y * sin, y * cos - x * sin)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the transformation as a `gsk::Transform`, to be applied to a
+    /// `Snapshot` before drawing the canvas.
+    pub fn to_transform(&self) -> gsk::Transform {
+        let transform = gsk::Transform::new();
+        let transform = transform
+            .translate(&graphene::Point::new(
+                self.offset_x as f32,
+                self.offset_y as f32,
+            ))
+            .unwrap_or(transform);
+        let transform = transform
+            .rotate(self.angle.to_degrees() as f32)
+            .unwrap_or(transform);
+        transform
+            .scale(self.scale as f32, self.scale as f32)
+            .unwrap_or(transform)
+    }
+
+    // Zooms by `factor` and rotates by `angle` around the point `x`, `y` of
+    // the widget.
+    fn zoom_around(&self, x: f64, y: f64, factor: f64, angle: f64) -> Self {
+        self.pinch((x, y), (x, y), factor, angle)
+    }
+
+    // Returns the transformation moving the point of the canvas under
+    // `anchor` to `center`, scaled by `factor` and rotated by `angle`.
+    fn pinch(&self, anchor: (f64, f64), center: (f64, f64), factor: f64, angle: f64) -> Self {
+        let (x, y) = self.invert(anchor.0, anchor.1);
+        let mut transform = Self {
+            offset_x: 0.0,
+            offset_y: 0.0,
+            scale: self.scale * factor,
+            angle: self.angle + angle,
+        };
+        let (moved_x, moved_y) = transform.apply(x, y);
+        transform.offset_x = center.0 - moved_x;
+        transform.offset_y = center.1 - moved_y;
+        transform
+    }
+}
+
+struct Inner {
+    widget: glib::WeakRef<Widget>,
+    drag: GestureDrag,
+    zoom: GestureZoom,
+    rotate: GestureRotate,
+    click: GestureClick,
+    scroll: EventControllerScroll,
+    motion: EventControllerMotion,
+    transform: Cell<CanvasTransform>,
+    // The transformation when the current gesture began, and the point it
+    // began at: the drag offset, or the center of the fingers.
+    start: Cell<CanvasTransform>,
+    anchor: Cell<(f64, f64)>,
+    // Whether two fingers are zooming and rotating, the drag then waits.
+    pinching: Cell<bool>,
+    // The position of the pointer, scrolling zooms around it.
+    pointer: Cell<Option<(f64, f64)>>,
+    scale_range: Cell<(f64, f64)>,
+    callbacks: RefCell<Vec<Rc<dyn Fn(&CanvasTransform)>>>,
+}
+
+impl Inner {
+    fn set_transform(&self, transform: CanvasTransform) {
+        let (min, max) = self.scale_range.get();
+        let transform = CanvasTransform {
+            scale: transform.scale.max(min).min(max),
+            ..transform
+        };
+        if transform == self.transform.get() {
+            return;
+        }
+        self.transform.set(transform);
+        // The callbacks may set the transformation again.
+        let callbacks = self.callbacks.borrow().clone();
+        for callback in callbacks {
+            callback(&transform);
+        }
+    }
+
+    // Clamps a zoom `factor` of `transform`, for the canvas to keep the
+    // point it zooms around in place at the ends of the scale range.
+    fn clamp_factor(&self, transform: &CanvasTransform, factor: f64) -> f64 {
+        let (min, max) = self.scale_range.get();
+        (transform.scale * factor).max(min).min(max) / transform.scale
+    }
+
+    fn reset(&self) {
+        // A double tap also begins a drag, which goes on from the reset
+        // canvas.
+        self.start.set(CanvasTransform::default());
+        self.set_transform(CanvasTransform::default());
+    }
+
+    fn drag_begin(&self) {
+        if !self.pinching.get() {
+            self.start.set(self.transform.get());
+            self.anchor.set((0.0, 0.0));
+        }
+    }
+
+    fn drag_update(&self, x: f64, y: f64) {
+        if self.pinching.get() {
+            return;
+        }
+        let (anchor_x, anchor_y) = self.anchor.get();
+        let start = self.start.get();
+        self.set_transform(CanvasTransform {
+            offset_x: start.offset_x + x - anchor_x,
+            offset_y: start.offset_y + y - anchor_y,
+            ..start
+        });
+    }
+
+    fn pinch_begin(&self) {
+        if let Some(center) = self.zoom.get_bounding_box_center() {
+            self.pinching.set(true);
+            self.start.set(self.transform.get());
+            self.anchor.set(center);
+        }
+    }
+
+    fn pinch_update(&self) {
+        if !self.pinching.get() {
+            return;
+        }
+        if let Some(center) = self.zoom.get_bounding_box_center() {
+            let start = self.start.get();
+            let factor = self.clamp_factor(&start, self.zoom.get_scale_delta());
+            self.set_transform(start.pinch(
+                self.anchor.get(),
+                center,
+                factor,
+                self.rotate.get_angle_delta(),
+            ));
+        }
+    }
+
+    fn pinch_end(&self) {
+        if !self.pinching.replace(false) {
+            return;
+        }
+        // The remaining finger drags on from where the pinch left the canvas.
+        self.start.set(self.transform.get());
+        self.anchor
+            .set(self.drag.get_offset().unwrap_or((0.0, 0.0)));
+    }
+
+    fn scroll(&self, dx: f64, dy: f64, state: gdk::ModifierType) {
+        let transform = self.transform.get();
+        if state.contains(gdk::ModifierType::CONTROL_MASK) {
+            // Scrolling down zooms out, around the pointer or the center of
+            // the widget.
+            let (x, y) = self.pointer.get().unwrap_or_else(|| {
+                self.widget
+                    .upgrade()
+                    .map(|widget| {
+                        (
+                            widget.get_width() as f64 / 2.0,
+                            widget.get_height() as f64 / 2.0,
+                        )
+                    })
+                    .unwrap_or((0.0, 0.0))
+            });
+            let factor = self.clamp_factor(&transform, SCROLL_ZOOM_STEP.powf(-dy));
+            self.set_transform(transform.zoom_around(x, y, factor, 0.0));
+        } else {
+            self.set_transform(CanvasTransform {
+                offset_x: transform.offset_x - dx * SCROLL_PAN_STEP,
+                offset_y: transform.offset_y - dy * SCROLL_PAN_STEP,
+                ..transform
+            });
+        }
+    }
+
+    fn controllers(&self) -> [&EventController; 6] {
+        [
+            self.drag.upcast_ref(),
+            self.zoom.upcast_ref(),
+            self.rotate.upcast_ref(),
+            self.click.upcast_ref(),
+            self.scroll.upcast_ref(),
+            self.motion.upcast_ref(),
+        ]
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        if let Some(widget) = self.widget.upgrade() {
+            for controller in &self.controllers() {
+                widget.remove_controller(*controller);
+            }
+        }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Pans, zooms and rotates a canvas drawn by a widget, following the
+/// gestures on the widget:
+///
+/// - dragging with the pointer or a finger pans the canvas,
+/// - pinching with two fingers, or on a touchpad, zooms and rotates it
+///   around the fingers, and pans it along with them,
+/// - scrolling pans it, and zooms around the pointer with Control held down,
+/// - double clicking or tapping resets it.
+///
+/// The gestures are grouped, so that a second finger turns a drag into a
+/// pinch, and lifting it back into a drag. They are removed from the widget
+/// when the `CanvasGestures` is dropped, which usually lives in the
+/// implementation of the widget:
+///
+/// ```no_run
+/// # use gtk4 as gtk;
+/// # use gtk::glib;
+/// # use gtk::prelude::*;
+/// let area = gtk::DrawingArea::new();
+/// let gestures = gtk::gestures::CanvasGestures::new(&area);
+/// gestures.connect_transform_changed(glib::clone!(@weak area => move |_| {
+///     area.queue_draw();
+/// }));
+/// ```
+pub struct CanvasGestures {
+    inner: Rc<Inner>,
+}
+
+impl fmt::Debug for CanvasGestures {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CanvasGestures")
+            .field("widget", &self.inner.widget.upgrade())
+            .field("transform", &self.inner.transform.get())
+            .finish()
+    }
+}
+
+impl CanvasGestures {
+    // rustdoc-stripper-ignore-next
+    /// Adds the gesture controllers to `widget`.
+    pub fn new<W: IsA<Widget>>(widget: &W) -> Self {
+        let inner = Rc::new(Inner {
+            widget: widget.as_ref().downgrade(),
+            drag: GestureDrag::new(),
+            zoom: GestureZoom::new(),
+            rotate: GestureRotate::new(),
+            click: GestureClick::new(),
+            scroll: EventControllerScroll::new(EventControllerScrollFlags::BOTH_AXES),
+            motion: EventControllerMotion::new(),
+            transform: Cell::new(CanvasTransform::default()),
+            start: Cell::new(CanvasTransform::default()),
+            anchor: Cell::new((0.0, 0.0)),
+            pinching: Cell::new(false),
+            pointer: Cell::new(None),
+            scale_range: Cell::new((0.1, 10.0)),
+            callbacks: RefCell::new(Vec::new()),
+        });
+
+        // The gestures of a group share the state of the touch sequences.
+        inner.drag.group(&inner.zoom);
+        inner.drag.group(&inner.rotate);
+        inner.drag.group(&inner.click);
+
+        // The controllers are owned by the widget, their handlers only keep
+        // weak references.
+        inner
+            .drag
+            .connect_drag_begin(glib::clone!(@weak inner => move |drag, _, _| {
+                drag.set_state(EventSequenceState::Claimed);
+                inner.drag_begin();
+            }));
+        inner
+            .drag
+            .connect_drag_update(glib::clone!(@weak inner => move |_, x, y| {
+                inner.drag_update(x, y);
+            }));
+
+        // The zoom and rotate gestures begin and end together.
+        inner
+            .zoom
+            .connect_begin(glib::clone!(@weak inner => move |_, _| inner.pinch_begin()));
+        inner
+            .zoom
+            .connect_scale_changed(glib::clone!(@weak inner => move |_, _| inner.pinch_update()));
+        inner.rotate.connect_angle_changed(
+            glib::clone!(@weak inner => move |_, _, _| inner.pinch_update()),
+        );
+        inner
+            .zoom
+            .connect_end(glib::clone!(@weak inner => move |_, _| inner.pinch_end()));
+
+        inner
+            .click
+            .connect_pressed(glib::clone!(@weak inner => move |_, n_press, _, _| {
+                if n_press == 2 {
+                    inner.reset();
+                }
+            }));
+
+        inner
+            .motion
+            .connect_motion(glib::clone!(@weak inner => move |_, x, y| {
+                inner.pointer.set(Some((x, y)));
+            }));
+        inner
+            .motion
+            .connect_leave(glib::clone!(@weak inner => move |_| inner.pointer.set(None)));
+
+        inner.scroll.connect_scroll(
+            glib::clone!(@weak inner => @default-return Inhibit(false), move |scroll, dx, dy| {
+                inner.scroll(dx, dy, scroll.get_current_event_state());
+                Inhibit(true)
+            }),
+        );
+
+        let widget = widget.as_ref();
+        for controller in &inner.controllers() {
+            widget.add_controller(*controller);
+        }
+
+        Self { inner }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the current transformation of the canvas.
+    pub fn get_transform(&self) -> CanvasTransform {
+        self.inner.transform.get()
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the transformation of the canvas, with its scale clamped to the
+    /// range set with [`set_scale_range`].
+    ///
+    /// [`set_scale_range`]: #method.set_scale_range
+    pub fn set_transform(&self, transform: CanvasTransform) {
+        self.inner.set_transform(transform);
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Resets the transformation of the canvas, like a double click.
+    pub fn reset(&self) {
+        self.inner.reset();
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the range of the scale of the canvas, from `0.1` to `10.0` by
+    /// default.
+    pub fn set_scale_range(&self, min: f64, max: f64) {
+        assert!(
+            min > 0.0 && min <= max,
+            "Invalid scale range from {} to {}",
+            min,
+            max
+        );
+        self.inner.scale_range.set((min, max));
+        self.inner.set_transform(self.inner.transform.get());
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Calls `f` with the new transformation of the canvas when it changes,
+    /// usually to redraw the widget.
+    pub fn connect_transform_changed<F: Fn(&CanvasTransform) + 'static>(&self, f: F) {
+        self.inner.callbacks.borrow_mut().push(Rc::new(f));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close((x, y): (f64, f64), (expected_x, expected_y): (f64, f64)) {
+        assert!(
+            (x - expected_x).abs() < 1e-9 && (y - expected_y).abs() < 1e-9,
+            "({}, {}) != ({}, {})",
+            x,
+            y,
+            expected_x,
+            expected_y
+        );
+    }
+
+    #[test]
+    fn canvas_transform() {
+        let transform = CanvasTransform {
+            offset_x: 10.0,
+            offset_y: 20.0,
+            scale: 2.0,
+            angle: std::f64::consts::FRAC_PI_2,
+        };
+        // Scaled, rotated a quarter turn clockwise, then moved.
+        assert_close(transform.apply(1.0, 0.0), (10.0, 22.0));
+        assert_close(transform.apply(0.0, 1.0), (8.0, 20.0));
+        assert_close(transform.invert(10.0, 22.0), (1.0, 0.0));
+        let (x, y) = transform.apply(3.0, -4.0);
+        assert_close(transform.invert(x, y), (3.0, -4.0));
+
+        // The point under the pointer stays in place while zooming.
+        let zoomed = transform.zoom_around(30.0, 40.0, 1.5, 0.25);
+        assert!((zoomed.scale - 3.0).abs() < 1e-9);
+        let (x, y) = transform.invert(30.0, 40.0);
+        assert_close(zoomed.apply(x, y), (30.0, 40.0));
+    }
+}
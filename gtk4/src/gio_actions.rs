@@ -0,0 +1,241 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Helpers adding `gio::SimpleAction`s with typed parameters and state to a
+//! `gio::ActionMap`, like a `gtk::Application` or a
+//! `gtk::ApplicationWindow`, or several actions at once from
+//! [`ActionEntry`]s.
+//!
+//! The values are converted from and to `glib::Variant`s of the static type
+//! of `T`, which GIO checks the parameters against when the actions are
+//! activated.
+//!
+//! Typed parameters can be passed to widget actions with
+//! [`WidgetExtManual::activate_action_typed`].
+//!
+//! [`WidgetExtManual::activate_action_typed`]: ../prelude/trait.WidgetExtManual.html#tymethod.activate_action_typed
+//! [`ActionEntry`]: struct.ActionEntry.html
+
+use gio::prelude::*;
+use glib::{FromVariant, IsA, StaticVariantType, ToVariant};
+use std::fmt;
+
+// rustdoc-stripper-ignore-next
+/// Adds an action named `name` to `map`, taking a parameter of type `T`
+/// which is given to `activate`, and returns it.
+pub fn add_action_typed<M, T, F>(map: &M, name: &str, activate: F) -> gio::SimpleAction
+where
+    M: IsA<gio::ActionMap>,
+    T: StaticVariantType + FromVariant,
+    F: Fn(T) + 'static,
+{
+    let action = gio::SimpleAction::new(name, Some(&T::static_variant_type()));
+    action.connect_activate(
+        move |action, parameter| match parameter.and_then(T::from_variant) {
+            Some(parameter) => activate(parameter),
+            None => glib::g_critical!(
+                "Gtk",
+                "Action `{}` expects a parameter of type `{}`, got {:?}",
+                action.get_name(),
+                T::static_variant_type().to_str(),
+                parameter
+            ),
+        },
+    );
+    map.add_action(&action);
+    action
+}
+
+// rustdoc-stripper-ignore-next
+/// Adds an action named `name` to `map`, with a state of type `T` starting
+/// from `initial_state`, and returns it.
+///
+/// `change_state` is called with the requested new state, and the state of
+/// the action is only set to it if `change_state` returns `true`.
+///
+/// Boolean actions have no parameter and are toggled when activated, like
+/// the check items of menus. The others take their new state as parameter,
+/// like the radio items of menus.
+pub fn add_stateful_action_typed<M, T, F>(
+    map: &M,
+    name: &str,
+    initial_state: T,
+    change_state: F,
+) -> gio::SimpleAction
+where
+    M: IsA<gio::ActionMap>,
+    T: StaticVariantType + FromVariant + ToVariant,
+    F: Fn(T) -> bool + 'static,
+{
+    let state_type = T::static_variant_type();
+    let parameter_type = if *state_type == *bool::static_variant_type() {
+        None
+    } else {
+        Some(&*state_type)
+    };
+    let action = gio::SimpleAction::new_stateful(name, parameter_type, &initial_state.to_variant());
+    action.connect_change_state(move |action, value| match value.and_then(T::from_variant) {
+        Some(state) => {
+            if change_state(state) {
+                action.set_state(value.unwrap());
+            }
+        }
+        None => glib::g_critical!(
+            "Gtk",
+            "Action `{}` expects a state of type `{}`, got {:?}",
+            action.get_name(),
+            T::static_variant_type().to_str(),
+            value
+        ),
+    });
+    map.add_action(&action);
+    action
+}
+
+type ActionCallback = Box<dyn Fn(&gio::SimpleAction, Option<&glib::Variant>)>;
+
+// rustdoc-stripper-ignore-next
+/// The description of an action to add with [`add_action_entries`], like a
+/// `GActionEntry`, built with [`ActionEntry::builder`].
+///
+/// [`add_action_entries`]: fn.add_action_entries.html
+/// [`ActionEntry::builder`]: #method.builder
+pub struct ActionEntry {
+    name: String,
+    parameter_type: Option<glib::VariantType>,
+    state: Option<glib::Variant>,
+    activate: Option<ActionCallback>,
+    change_state: Option<ActionCallback>,
+}
+
+impl fmt::Debug for ActionEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ActionEntry")
+            .field("name", &self.name)
+            .field("parameter_type", &self.parameter_type)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl ActionEntry {
+    // rustdoc-stripper-ignore-next
+    /// Starts the description of an action named `name`, without parameter
+    /// nor state.
+    pub fn builder(name: &str) -> ActionEntryBuilder {
+        ActionEntryBuilder(ActionEntry {
+            name: name.to_owned(),
+            parameter_type: None,
+            state: None,
+            activate: None,
+            change_state: None,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ActionEntryBuilder(ActionEntry);
+
+impl ActionEntryBuilder {
+    // rustdoc-stripper-ignore-next
+    /// Sets the type of the parameter of the action.
+    pub fn parameter_type(mut self, parameter_type: &glib::VariantTy) -> Self {
+        self.0.parameter_type = Some(parameter_type.to_owned());
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Makes the action stateful, starting from `state`.
+    pub fn state(mut self, state: glib::Variant) -> Self {
+        self.0.state = Some(state);
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the handler of the activations of the action, with their
+    /// parameter.
+    ///
+    /// Without it, stateful actions are toggled when their state is a
+    /// boolean, or changed to the parameter when it has the type of their
+    /// state.
+    pub fn activate<F: Fn(&gio::SimpleAction, Option<&glib::Variant>) + 'static>(
+        mut self,
+        activate: F,
+    ) -> Self {
+        self.0.activate = Some(Box::new(activate));
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the handler of the requests to change the state of the action,
+    /// which has to call `SimpleAction::set_state` to accept them.
+    ///
+    /// Without it, the requested states are always set.
+    pub fn change_state<F: Fn(&gio::SimpleAction, Option<&glib::Variant>) + 'static>(
+        mut self,
+        change_state: F,
+    ) -> Self {
+        self.0.change_state = Some(Box::new(change_state));
+        self
+    }
+
+    pub fn build(self) -> ActionEntry {
+        self.0
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Adds the actions described by `entries` to `map`, like
+/// `g_action_map_add_action_entries`.
+///
+/// ```no_run
+/// # use gtk4 as gtk;
+/// # use gtk::gio_actions::{add_action_entries, ActionEntry};
+/// # use gtk::glib::ToVariant;
+/// let window = gtk::ApplicationWindow::new(&gtk::Application::new(None, Default::default()).unwrap());
+/// add_action_entries(
+///     &window,
+///     vec![
+///         ActionEntry::builder("close")
+///             .activate(|_, _| println!("Close"))
+///             .build(),
+///         ActionEntry::builder("fullscreen")
+///             .state(false.to_variant())
+///             .build(),
+///     ],
+/// );
+/// ```
+#[doc(alias = "g_action_map_add_action_entries")]
+pub fn add_action_entries<M, I>(map: &M, entries: I)
+where
+    M: IsA<gio::ActionMap>,
+    I: IntoIterator<Item = ActionEntry>,
+{
+    for entry in entries {
+        let parameter_type = entry.parameter_type.as_deref();
+        let action = match entry.state {
+            Some(ref state) => gio::SimpleAction::new_stateful(&entry.name, parameter_type, state),
+            None => gio::SimpleAction::new(&entry.name, parameter_type),
+        };
+        if let Some(activate) = entry.activate {
+            action.connect_activate(move |action, parameter| activate(action, parameter));
+        }
+        if let Some(change_state) = entry.change_state {
+            action.connect_change_state(move |action, value| change_state(action, value));
+        }
+        map.add_action(&action);
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Creates a `gio::SimpleActionGroup` with the actions described by
+/// `entries`, see [`add_action_entries`].
+///
+/// [`add_action_entries`]: fn.add_action_entries.html
+pub fn action_group_from_entries<I>(entries: I) -> gio::SimpleActionGroup
+where
+    I: IntoIterator<Item = ActionEntry>,
+{
+    let group = gio::SimpleActionGroup::new();
+    add_action_entries(&group, entries);
+    group
+}
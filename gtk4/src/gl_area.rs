@@ -0,0 +1,28 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::prelude::GLAreaExt;
+use crate::GLArea;
+use glib::IsA;
+
+pub trait GLAreaExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Makes the GL context of the area current and binds its buffers, then
+    /// calls `f`, like GTK does before emitting `render`.
+    ///
+    /// Returns the error of the area instead when it has no usable context.
+    fn make_current_scope<R, F: FnOnce(&Self) -> R>(&self, f: F) -> Result<R, glib::Error>;
+}
+
+impl<O: IsA<GLArea>> GLAreaExtManual for O {
+    fn make_current_scope<R, F: FnOnce(&Self) -> R>(&self, f: F) -> Result<R, glib::Error> {
+        self.make_current();
+        if let Some(error) = self.get_error() {
+            return Err(error);
+        }
+        self.attach_buffers();
+        if let Some(error) = self.get_error() {
+            return Err(error);
+        }
+        Ok(f(self))
+    }
+}
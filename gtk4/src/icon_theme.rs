@@ -3,18 +3,24 @@
 use crate::IconTheme;
 use glib::translate::*;
 
-pub trait IconThemeExtManual {
+impl IconTheme {
     #[doc(alias = "gtk_icon_theme_set_resource_path")]
+    pub fn set_resource_path(&self, path: &[&str]) {
+        unsafe {
+            ffi::gtk_icon_theme_set_resource_path(self.to_glib_none().0, path.to_glib_none().0);
+        }
+    }
+}
+
+#[deprecated(note = "Use the inherent `IconTheme::set_resource_path` instead")]
+pub trait IconThemeExtManual {
+    #[deprecated(note = "Use the inherent `IconTheme::set_resource_path` instead")]
     fn set_resource_path(&self, path: &str);
 }
 
+#[allow(deprecated)]
 impl<O: AsRef<IconTheme>> IconThemeExtManual for O {
     fn set_resource_path(&self, path: &str) {
-        unsafe {
-            ffi::gtk_icon_theme_set_resource_path(
-                self.as_ref().to_glib_none().0,
-                path.as_ptr() as *const _,
-            );
-        }
+        self.as_ref().set_resource_path(&[path]);
     }
 }
@@ -64,6 +64,13 @@ pub mod subclass;
 
 pub mod prelude;
 
+pub mod accessible;
+pub mod gestures;
+pub mod gio_actions;
+pub mod menu;
+pub mod property_binding;
+pub mod style;
+
 pub use auto::functions::*;
 pub use auto::*;
 pub use rt::*;
@@ -72,26 +79,38 @@ mod actionable;
 mod application;
 mod border;
 mod builder;
+mod builder_rust_scope;
+mod builders;
+mod callback_action;
 mod cell_area;
 mod cell_editable;
 mod cell_renderer;
 mod color_chooser;
 mod combo_box;
+mod constraint;
 mod constraint_guide;
+mod constraint_layout;
 mod css_location;
+mod css_provider;
+mod css_style_change;
 mod custom_filter;
 mod custom_sorter;
 mod dialog;
 mod drawing_area;
+mod drop_target;
 mod editable;
 mod entry;
 mod entry_buffer;
 mod entry_completion;
 mod enums;
 mod event_controller_key;
+mod expression;
+mod file_chooser;
 mod file_chooser_dialog;
+mod file_chooser_native;
 mod flow_box;
 mod functions;
+mod gl_area;
 mod icon_theme;
 mod im_context;
 mod im_context_simple;
@@ -99,20 +118,27 @@ mod keyval_trigger;
 mod label;
 mod list_box;
 mod list_store;
+mod media_stream;
 mod message_dialog;
 mod mnemonic_trigger;
+mod native_dialog;
 mod notebook;
 mod overlay;
 mod pad_action_entry;
 mod pad_controller;
 mod page_range;
+mod print_operation;
 mod recent_data;
 mod requisition;
 mod response_type;
+mod settings;
+mod shortcut_controller;
 mod shortcut_trigger;
 mod shortcuts_section;
+mod signal_list_item_factory;
 mod snapshot;
 mod spin_button;
+mod string_list;
 mod text;
 mod text_buffer;
 mod text_view;
@@ -121,16 +147,31 @@ mod tree_model_sort;
 mod tree_path;
 mod tree_sortable;
 mod tree_store;
+mod typed_list_model;
+mod typed_list_store;
 mod widget;
 
 pub use application::ApplicationBuilder;
 pub use border::Border;
+pub use builder::BuilderObjectError;
+pub use builder_rust_scope::BuilderRustScope;
 pub use css_location::CssLocation;
+pub use css_style_change::CssStyleChange;
+pub use expression::{
+    ClosureExpression, ConstantExpression, Expression, ExpressionWatch, ObjectExpression,
+    PropertyExpression,
+};
+pub use file_chooser::FileChooserFutureError;
 pub use functions::*;
 pub use pad_action_entry::PadActionEntry;
 pub use page_range::PageRange;
+pub use print_operation::{PrintPage, PrintPageStream};
 pub use recent_data::RecentData;
 pub use requisition::Requisition;
 pub use response_type::ResponseType;
+pub use string_list::StringListIter;
+pub use text_buffer::{TextBufferLines, TextMarkGuard, TextSearch};
 pub use tree_sortable::SortColumn;
-pub use widget::TickCallbackId;
+pub use typed_list_model::{TypedListModel, TypedListModelIter};
+pub use typed_list_store::TypedListStore;
+pub use widget::{TickCallbackId, WidgetChildren};
@@ -0,0 +1,25 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::MediaStream;
+use glib::translate::*;
+use glib::IsA;
+
+pub trait MediaStreamExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Sets the stream into an error state, which stops the playback.
+    ///
+    /// Only the first error is kept, the later ones are ignored.
+    #[doc(alias = "gtk_media_stream_gerror")]
+    fn gerror(&self, error: glib::Error);
+}
+
+impl<O: IsA<MediaStream>> MediaStreamExtManual for O {
+    fn gerror(&self, error: glib::Error) {
+        unsafe {
+            ffi::gtk_media_stream_gerror(
+                self.as_ref().to_glib_none().0,
+                error.to_glib_full() as *mut _,
+            );
+        }
+    }
+}
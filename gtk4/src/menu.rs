@@ -0,0 +1,209 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! A builder for the `gio::Menu`s shown by `gtk::PopoverMenu`,
+//! `gtk::PopoverMenuBar` and `gtk::MenuButton`.
+
+use gio::prelude::*;
+use glib::ToVariant;
+
+// rustdoc-stripper-ignore-next
+/// Builds a `gio::Menu` out of items, sections and submenus.
+///
+/// [`accel`] and [`icon`] apply to the item added last:
+///
+/// ```no_run
+/// # use gtk4 as gtk;
+/// # use gtk::glib::ToVariant;
+/// # use gtk::prelude::*;
+/// let menu = gtk::menu::MenuBuilder::new()
+///     .section(|section| {
+///         section
+///             .item("Open", "win.open")
+///             .accel("<Control>o")
+///             .item_with_target("Zoom In", "win.zoom", &1.25.to_variant())
+///     })
+///     .submenu("Theme", |submenu| {
+///         submenu
+///             .item("Light", "app.theme::light")
+///             .item("Dark", "app.theme::dark")
+///     })
+///     .item("Quit", "app.quit")
+///     .accel("<Control>q")
+///     .build();
+/// let button = gtk::MenuButton::new();
+/// button.set_menu_model(Some(&menu));
+/// ```
+///
+/// [`accel`]: #method.accel
+/// [`icon`]: #method.icon
+#[derive(Debug)]
+pub struct MenuBuilder {
+    menu: gio::Menu,
+    // Items are copied when added to the menu, the last one is only added
+    // once it can't be changed anymore.
+    pending_item: Option<gio::MenuItem>,
+}
+
+impl Default for MenuBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MenuBuilder {
+    pub fn new() -> Self {
+        Self {
+            menu: gio::Menu::new(),
+            pending_item: None,
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds an item activating `detailed_action`, like `"app.quit"`, or
+    /// `"win.zoom(1.5)"` and `"app.theme::dark"` with a target.
+    pub fn item(self, label: &str, detailed_action: &str) -> Self {
+        self.add_item(gio::MenuItem::new(Some(label), Some(detailed_action)))
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds an item activating `action` with the `target` parameter.
+    pub fn item_with_target(self, label: &str, action: &str, target: &glib::Variant) -> Self {
+        let item = gio::MenuItem::new(Some(label), None);
+        item.set_action_and_target_value(Some(action), Some(target));
+        self.add_item(item)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the accelerator shown next to the last item, like
+    /// `"<Control>q"`.
+    ///
+    /// This is only a hint, the accelerator has to be set on the application
+    /// with `gtk::Application::set_accels_for_action` to do anything.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no item was added yet.
+    pub fn accel(self, accel: &str) -> Self {
+        self.last_item("accel")
+            .set_attribute_value("accel", Some(&accel.to_variant()));
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the icon named `icon_name` on the last item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no item was added yet.
+    pub fn icon(self, icon_name: &str) -> Self {
+        self.last_item("icon")
+            .set_icon(&gio::ThemedIcon::new(icon_name));
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds a section, with the items added to the builder given to `f`.
+    pub fn section<F: FnOnce(MenuBuilder) -> MenuBuilder>(mut self, f: F) -> Self {
+        self.flush();
+        self.menu
+            .append_section(None, &f(MenuBuilder::new()).build());
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds a submenu, with the items added to the builder given to `f`.
+    pub fn submenu<F: FnOnce(MenuBuilder) -> MenuBuilder>(mut self, label: &str, f: F) -> Self {
+        self.flush();
+        self.menu
+            .append_submenu(Some(label), &f(MenuBuilder::new()).build());
+        self
+    }
+
+    pub fn build(mut self) -> gio::Menu {
+        self.flush();
+        self.menu
+    }
+
+    fn add_item(mut self, item: gio::MenuItem) -> Self {
+        self.flush();
+        self.pending_item = Some(item);
+        self
+    }
+
+    fn last_item(&self, attribute: &str) -> &gio::MenuItem {
+        self.pending_item
+            .as_ref()
+            .unwrap_or_else(|| panic!("No item to set the {} of", attribute))
+    }
+
+    fn flush(&mut self) {
+        if let Some(item) = self.pending_item.take() {
+            self.menu.append_item(&item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use glib::VariantTy;
+
+    fn attribute(model: &gio::MenuModel, index: i32, attribute: &str) -> Option<String> {
+        model
+            .get_item_attribute_value(index, attribute, Some(&VariantTy::new("s").unwrap()))
+            .and_then(|value| value.get::<String>())
+    }
+
+    #[test]
+    fn menu_builder() {
+        let menu = MenuBuilder::new()
+            .section(|section| {
+                section
+                    .item("Open", "win.open")
+                    .accel("<Control>o")
+                    .icon("document-open")
+                    .item_with_target("Zoom", "win.zoom", &1.5.to_variant())
+            })
+            .submenu("Theme", |submenu| submenu.item("Dark", "app.theme::dark"))
+            .item("Quit", "app.quit")
+            .build();
+        let menu = menu.upcast::<gio::MenuModel>();
+        assert_eq!(menu.get_n_items(), 3);
+
+        let section = menu.get_item_link(0, "section").unwrap();
+        assert_eq!(section.get_n_items(), 2);
+        assert_eq!(attribute(&section, 0, "label").as_deref(), Some("Open"));
+        assert_eq!(
+            attribute(&section, 0, "action").as_deref(),
+            Some("win.open")
+        );
+        assert_eq!(
+            attribute(&section, 0, "accel").as_deref(),
+            Some("<Control>o")
+        );
+        assert!(section.get_item_attribute_value(0, "icon", None).is_some());
+        assert_eq!(
+            attribute(&section, 1, "action").as_deref(),
+            Some("win.zoom")
+        );
+        assert_eq!(
+            section.get_item_attribute_value(1, "target", None),
+            Some(1.5.to_variant())
+        );
+
+        assert_eq!(attribute(&menu, 1, "label").as_deref(), Some("Theme"));
+        let submenu = menu.get_item_link(1, "submenu").unwrap();
+        assert_eq!(
+            attribute(&submenu, 0, "action").as_deref(),
+            Some("app.theme")
+        );
+        assert_eq!(
+            submenu.get_item_attribute_value(0, "target", None),
+            Some("dark".to_variant())
+        );
+
+        assert_eq!(attribute(&menu, 2, "action").as_deref(), Some("app.quit"));
+        assert!(menu.get_item_link(2, "section").is_none());
+    }
+}
@@ -0,0 +1,81 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::dialog::{run_dialog_async, run_dialog_future, ResponseDialog};
+use crate::{NativeDialog, NativeDialogExt, ResponseType, Window};
+use glib::object::Cast;
+use glib::{IsA, SignalHandlerId};
+use std::future::Future;
+use std::pin::Pin;
+
+pub trait NativeDialogExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Shows the dialog and returns a `Future` that resolves to the
+    /// `ResponseType` on response.
+    ///
+    /// The dialog is hidden once the response has been received, so it is
+    /// left to the caller to destroy it or show it again. If it is
+    /// destroyed before that, the `Future` resolves to `ResponseType::None`.
+    /// It keeps a strong reference to the dialog until it resolves, which is
+    /// needed for dialogs like `FileChooserNative` that nothing else owns.
+    ///
+    /// ```no_run
+    /// use gtk4::prelude::*;
+    ///
+    /// # async fn run() {
+    /// let dialog = gtk4::FileChooserNative::new(
+    ///     Some("Open File"),
+    ///     None::<&gtk4::Window>,
+    ///     gtk4::FileChooserAction::Open,
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// if dialog.run_future().await == gtk4::ResponseType::Accept {
+    ///     println!("Selected: {:?}", dialog.get_file());
+    /// }
+    /// # }
+    /// ```
+    fn run_future<'a>(&'a self) -> Pin<Box<dyn Future<Output = ResponseType> + 'a>>;
+
+    // rustdoc-stripper-ignore-next
+    /// Shows the dialog and calls the callback when a response has been received.
+    /// The dialog is hidden before the callback is called and kept alive until
+    /// then.
+    ///
+    /// **Important**: this function isn't blocking.
+    fn run_async<F: FnOnce(&Self, ResponseType) + 'static>(&self, f: F);
+}
+
+impl<O: IsA<NativeDialog>> NativeDialogExtManual for O {
+    fn run_future<'a>(&'a self) -> Pin<Box<dyn Future<Output = ResponseType> + 'a>> {
+        Box::pin(run_dialog_future(self.upcast_ref::<NativeDialog>().clone()))
+    }
+
+    fn run_async<F: FnOnce(&Self, ResponseType) + 'static>(&self, f: F) {
+        run_dialog_async(
+            self.upcast_ref::<NativeDialog>(),
+            move |dialog, response_type| f(dialog.downcast_ref::<O>().unwrap(), response_type),
+        );
+    }
+}
+
+impl ResponseDialog for NativeDialog {
+    fn dialog_connect_response<F: Fn(&Self, ResponseType) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        self.connect_response(f)
+    }
+
+    fn dialog_set_transient_for(&self, parent: Option<&Window>) {
+        self.set_transient_for(parent)
+    }
+
+    fn dialog_show(&self) {
+        self.show()
+    }
+
+    fn dialog_hide(&self) {
+        self.hide()
+    }
+}
@@ -4,6 +4,7 @@
 
 pub use crate::auto::traits::*;
 
+pub use crate::accessible::AccessibleExtManual;
 pub use crate::actionable::ActionableExtManual;
 pub use crate::builder::BuilderExtManual;
 pub use crate::cell_area::CellAreaExtManual;
@@ -17,13 +18,23 @@ pub use crate::editable::EditableExtManual;
 pub use crate::entry::EntryExtManual;
 pub use crate::entry_buffer::EntryBufferExtManual;
 pub use crate::entry_completion::EntryCompletionExtManual;
+pub use crate::file_chooser::FileChooserExtManual;
 pub use crate::flow_box::FlowBoxExtManual;
+pub use crate::gl_area::GLAreaExtManual;
+#[allow(deprecated)]
+pub use crate::icon_theme::IconThemeExtManual;
 pub use crate::im_context::IMContextExtManual;
 pub use crate::im_context_simple::IMContextSimpleExtManual;
 pub use crate::list_box::ListBoxExtManual;
 pub use crate::list_store::GtkListStoreExtManual;
+pub use crate::media_stream::MediaStreamExtManual;
+pub use crate::native_dialog::NativeDialogExtManual;
 pub use crate::notebook::NotebookExtManual;
 pub use crate::overlay::OverlayExtManual;
+pub use crate::print_operation::PrintOperationExtManual;
+pub use crate::property_binding::BindPropertyBuilderExt;
+pub use crate::shortcut_trigger::ShortcutTriggerExtManual;
+pub use crate::snapshot::SnapshotExtManual;
 pub use crate::spin_button::SpinButtonExtManual;
 pub use crate::text_buffer::TextBufferExtManual;
 pub use crate::text_view::TextViewExtManual;
@@ -0,0 +1,207 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{
+    PrintContext, PrintOperation, PrintOperationAction, PrintOperationExt, PrintOperationResult,
+    Window,
+};
+use futures_channel::mpsc;
+use futures_core::Stream;
+use glib::{IsA, ObjectExt, SignalHandlerId};
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+pub trait PrintOperationExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Runs the operation asynchronously and returns a `Future` that
+    /// resolves once it is done.
+    ///
+    /// The operation is only started when the `Future` is first polled. It
+    /// resolves to the error of the operation if the result is
+    /// `PrintOperationResult::Error`.
+    ///
+    /// The pages are drawn from the `draw-page` signal, or from the stream
+    /// returned by [`pages_stream`].
+    ///
+    /// [`pages_stream`]: #tymethod.pages_stream
+    fn run_future<P: IsA<Window>>(
+        &self,
+        action: PrintOperationAction,
+        parent: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<PrintOperationResult, glib::Error>> + 'static>>;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns a stream of the pages to draw, to be called before running
+    /// the operation.
+    ///
+    /// Drawing is deferred until the yielded [`PrintPage`] is dropped, so the
+    /// operation doesn't ask for the next page before the current one has
+    /// been drawn. The stream ends after the last page, on `end-print`, or
+    /// when the operation is done.
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use gtk4::prelude::*;
+    ///
+    /// # async fn print(operation: gtk4::PrintOperation) {
+    /// let mut pages = operation.pages_stream();
+    /// let result = operation.run_future(
+    ///     gtk4::PrintOperationAction::PrintDialog,
+    ///     None::<&gtk4::Window>,
+    /// );
+    /// gtk4::glib::MainContext::default().spawn_local(async move {
+    ///     while let Some(page) = pages.next().await {
+    ///         let cr = page.context().get_cairo_context().unwrap();
+    ///         cr.move_to(20.0, 20.0);
+    ///         cr.show_text(&format!("Page {}", page.page_nr() + 1));
+    ///     }
+    /// });
+    /// result.await.expect("Printing failed");
+    /// # }
+    /// ```
+    ///
+    /// [`PrintPage`]: struct.PrintPage.html
+    fn pages_stream(&self) -> PrintPageStream;
+}
+
+impl<O: IsA<PrintOperation>> PrintOperationExtManual for O {
+    fn run_future<P: IsA<Window>>(
+        &self,
+        action: PrintOperationAction,
+        parent: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<PrintOperationResult, glib::Error>> + 'static>> {
+        // Keep the operation and its parent alive until it is done.
+        let operation = self.as_ref().clone();
+        let parent = parent.map(|p| p.as_ref().clone());
+        Box::pin(async move {
+            let (sender, receiver) = futures_channel::oneshot::channel();
+
+            let sender = Cell::new(Some(sender));
+
+            let done_handler = operation.connect_done(move |_, result| {
+                if let Some(m) = sender.replace(None) {
+                    let _result = m.send(result);
+                }
+            });
+
+            operation.set_allow_async(true);
+            let result = match operation.run(action, parent.as_ref()) {
+                Ok(PrintOperationResult::InProgress) => {
+                    receiver.await.unwrap_or(PrintOperationResult::Cancel)
+                }
+                Ok(result) => result,
+                Err(err) => {
+                    operation.disconnect(done_handler);
+                    return Err(err);
+                }
+            };
+            operation.disconnect(done_handler);
+
+            if result == PrintOperationResult::Error {
+                operation.get_error()?;
+            }
+            Ok(result)
+        })
+    }
+
+    fn pages_stream(&self) -> PrintPageStream {
+        let (sender, receiver) = mpsc::unbounded();
+        let sender = Rc::new(RefCell::new(Some(sender)));
+        let handlers = Rc::new(RefCell::new(Vec::<SignalHandlerId>::new()));
+
+        let draw_page_handler = {
+            let sender = sender.clone();
+            self.as_ref()
+                .connect_draw_page(move |operation, context, page_nr| {
+                    if let Some(ref sender) = *sender.borrow() {
+                        operation.set_defer_drawing();
+                        // If the stream was dropped, the page is finished blank
+                        // when dropping it with the error.
+                        let _ = sender.unbounded_send(PrintPage {
+                            operation: operation.clone(),
+                            context: context.clone(),
+                            page_nr,
+                        });
+                    }
+                })
+        };
+
+        let close = {
+            let handlers = handlers.clone();
+            move |operation: &PrintOperation| {
+                sender.borrow_mut().take();
+                for handler in handlers.borrow_mut().drain(..) {
+                    operation.disconnect(handler);
+                }
+            }
+        };
+        let close = Rc::new(close);
+        let end_print_handler = {
+            let close = close.clone();
+            self.as_ref()
+                .connect_end_print(move |operation, _| close(operation))
+        };
+        let done_handler = self
+            .as_ref()
+            .connect_done(move |operation, _| close(operation));
+
+        handlers
+            .borrow_mut()
+            .extend(vec![draw_page_handler, end_print_handler, done_handler]);
+
+        PrintPageStream { receiver }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// A page to draw, yielded by [`PrintPageStream`].
+///
+/// The page is finished, and the operation moves on to the next one, when it
+/// is dropped.
+///
+/// [`PrintPageStream`]: struct.PrintPageStream.html
+#[derive(Debug)]
+pub struct PrintPage {
+    operation: PrintOperation,
+    context: PrintContext,
+    page_nr: i32,
+}
+
+impl PrintPage {
+    pub fn context(&self) -> &PrintContext {
+        &self.context
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the 0-based number of the page.
+    pub fn page_nr(&self) -> i32 {
+        self.page_nr
+    }
+}
+
+impl Drop for PrintPage {
+    fn drop(&mut self) {
+        self.operation.draw_page_finish();
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// A stream of the pages of a [`PrintOperation`] to draw, returned by
+/// [`PrintOperationExtManual::pages_stream`].
+///
+/// [`PrintOperation`]: struct.PrintOperation.html
+/// [`PrintOperationExtManual::pages_stream`]: prelude/trait.PrintOperationExtManual.html#tymethod.pages_stream
+#[derive(Debug)]
+pub struct PrintPageStream {
+    receiver: mpsc::UnboundedReceiver<PrintPage>,
+}
+
+impl Stream for PrintPageStream {
+    type Item = PrintPage;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<PrintPage>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
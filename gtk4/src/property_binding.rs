@@ -0,0 +1,298 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Property bindings with typed transformations.
+
+use glib::object::{IsA, ObjectExt};
+use glib::value::FromValueOptional;
+use glib::{Binding, BindingFlags, Cast, Object, StaticType, ToValue, Type, Value};
+use std::fmt;
+
+pub trait BindPropertyBuilderExt: IsA<Object> {
+    // rustdoc-stripper-ignore-next
+    /// Starts binding `source_property` of the object to `target_property` of
+    /// `target`, see [`PropertyBindingBuilder`].
+    ///
+    /// [`PropertyBindingBuilder`]: ../property_binding/struct.PropertyBindingBuilder.html
+    fn bind_property_builder<'a, O: IsA<Object>>(
+        &'a self,
+        source_property: &'a str,
+        target: &'a O,
+        target_property: &'a str,
+    ) -> PropertyBindingBuilder<'a> {
+        PropertyBindingBuilder {
+            source: self.upcast_ref(),
+            source_property,
+            target: target.upcast_ref(),
+            target_property,
+            flags: BindingFlags::DEFAULT,
+            transform_to: None,
+            transform_from: None,
+        }
+    }
+}
+
+impl<O: IsA<Object>> BindPropertyBuilderExt for O {}
+
+// A transformation from values of type `from` to values of type `to`.
+struct Transform {
+    from: Type,
+    to: Type,
+    func: Box<dyn Fn(&Binding, &Value) -> Option<Value> + Send + Sync>,
+}
+
+impl Transform {
+    fn new<S, T, F>(func: F) -> Self
+    where
+        S: for<'b> FromValueOptional<'b> + StaticType,
+        T: ToValue + StaticType,
+        F: Fn(S) -> Option<T> + Send + Sync + 'static,
+    {
+        Self {
+            from: S::static_type(),
+            to: T::static_type(),
+            // `None` values, like unset objects, aren't transformed and
+            // leave the other property as it is, like `None` results.
+            func: Box::new(move |_, value| {
+                value
+                    .get::<S>()
+                    .ok()
+                    .and_then(|value| value)
+                    .and_then(|value| func(value))
+                    .map(|value| value.to_value())
+            }),
+        }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Binds `source_property` of `source` to `target_property` of `target`,
+/// transforming the values with `transform_to`, and with `transform_from`
+/// for `BindingFlags::BIDIRECTIONAL` bindings. When a transformation returns
+/// `None`, the other property keeps its value.
+///
+/// ```no_run
+/// # use gtk4 as gtk;
+/// # use gtk::glib;
+/// let spin_button = gtk::SpinButton::with_range(0.0, 100.0, 1.0);
+/// let entry = gtk::Entry::new();
+/// gtk::property_binding::bind_property_full(
+///     &spin_button,
+///     "value",
+///     &entry,
+///     "text",
+///     glib::BindingFlags::BIDIRECTIONAL | glib::BindingFlags::SYNC_CREATE,
+///     |value: f64| Some(value.to_string()),
+///     |text: String| text.parse::<f64>().ok(),
+/// );
+/// ```
+///
+/// This is a shortcut for [`BindPropertyBuilderExt::bind_property_builder`],
+/// and panics in the same cases as [`PropertyBindingBuilder::build`].
+///
+/// [`BindPropertyBuilderExt::bind_property_builder`]: ../prelude/trait.BindPropertyBuilderExt.html#method.bind_property_builder
+/// [`PropertyBindingBuilder::build`]: struct.PropertyBindingBuilder.html#method.build
+pub fn bind_property_full<O, P, S, T, F, G>(
+    source: &O,
+    source_property: &str,
+    target: &P,
+    target_property: &str,
+    flags: BindingFlags,
+    transform_to: F,
+    transform_from: G,
+) -> Binding
+where
+    O: IsA<Object>,
+    P: IsA<Object>,
+    S: for<'b> FromValueOptional<'b> + ToValue + StaticType,
+    T: for<'b> FromValueOptional<'b> + ToValue + StaticType,
+    F: Fn(S) -> Option<T> + Send + Sync + 'static,
+    G: Fn(T) -> Option<S> + Send + Sync + 'static,
+{
+    let bidirectional = flags.contains(BindingFlags::BIDIRECTIONAL);
+    let mut builder = source.bind_property_builder(source_property, target, target_property);
+    builder.flags = flags;
+    builder.transform_to = Some(Transform::new(transform_to));
+    // The types of the reverse transformation are only checked when it is
+    // used.
+    if bidirectional {
+        builder.transform_from = Some(Transform::new(transform_from));
+    }
+    builder.build()
+}
+
+// rustdoc-stripper-ignore-next
+/// Binds a property of an object to a property of another object, created
+/// with [`BindPropertyBuilderExt::bind_property_builder`].
+///
+/// The transformations take and return Rust values, their types are checked
+/// against the types of the properties by [`build`]:
+///
+/// ```no_run
+/// # use gtk4 as gtk;
+/// # use gtk::prelude::*;
+/// let scale = gtk::Scale::with_range(gtk::Orientation::Horizontal, 0.0, 10.0, 0.1);
+/// let label = gtk::Label::new(None);
+/// scale
+///     .get_adjustment()
+///     .bind_property_builder("value", &label, "label")
+///     .transform_to(|value: f64| format!("{:.1}", value))
+///     .sync_create()
+///     .build();
+/// ```
+///
+/// [`BindPropertyBuilderExt::bind_property_builder`]: ../prelude/trait.BindPropertyBuilderExt.html#method.bind_property_builder
+/// [`build`]: #method.build
+pub struct PropertyBindingBuilder<'a> {
+    source: &'a Object,
+    source_property: &'a str,
+    target: &'a Object,
+    target_property: &'a str,
+    flags: BindingFlags,
+    transform_to: Option<Transform>,
+    transform_from: Option<Transform>,
+}
+
+impl<'a> fmt::Debug for PropertyBindingBuilder<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PropertyBindingBuilder")
+            .field("source", &self.source)
+            .field("source_property", &self.source_property)
+            .field("target", &self.target)
+            .field("target_property", &self.target_property)
+            .field("flags", &self.flags)
+            .finish()
+    }
+}
+
+impl<'a> PropertyBindingBuilder<'a> {
+    // rustdoc-stripper-ignore-next
+    /// Transforms the values of the source property with `func` before
+    /// setting them on the target property.
+    pub fn transform_to<S, T, F>(mut self, func: F) -> Self
+    where
+        S: for<'b> FromValueOptional<'b> + StaticType,
+        T: ToValue + StaticType,
+        F: Fn(S) -> T + Send + Sync + 'static,
+    {
+        self.transform_to = Some(Transform::new(move |value| Some(func(value))));
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Transforms the values of the target property with `func` before
+    /// setting them on the source property, for [`bidirectional`] bindings.
+    ///
+    /// [`bidirectional`]: #method.bidirectional
+    pub fn transform_from<S, T, F>(mut self, func: F) -> Self
+    where
+        S: for<'b> FromValueOptional<'b> + StaticType,
+        T: ToValue + StaticType,
+        F: Fn(S) -> T + Send + Sync + 'static,
+    {
+        self.transform_from = Some(Transform::new(move |value| Some(func(value))));
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Also updates the source property when the target property changes.
+    pub fn bidirectional(mut self) -> Self {
+        self.flags |= BindingFlags::BIDIRECTIONAL;
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the target property to the current value of the source property
+    /// when the binding is created.
+    pub fn sync_create(mut self) -> Self {
+        self.flags |= BindingFlags::SYNC_CREATE;
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Negates boolean values, for bindings between boolean properties
+    /// without transformations.
+    pub fn invert_boolean(mut self) -> Self {
+        self.flags |= BindingFlags::INVERT_BOOLEAN;
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates the binding. It is removed with `Binding::unbind`, or when
+    /// either object is finalized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either property doesn't exist, or if the types of the
+    /// transformations don't match the types of the properties.
+    pub fn build(self) -> Binding {
+        let PropertyBindingBuilder {
+            source,
+            source_property,
+            target,
+            target_property,
+            flags,
+            transform_to,
+            transform_from,
+        } = self;
+        let source_type = property_type(source, source_property);
+        let target_type = property_type(target, target_property);
+
+        let mut builder = source
+            .bind_property(source_property, target, target_property)
+            .flags(flags);
+        if let Some(transform) = transform_to {
+            check_transform(
+                &transform,
+                (source_property, source_type),
+                (target_property, target_type),
+            );
+            builder = builder.transform_to(transform.func);
+        }
+        if let Some(transform) = transform_from {
+            check_transform(
+                &transform,
+                (target_property, target_type),
+                (source_property, source_type),
+            );
+            builder = builder.transform_from(transform.func);
+        }
+
+        builder.build().unwrap_or_else(|| {
+            panic!(
+                "Failed to bind property `{}` of {} to property `{}` of {}",
+                source_property,
+                source.get_type(),
+                target_property,
+                target.get_type(),
+            )
+        })
+    }
+}
+
+fn property_type(object: &Object, property: &str) -> Type {
+    object
+        .find_property(property)
+        .unwrap_or_else(|| panic!("{} has no property `{}`", object.get_type(), property))
+        .get_value_type()
+}
+
+fn check_transform(
+    transform: &Transform,
+    (from, from_type): (&str, Type),
+    (to, to_type): (&str, Type),
+) {
+    assert!(
+        from_type.is_a(&transform.from),
+        "Property `{}` of type {} can't be transformed from {}",
+        from,
+        from_type,
+        transform.from,
+    );
+    assert!(
+        transform.to.is_a(&to_type),
+        "Property `{}` of type {} can't be set to a transformed {}",
+        to,
+        to_type,
+        transform.to,
+    );
+}
@@ -0,0 +1,20 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Settings;
+use glib::translate::*;
+
+impl Settings {
+    // rustdoc-stripper-ignore-next
+    /// Gets the settings of the default display.
+    ///
+    /// Returns `None` if there is no default display, or if GTK isn't
+    /// initialized or this isn't the main thread.
+    #[doc(alias = "gtk_settings_get_default")]
+    pub fn get_default() -> Option<Settings> {
+        skip_assert_initialized!();
+        if !crate::rt::is_initialized_main_thread() {
+            return None;
+        }
+        unsafe { from_glib_none(ffi::gtk_settings_get_default()) }
+    }
+}
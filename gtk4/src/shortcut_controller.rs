@@ -0,0 +1,26 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{CallbackAction, Shortcut, ShortcutController, ShortcutTrigger, Widget};
+
+impl ShortcutController {
+    // rustdoc-stripper-ignore-next
+    /// Adds a shortcut calling `action` when `trigger` is triggered, and
+    /// returns it.
+    ///
+    /// `trigger` is parsed with [`ShortcutTrigger::parse_string`], like
+    /// `"<Control>q"`, and `action` returns whether it handled the shortcut.
+    ///
+    /// [`ShortcutTrigger::parse_string`]: struct.ShortcutTrigger.html#method.parse_string
+    pub fn add_shortcut_str<F: Fn(&Widget) -> bool + 'static>(
+        &self,
+        trigger: &str,
+        action: F,
+    ) -> Result<Shortcut, glib::BoolError> {
+        let trigger = ShortcutTrigger::parse_string(trigger)
+            .ok_or_else(|| glib::bool_error!("Invalid shortcut trigger `{}`", trigger))?;
+        let action = CallbackAction::new(move |widget, _| action(widget));
+        let shortcut = Shortcut::new(Some(&trigger), Some(&action));
+        self.add_shortcut(&shortcut);
+        Ok(shortcut)
+    }
+}
@@ -0,0 +1,104 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{ListItem, SignalListItemFactory, Widget};
+use glib::object::IsA;
+use glib::{Cast, ObjectExt, SignalHandlerId, StaticType};
+
+impl SignalListItemFactory {
+    // rustdoc-stripper-ignore-next
+    /// Creates a factory setting a widget created by `setup` as the child of
+    /// each list item, and calling `bind` with that child and the item.
+    ///
+    /// `unbind` and `teardown` handlers can be added with
+    /// [`connect_typed_unbind`] and [`connect_typed_teardown`].
+    ///
+    /// # Panics
+    ///
+    /// `bind` panics if the item is not a `T`.
+    ///
+    /// [`connect_typed_unbind`]: #method.connect_typed_unbind
+    /// [`connect_typed_teardown`]: #method.connect_typed_teardown
+    pub fn with_handlers<W, T, S, B>(setup: S, bind: B) -> Self
+    where
+        W: IsA<Widget>,
+        T: IsA<glib::Object>,
+        S: Fn() -> W + 'static,
+        B: Fn(&W, &T) + 'static,
+    {
+        let factory = Self::new();
+        factory.connect_setup(move |_, list_item| {
+            list_item.set_child(Some(&setup()));
+        });
+        factory.connect_bind(move |_, list_item| {
+            bind(
+                &typed_child::<W>(list_item, "bind"),
+                &typed_item::<T>(list_item, "bind"),
+            );
+        });
+        factory
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Connects to `unbind` with the child and the item of the list item
+    /// downcasted, see [`with_handlers`].
+    ///
+    /// [`with_handlers`]: #method.with_handlers
+    pub fn connect_typed_unbind<W, T, F>(&self, f: F) -> SignalHandlerId
+    where
+        W: IsA<Widget>,
+        T: IsA<glib::Object>,
+        F: Fn(&W, &T) + 'static,
+    {
+        self.connect_unbind(move |_, list_item| {
+            f(
+                &typed_child::<W>(list_item, "unbind"),
+                &typed_item::<T>(list_item, "unbind"),
+            );
+        })
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Connects to `teardown` with the child of the list item downcasted,
+    /// see [`with_handlers`].
+    ///
+    /// [`with_handlers`]: #method.with_handlers
+    pub fn connect_typed_teardown<W, F>(&self, f: F) -> SignalHandlerId
+    where
+        W: IsA<Widget>,
+        F: Fn(&W) + 'static,
+    {
+        self.connect_teardown(move |_, list_item| {
+            f(&typed_child::<W>(list_item, "teardown"));
+        })
+    }
+}
+
+fn typed_child<W: IsA<Widget>>(list_item: &ListItem, signal: &str) -> W {
+    let child = list_item
+        .get_child()
+        .unwrap_or_else(|| panic!("List item has no child in `{}`", signal));
+    let child_type = child.get_type();
+    child.downcast::<W>().unwrap_or_else(|_| {
+        panic!(
+            "List item child of type {} is not a {} in `{}`",
+            child_type,
+            W::static_type(),
+            signal
+        )
+    })
+}
+
+fn typed_item<T: IsA<glib::Object>>(list_item: &ListItem, signal: &str) -> T {
+    let item = list_item
+        .get_item()
+        .unwrap_or_else(|| panic!("List item has no item in `{}`", signal));
+    let item_type = item.get_type();
+    item.downcast::<T>().unwrap_or_else(|_| {
+        panic!(
+            "List item of type {} is not a {} in `{}`",
+            item_type,
+            T::static_type(),
+            signal
+        )
+    })
+}
@@ -2,8 +2,9 @@
 
 use crate::Snapshot;
 use glib::translate::*;
+use glib::IsA;
 use graphene::{Point, Rect};
-use gsk::ColorStop;
+use gsk::{ColorStop, RoundedRect};
 
 impl Snapshot {
     #[doc(alias = "gtk_snapshot_append_linear_gradient")]
@@ -123,3 +124,102 @@ impl Snapshot {
         }
     }
 }
+
+// rustdoc-stripper-ignore-next
+/// Shorthands taking plain coordinates instead of `graphene` and `gsk`
+/// geometry types.
+pub trait SnapshotExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Appends a rectangle filled with `color`.
+    #[doc(alias = "gtk_snapshot_append_color")]
+    fn append_color_rect(&self, color: &gdk::RGBA, x: f32, y: f32, width: f32, height: f32);
+
+    // rustdoc-stripper-ignore-next
+    /// Appends `texture` scaled to the given rectangle.
+    #[doc(alias = "gtk_snapshot_append_texture")]
+    fn append_texture_rect<P: IsA<gdk::Texture>>(
+        &self,
+        texture: &P,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    );
+
+    #[doc(alias = "gtk_snapshot_translate")]
+    fn translate_xy(&self, x: f32, y: f32);
+
+    // rustdoc-stripper-ignore-next
+    /// Calls `f` between [`save`] and [`restore`]. The state is restored
+    /// even if `f` panics.
+    ///
+    /// [`save`]: ../struct.Snapshot.html#method.save
+    /// [`restore`]: ../struct.Snapshot.html#method.restore
+    fn with_state<F: FnOnce(&Snapshot)>(&self, f: F);
+
+    // rustdoc-stripper-ignore-next
+    /// Pushes a clip to `bounds`, which must be popped with [`pop`].
+    ///
+    /// [`pop`]: ../struct.Snapshot.html#method.pop
+    #[doc(alias = "gtk_snapshot_push_clip")]
+    fn push_clip_rect(&self, bounds: &Rect);
+
+    // rustdoc-stripper-ignore-next
+    /// Pushes a clip to `bounds` with all its corners rounded by `radius`,
+    /// which must be popped with [`pop`].
+    ///
+    /// The generated [`push_rounded_clip`] keeps its name for the
+    /// `gsk::RoundedRect` version.
+    ///
+    /// [`pop`]: ../struct.Snapshot.html#method.pop
+    /// [`push_rounded_clip`]: ../struct.Snapshot.html#method.push_rounded_clip
+    #[doc(alias = "gtk_snapshot_push_rounded_clip")]
+    fn push_rounded_clip_rect(&self, bounds: &Rect, radius: f32);
+}
+
+impl<O: IsA<Snapshot>> SnapshotExtManual for O {
+    fn append_color_rect(&self, color: &gdk::RGBA, x: f32, y: f32, width: f32, height: f32) {
+        self.as_ref()
+            .append_color(color, &Rect::new(x, y, width, height));
+    }
+
+    fn append_texture_rect<P: IsA<gdk::Texture>>(
+        &self,
+        texture: &P,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    ) {
+        self.as_ref()
+            .append_texture(texture, &Rect::new(x, y, width, height));
+    }
+
+    fn translate_xy(&self, x: f32, y: f32) {
+        self.as_ref().translate(&Point::new(x, y));
+    }
+
+    fn with_state<F: FnOnce(&Snapshot)>(&self, f: F) {
+        struct RestoreGuard<'a>(&'a Snapshot);
+
+        impl<'a> Drop for RestoreGuard<'a> {
+            fn drop(&mut self) {
+                self.0.restore();
+            }
+        }
+
+        let snapshot = self.as_ref();
+        snapshot.save();
+        let _guard = RestoreGuard(snapshot);
+        f(snapshot);
+    }
+
+    fn push_clip_rect(&self, bounds: &Rect) {
+        self.as_ref().push_clip(bounds);
+    }
+
+    fn push_rounded_clip_rect(&self, bounds: &Rect, radius: f32) {
+        self.as_ref()
+            .push_rounded_clip(&RoundedRect::new_from_rect(bounds.clone(), radius));
+    }
+}
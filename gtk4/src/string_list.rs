@@ -0,0 +1,77 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::StringList;
+use gio::prelude::*;
+use std::iter::FromIterator;
+
+impl StringList {
+    // rustdoc-stripper-ignore-next
+    /// Returns an iterator over the strings. They are read one at a time, so
+    /// the iterator sees the changes made to the list while iterating.
+    pub fn iter(&self) -> StringListIter<'_> {
+        StringListIter {
+            list: self,
+            position: 0,
+        }
+    }
+
+    // Appends with a single `items-changed` emission.
+    fn extend_strings<S: AsRef<str>, I: IntoIterator<Item = S>>(&self, iter: I) {
+        let strings = iter.into_iter().collect::<Vec<_>>();
+        let strings = strings.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+        self.splice(self.get_n_items(), 0, &strings);
+    }
+}
+
+impl<'a> IntoIterator for &'a StringList {
+    type Item = glib::GString;
+    type IntoIter = StringListIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<String> for StringList {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let list = StringList::new(&[]);
+        list.extend_strings(iter);
+        list
+    }
+}
+
+impl<'a> FromIterator<&'a str> for StringList {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let list = StringList::new(&[]);
+        list.extend_strings(iter);
+        list
+    }
+}
+
+impl Extend<String> for StringList {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        self.extend_strings(iter);
+    }
+}
+
+impl<'a> Extend<&'a str> for StringList {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.extend_strings(iter);
+    }
+}
+
+#[derive(Debug)]
+pub struct StringListIter<'a> {
+    list: &'a StringList,
+    position: u32,
+}
+
+impl<'a> Iterator for StringListIter<'a> {
+    type Item = glib::GString;
+
+    fn next(&mut self) -> Option<glib::GString> {
+        let string = self.list.get_string(self.position)?;
+        self.position += 1;
+        Some(string)
+    }
+}
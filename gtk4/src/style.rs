@@ -0,0 +1,72 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Helpers installing style sheets for all the widgets of a display.
+
+use crate::{CssProvider, StyleContext};
+
+// rustdoc-stripper-ignore-next
+/// The priorities of the style providers of a display, from the lowest to
+/// the highest. The rules of the providers of higher priority win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleProviderPriority {
+    // rustdoc-stripper-ignore-next
+    /// For the defaults of the theme, used when it has no value.
+    Fallback,
+    // rustdoc-stripper-ignore-next
+    /// For the theme.
+    Theme,
+    // rustdoc-stripper-ignore-next
+    /// For the style information of the `gtk::Settings`.
+    Settings,
+    // rustdoc-stripper-ignore-next
+    /// For the style sheets of applications.
+    Application,
+    // rustdoc-stripper-ignore-next
+    /// For the style sheet of the user, `gtk-4.0/gtk.css` in their
+    /// configuration directory.
+    User,
+}
+
+impl From<StyleProviderPriority> for u32 {
+    fn from(priority: StyleProviderPriority) -> u32 {
+        match priority {
+            StyleProviderPriority::Fallback => crate::STYLE_PROVIDER_PRIORITY_FALLBACK,
+            StyleProviderPriority::Theme => crate::STYLE_PROVIDER_PRIORITY_THEME,
+            StyleProviderPriority::Settings => crate::STYLE_PROVIDER_PRIORITY_SETTINGS,
+            StyleProviderPriority::Application => crate::STYLE_PROVIDER_PRIORITY_APPLICATION,
+            StyleProviderPriority::User => crate::STYLE_PROVIDER_PRIORITY_USER,
+        }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Loads `css` and adds it to the default display with `priority`, and
+/// returns its provider, to be removed with
+/// `StyleContext::remove_provider_for_display`.
+///
+/// Returns the first parsing error instead, see
+/// `CssProvider::load_from_data_checked`.
+///
+/// ```no_run
+/// # use gtk4 as gtk;
+/// use gtk::style::{add_css, StyleProviderPriority};
+///
+/// add_css(
+///     "button.destructive { color: red; }",
+///     StyleProviderPriority::Application,
+/// )
+/// .expect("Invalid style sheet");
+/// ```
+///
+/// # Panics
+///
+/// Panics if there is no default display.
+#[doc(alias = "gtk_style_context_add_provider_for_display")]
+pub fn add_css(css: &str, priority: StyleProviderPriority) -> Result<CssProvider, glib::Error> {
+    assert_initialized_main_thread!();
+    let display = gdk::Display::get_default().expect("No default display to add the CSS to");
+    let provider = CssProvider::new();
+    provider.load_from_data_checked(css.as_bytes())?;
+    StyleContext::add_provider_for_display(&display, &provider, priority.into());
+    Ok(provider)
+}
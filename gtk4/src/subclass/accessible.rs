@@ -3,6 +3,11 @@
 use crate::Accessible;
 use glib::subclass::prelude::*;
 
+// rustdoc-stripper-ignore-next
+/// `GtkAccessibleInterface` has no virtual functions, widgets declare their
+/// role with [`WidgetClassSubclassExt::set_accessible_role`] in `class_init`.
+///
+/// [`WidgetClassSubclassExt::set_accessible_role`]: ../widget/trait.WidgetClassSubclassExt.html#method.set_accessible_role
 pub trait AccessibleImpl: ObjectImpl {}
 
 unsafe impl<T: AccessibleImpl> IsImplementable<T> for Accessible {
@@ -0,0 +1,230 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::subclass::parent_iface;
+use crate::{Builder, BuilderClosureFlags, BuilderScope};
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use glib::{Cast, GString, Object};
+use libc::c_char;
+use std::ptr;
+
+pub trait BuilderScopeImpl: BuilderScopeImplExt + ObjectImpl {
+    // rustdoc-stripper-ignore-next
+    /// Returns the type named `type_name` in a `<object class="...">`
+    /// element, or `glib::Type::Invalid` if there is none.
+    fn get_type_from_name(
+        &self,
+        builder_scope: &Self::Type,
+        builder: &Builder,
+        type_name: &str,
+    ) -> glib::Type {
+        self.parent_get_type_from_name(builder_scope, builder, type_name)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the type returned by the `get_type` function named
+    /// `function_name` in a `<object type-func="...">` element, or
+    /// `glib::Type::Invalid` if there is none.
+    fn get_type_from_function(
+        &self,
+        builder_scope: &Self::Type,
+        builder: &Builder,
+        function_name: &str,
+    ) -> glib::Type {
+        self.parent_get_type_from_function(builder_scope, builder, function_name)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the closure for the handler named `function_name` in a
+    /// `<signal>` element, or for the function of a `<closure>` expression.
+    fn create_closure(
+        &self,
+        builder_scope: &Self::Type,
+        builder: &Builder,
+        function_name: &str,
+        flags: BuilderClosureFlags,
+        object: Option<&Object>,
+    ) -> Result<glib::Closure, glib::Error>;
+}
+
+pub trait BuilderScopeImplExt: ObjectSubclass {
+    fn parent_get_type_from_name(
+        &self,
+        builder_scope: &Self::Type,
+        builder: &Builder,
+        type_name: &str,
+    ) -> glib::Type;
+
+    fn parent_get_type_from_function(
+        &self,
+        builder_scope: &Self::Type,
+        builder: &Builder,
+        function_name: &str,
+    ) -> glib::Type;
+
+    fn parent_create_closure(
+        &self,
+        builder_scope: &Self::Type,
+        builder: &Builder,
+        function_name: &str,
+        flags: BuilderClosureFlags,
+        object: Option<&Object>,
+    ) -> Result<glib::Closure, glib::Error>;
+}
+
+impl<T: BuilderScopeImpl> BuilderScopeImplExt for T {
+    fn parent_get_type_from_name(
+        &self,
+        builder_scope: &Self::Type,
+        builder: &Builder,
+        type_name: &str,
+    ) -> glib::Type {
+        unsafe {
+            let parent_iface = parent_iface::<T, BuilderScope, ffi::GtkBuilderScopeInterface>();
+            let f = (*parent_iface)
+                .get_type_from_name
+                .expect("No parent iface impl for \"get_type_from_name\"");
+            from_glib(f(
+                builder_scope
+                    .unsafe_cast_ref::<BuilderScope>()
+                    .to_glib_none()
+                    .0,
+                builder.to_glib_none().0,
+                type_name.to_glib_none().0,
+            ))
+        }
+    }
+
+    fn parent_get_type_from_function(
+        &self,
+        builder_scope: &Self::Type,
+        builder: &Builder,
+        function_name: &str,
+    ) -> glib::Type {
+        unsafe {
+            let parent_iface = parent_iface::<T, BuilderScope, ffi::GtkBuilderScopeInterface>();
+            let f = (*parent_iface)
+                .get_type_from_function
+                .expect("No parent iface impl for \"get_type_from_function\"");
+            from_glib(f(
+                builder_scope
+                    .unsafe_cast_ref::<BuilderScope>()
+                    .to_glib_none()
+                    .0,
+                builder.to_glib_none().0,
+                function_name.to_glib_none().0,
+            ))
+        }
+    }
+
+    fn parent_create_closure(
+        &self,
+        builder_scope: &Self::Type,
+        builder: &Builder,
+        function_name: &str,
+        flags: BuilderClosureFlags,
+        object: Option<&Object>,
+    ) -> Result<glib::Closure, glib::Error> {
+        unsafe {
+            let parent_iface = parent_iface::<T, BuilderScope, ffi::GtkBuilderScopeInterface>();
+            let f = (*parent_iface)
+                .create_closure
+                .expect("No parent iface impl for \"create_closure\"");
+            let mut error = ptr::null_mut();
+            let closure = f(
+                builder_scope
+                    .unsafe_cast_ref::<BuilderScope>()
+                    .to_glib_none()
+                    .0,
+                builder.to_glib_none().0,
+                function_name.to_glib_none().0,
+                flags.to_glib(),
+                object.to_glib_none().0,
+                &mut error,
+            );
+            if error.is_null() {
+                Ok(from_glib_full(closure))
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+}
+
+unsafe impl<T: BuilderScopeImpl> IsImplementable<T> for BuilderScope {
+    unsafe extern "C" fn interface_init(
+        iface: glib::ffi::gpointer,
+        _iface_data: glib::ffi::gpointer,
+    ) {
+        let builder_scope_iface = &mut *(iface as *mut ffi::GtkBuilderScopeInterface);
+
+        builder_scope_iface.get_type_from_name = Some(builder_scope_get_type_from_name::<T>);
+        builder_scope_iface.get_type_from_function =
+            Some(builder_scope_get_type_from_function::<T>);
+        builder_scope_iface.create_closure = Some(builder_scope_create_closure::<T>);
+    }
+}
+
+unsafe extern "C" fn builder_scope_get_type_from_name<T: BuilderScopeImpl>(
+    builder_scope: *mut ffi::GtkBuilderScope,
+    builderptr: *mut ffi::GtkBuilder,
+    type_nameptr: *const c_char,
+) -> glib::ffi::GType {
+    let instance = &*(builder_scope as *mut T::Instance);
+    let imp = instance.get_impl();
+    let builder: Borrowed<Builder> = from_glib_borrow(builderptr);
+
+    imp.get_type_from_name(
+        from_glib_borrow::<_, BuilderScope>(builder_scope).unsafe_cast_ref(),
+        &builder,
+        &GString::from_glib_borrow(type_nameptr),
+    )
+    .to_glib()
+}
+
+unsafe extern "C" fn builder_scope_get_type_from_function<T: BuilderScopeImpl>(
+    builder_scope: *mut ffi::GtkBuilderScope,
+    builderptr: *mut ffi::GtkBuilder,
+    function_nameptr: *const c_char,
+) -> glib::ffi::GType {
+    let instance = &*(builder_scope as *mut T::Instance);
+    let imp = instance.get_impl();
+    let builder: Borrowed<Builder> = from_glib_borrow(builderptr);
+
+    imp.get_type_from_function(
+        from_glib_borrow::<_, BuilderScope>(builder_scope).unsafe_cast_ref(),
+        &builder,
+        &GString::from_glib_borrow(function_nameptr),
+    )
+    .to_glib()
+}
+
+unsafe extern "C" fn builder_scope_create_closure<T: BuilderScopeImpl>(
+    builder_scope: *mut ffi::GtkBuilderScope,
+    builderptr: *mut ffi::GtkBuilder,
+    function_nameptr: *const c_char,
+    flags: ffi::GtkBuilderClosureFlags,
+    objectptr: *mut glib::gobject_ffi::GObject,
+    errorptr: *mut *mut glib::ffi::GError,
+) -> *mut glib::gobject_ffi::GClosure {
+    let instance = &*(builder_scope as *mut T::Instance);
+    let imp = instance.get_impl();
+    let builder: Borrowed<Builder> = from_glib_borrow(builderptr);
+    let object: Borrowed<Option<Object>> = from_glib_borrow(objectptr);
+
+    match imp.create_closure(
+        from_glib_borrow::<_, BuilderScope>(builder_scope).unsafe_cast_ref(),
+        &builder,
+        &GString::from_glib_borrow(function_nameptr),
+        from_glib(flags),
+        object.as_ref().as_ref(),
+    ) {
+        Ok(closure) => closure.to_glib_full(),
+        Err(err) => {
+            if !errorptr.is_null() {
+                *errorptr = err.to_glib_full() as *mut _;
+            }
+            ptr::null_mut()
+        }
+    }
+}
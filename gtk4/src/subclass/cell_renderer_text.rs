@@ -7,6 +7,14 @@ use glib::{Cast, GString};
 use super::cell_renderer::CellRendererImpl;
 use crate::{CellRenderer, CellRendererText};
 
+// rustdoc-stripper-ignore-next
+/// The drawing and sizing virtual methods, `snapshot` (GTK 3's `render`),
+/// `get_preferred_width`, `get_preferred_height` and `editing_started`, are
+/// inherited from [`CellRendererImpl`] and chain up to the text renderer's
+/// implementation through [`CellRendererImplExt`].
+///
+/// [`CellRendererImpl`]: ../cell_renderer/trait.CellRendererImpl.html
+/// [`CellRendererImplExt`]: ../cell_renderer/trait.CellRendererImplExt.html
 pub trait CellRendererTextImpl: CellRendererTextImplExt + CellRendererImpl {
     fn edited(&self, renderer: &Self::Type, path: &str, new_text: &str) {
         self.parent_edited(renderer, path, new_text);
@@ -1,12 +1,13 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::subclass::parent_iface;
 use crate::{ColorChooser, Orientation};
 use gdk::RGBA;
 use glib::subclass::prelude::*;
 use glib::translate::*;
 use glib::Cast;
 
-pub trait ColorChooserImpl: ObjectImpl {
+pub trait ColorChooserImpl: ColorChooserImplExt + ObjectImpl {
     fn add_palette(
         &self,
         color_chooser: &Self::Type,
@@ -14,11 +15,35 @@ pub trait ColorChooserImpl: ObjectImpl {
         colors_per_line: i32,
         colors: &[RGBA],
     );
-    fn color_activated(&self, color_chooser: &Self::Type, rgba: RGBA);
+    fn color_activated(&self, color_chooser: &Self::Type, rgba: RGBA) {
+        self.parent_color_activated(color_chooser, rgba)
+    }
     fn get_rgba(&self, color_chooser: &Self::Type) -> RGBA;
     fn set_rgba(&self, color_chooser: &Self::Type, rgba: RGBA);
 }
 
+pub trait ColorChooserImplExt: ObjectSubclass {
+    fn parent_color_activated(&self, color_chooser: &Self::Type, rgba: RGBA);
+}
+
+impl<T: ColorChooserImpl> ColorChooserImplExt for T {
+    fn parent_color_activated(&self, color_chooser: &Self::Type, rgba: RGBA) {
+        unsafe {
+            let parent_iface = parent_iface::<T, ColorChooser, ffi::GtkColorChooserInterface>();
+            // The "color-activated" signal has no default handler in GTK.
+            if let Some(f) = (*parent_iface).color_activated {
+                f(
+                    color_chooser
+                        .unsafe_cast_ref::<ColorChooser>()
+                        .to_glib_none()
+                        .0,
+                    rgba.to_glib_none().0,
+                );
+            }
+        }
+    }
+}
+
 unsafe impl<T: ColorChooserImpl> IsImplementable<T> for ColorChooser {
     unsafe extern "C" fn interface_init(
         iface: glib::ffi::gpointer,
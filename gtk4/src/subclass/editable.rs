@@ -1,22 +1,312 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::subclass::parent_iface;
 use crate::Editable;
 use glib::subclass::prelude::*;
 use glib::translate::*;
-use glib::{Cast, GString};
+use glib::{Cast, GString, ObjectExt};
 use libc::{c_char, c_int};
+use std::ffi::CStr;
+use std::{slice, str};
 
-pub trait EditableImpl: ObjectImpl {
-    fn insert_text(&self, editable: &Self::Type, text: &str, length: i32, position: &mut i32);
-    fn delete_text(&self, editable: &Self::Type, start_position: i32, end_position: i32);
-    fn changed(&self, editable: &Self::Type);
-    fn get_text(&self, editable: &Self::Type) -> GString;
-    fn do_insert_text(&self, editable: &Self::Type, text: &str, length: i32, position: &mut i32);
-    fn do_delete_text(&self, editable: &Self::Type, start_position: i32, end_position: i32);
-    fn get_selection_bounds(&self, editable: &Self::Type) -> Option<(i32, i32)>;
-    fn set_selection_bounds(&self, editable: &Self::Type, start_position: i32, end_position: i32);
+// rustdoc-stripper-ignore-next
+/// The virtual methods of `GtkEditable`.
+///
+/// Widgets wrapping a `Text` only need to implement [`get_delegate`], call
+/// [`EditableExt::init_delegate`] and [`EditableExt::finish_delegate`] and
+/// forward the properties installed with
+/// [`EditableClassSubclassExt::install_editable_properties`], with
+/// [`EditableImplExt::delegate_set_property`] and
+/// [`EditableImplExt::delegate_get_property`]. The other methods default to
+/// the implementation of the parent, which uses the delegate.
+///
+/// [`get_delegate`]: #method.get_delegate
+/// [`EditableExt::init_delegate`]: ../../prelude/trait.EditableExt.html#tymethod.init_delegate
+/// [`EditableExt::finish_delegate`]: ../../prelude/trait.EditableExt.html#tymethod.finish_delegate
+/// [`EditableClassSubclassExt::install_editable_properties`]: trait.EditableClassSubclassExt.html#method.install_editable_properties
+/// [`EditableImplExt::delegate_set_property`]: trait.EditableImplExt.html#tymethod.delegate_set_property
+/// [`EditableImplExt::delegate_get_property`]: trait.EditableImplExt.html#tymethod.delegate_get_property
+pub trait EditableImpl: EditableImplExt + ObjectImpl {
+    fn insert_text(&self, editable: &Self::Type, text: &str, length: i32, position: &mut i32) {
+        self.parent_insert_text(editable, text, length, position)
+    }
+
+    fn delete_text(&self, editable: &Self::Type, start_position: i32, end_position: i32) {
+        self.parent_delete_text(editable, start_position, end_position)
+    }
+
+    fn changed(&self, editable: &Self::Type) {
+        self.parent_changed(editable)
+    }
+
+    fn get_text(&self, editable: &Self::Type) -> GString {
+        self.parent_get_text(editable)
+    }
+
+    fn do_insert_text(&self, editable: &Self::Type, text: &str, length: i32, position: &mut i32) {
+        self.parent_do_insert_text(editable, text, length, position)
+    }
+
+    fn do_delete_text(&self, editable: &Self::Type, start_position: i32, end_position: i32) {
+        self.parent_do_delete_text(editable, start_position, end_position)
+    }
+
+    fn get_selection_bounds(&self, editable: &Self::Type) -> Option<(i32, i32)> {
+        self.parent_get_selection_bounds(editable)
+    }
+
+    fn set_selection_bounds(&self, editable: &Self::Type, start_position: i32, end_position: i32) {
+        self.parent_set_selection_bounds(editable, start_position, end_position)
+    }
+
+    fn get_delegate(&self, editable: &Self::Type) -> Option<Editable> {
+        self.parent_get_delegate(editable)
+    }
+}
+
+pub trait EditableImplExt: ObjectSubclass {
+    fn parent_insert_text(
+        &self,
+        editable: &Self::Type,
+        text: &str,
+        length: i32,
+        position: &mut i32,
+    );
+    fn parent_delete_text(&self, editable: &Self::Type, start_position: i32, end_position: i32);
+    fn parent_changed(&self, editable: &Self::Type);
+    fn parent_get_text(&self, editable: &Self::Type) -> GString;
+    fn parent_do_insert_text(
+        &self,
+        editable: &Self::Type,
+        text: &str,
+        length: i32,
+        position: &mut i32,
+    );
+    fn parent_do_delete_text(&self, editable: &Self::Type, start_position: i32, end_position: i32);
+    fn parent_get_selection_bounds(&self, editable: &Self::Type) -> Option<(i32, i32)>;
+    fn parent_set_selection_bounds(
+        &self,
+        editable: &Self::Type,
+        start_position: i32,
+        end_position: i32,
+    );
+    fn parent_get_delegate(&self, editable: &Self::Type) -> Option<Editable>;
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the property `id` of `ObjectImpl::set_property` on the delegate
+    /// if it is one of the properties installed with
+    /// `install_editable_properties`, returns `false` if it isn't.
+    #[doc(alias = "gtk_editable_delegate_set_property")]
+    fn delegate_set_property(&self, editable: &Self::Type, id: usize, value: &glib::Value) -> bool;
+
+    // rustdoc-stripper-ignore-next
+    /// Gets the property `id` of `ObjectImpl::get_property` from the delegate
+    /// if it is one of the properties installed with
+    /// `install_editable_properties`, returns `None` if it isn't.
+    #[doc(alias = "gtk_editable_delegate_get_property")]
+    fn delegate_get_property(&self, editable: &Self::Type, id: usize) -> Option<glib::Value>;
+}
+
+// The pspec installed for the property `id` of the subclass `T`.
+fn find_pspec<T: ObjectSubclass>(editable: &T::Type, id: usize) -> Option<glib::ParamSpec> {
+    let type_ = T::get_type().to_glib();
+    editable.list_properties().into_iter().find(|pspec| unsafe {
+        let ptr: *mut glib::gobject_ffi::GParamSpec = pspec.to_glib_none().0;
+        (*ptr).owner_type == type_ && (*ptr).param_id as usize == id + 1
+    })
 }
 
+impl<T: EditableImpl> EditableImplExt for T {
+    fn parent_insert_text(
+        &self,
+        editable: &Self::Type,
+        text: &str,
+        length: i32,
+        position: &mut i32,
+    ) {
+        unsafe {
+            let parent_iface = parent_iface::<T, Editable, ffi::GtkEditableInterface>();
+            let f = (*parent_iface)
+                .insert_text
+                .expect("No parent iface impl for \"insert_text\"");
+            f(
+                editable.unsafe_cast_ref::<Editable>().to_glib_none().0,
+                text.to_glib_none().0,
+                length,
+                position,
+            )
+        }
+    }
+
+    fn parent_delete_text(&self, editable: &Self::Type, start_position: i32, end_position: i32) {
+        unsafe {
+            let parent_iface = parent_iface::<T, Editable, ffi::GtkEditableInterface>();
+            let f = (*parent_iface)
+                .delete_text
+                .expect("No parent iface impl for \"delete_text\"");
+            f(
+                editable.unsafe_cast_ref::<Editable>().to_glib_none().0,
+                start_position,
+                end_position,
+            )
+        }
+    }
+
+    fn parent_changed(&self, editable: &Self::Type) {
+        unsafe {
+            let parent_iface = parent_iface::<T, Editable, ffi::GtkEditableInterface>();
+            if let Some(f) = (*parent_iface).changed {
+                f(editable.unsafe_cast_ref::<Editable>().to_glib_none().0)
+            }
+        }
+    }
+
+    fn parent_get_text(&self, editable: &Self::Type) -> GString {
+        unsafe {
+            let parent_iface = parent_iface::<T, Editable, ffi::GtkEditableInterface>();
+            let f = (*parent_iface)
+                .get_text
+                .expect("No parent iface impl for \"get_text\"");
+            from_glib_none(f(editable.unsafe_cast_ref::<Editable>().to_glib_none().0))
+        }
+    }
+
+    fn parent_do_insert_text(
+        &self,
+        editable: &Self::Type,
+        text: &str,
+        length: i32,
+        position: &mut i32,
+    ) {
+        unsafe {
+            let parent_iface = parent_iface::<T, Editable, ffi::GtkEditableInterface>();
+            let f = (*parent_iface)
+                .do_insert_text
+                .expect("No parent iface impl for \"do_insert_text\"");
+            f(
+                editable.unsafe_cast_ref::<Editable>().to_glib_none().0,
+                text.to_glib_none().0,
+                length,
+                position,
+            )
+        }
+    }
+
+    fn parent_do_delete_text(&self, editable: &Self::Type, start_position: i32, end_position: i32) {
+        unsafe {
+            let parent_iface = parent_iface::<T, Editable, ffi::GtkEditableInterface>();
+            let f = (*parent_iface)
+                .do_delete_text
+                .expect("No parent iface impl for \"do_delete_text\"");
+            f(
+                editable.unsafe_cast_ref::<Editable>().to_glib_none().0,
+                start_position,
+                end_position,
+            )
+        }
+    }
+
+    fn parent_get_selection_bounds(&self, editable: &Self::Type) -> Option<(i32, i32)> {
+        unsafe {
+            let parent_iface = parent_iface::<T, Editable, ffi::GtkEditableInterface>();
+            let f = (*parent_iface)
+                .get_selection_bounds
+                .expect("No parent iface impl for \"get_selection_bounds\"");
+            let mut start_position = 0;
+            let mut end_position = 0;
+            if from_glib(f(
+                editable.unsafe_cast_ref::<Editable>().to_glib_none().0,
+                &mut start_position,
+                &mut end_position,
+            )) {
+                Some((start_position, end_position))
+            } else {
+                None
+            }
+        }
+    }
+
+    fn parent_set_selection_bounds(
+        &self,
+        editable: &Self::Type,
+        start_position: i32,
+        end_position: i32,
+    ) {
+        unsafe {
+            let parent_iface = parent_iface::<T, Editable, ffi::GtkEditableInterface>();
+            let f = (*parent_iface)
+                .set_selection_bounds
+                .expect("No parent iface impl for \"set_selection_bounds\"");
+            f(
+                editable.unsafe_cast_ref::<Editable>().to_glib_none().0,
+                start_position,
+                end_position,
+            )
+        }
+    }
+
+    fn parent_get_delegate(&self, editable: &Self::Type) -> Option<Editable> {
+        unsafe {
+            let parent_iface = parent_iface::<T, Editable, ffi::GtkEditableInterface>();
+            match (*parent_iface).get_delegate {
+                Some(f) => {
+                    from_glib_none(f(editable.unsafe_cast_ref::<Editable>().to_glib_none().0))
+                }
+                None => None,
+            }
+        }
+    }
+
+    fn delegate_set_property(&self, editable: &Self::Type, id: usize, value: &glib::Value) -> bool {
+        let pspec = match find_pspec::<T>(editable, id) {
+            Some(pspec) => pspec,
+            None => return false,
+        };
+        unsafe {
+            from_glib(ffi::gtk_editable_delegate_set_property(
+                editable.upcast_ref::<glib::Object>().to_glib_none().0,
+                id as u32 + 1,
+                value.to_glib_none().0,
+                pspec.to_glib_none().0,
+            ))
+        }
+    }
+
+    fn delegate_get_property(&self, editable: &Self::Type, id: usize) -> Option<glib::Value> {
+        let pspec = find_pspec::<T>(editable, id)?;
+        unsafe {
+            let mut value = glib::Value::from_type(pspec.get_value_type());
+            if from_glib(ffi::gtk_editable_delegate_get_property(
+                editable.upcast_ref::<glib::Object>().to_glib_none().0,
+                id as u32 + 1,
+                value.to_glib_none_mut().0,
+                pspec.to_glib_none().0,
+            )) {
+                Some(value)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+pub unsafe trait EditableClassSubclassExt: ClassStruct {
+    // rustdoc-stripper-ignore-next
+    /// Installs the properties of `GtkEditable` from `first_prop`, to be
+    /// called in `class_init` after installing the properties of the type.
+    /// `first_prop` is the number of these properties plus one, and the
+    /// number of installed properties is returned.
+    #[doc(alias = "gtk_editable_install_properties")]
+    fn install_editable_properties(&mut self, first_prop: u32) -> u32 {
+        unsafe {
+            let object_class = self as *mut _ as *mut glib::gobject_ffi::GObjectClass;
+            ffi::gtk_editable_install_properties(object_class, first_prop)
+        }
+    }
+}
+
+unsafe impl<T: ClassStruct> EditableClassSubclassExt for T where T::Type: EditableImpl {}
+
 unsafe impl<T: EditableImpl> IsImplementable<T> for Editable {
     unsafe extern "C" fn interface_init(
         iface: glib::ffi::gpointer,
@@ -32,9 +322,20 @@ unsafe impl<T: EditableImpl> IsImplementable<T> for Editable {
         editable_iface.do_delete_text = Some(editable_do_delete_text::<T>);
         editable_iface.get_selection_bounds = Some(editable_get_selection_bounds::<T>);
         editable_iface.set_selection_bounds = Some(editable_set_selection_bounds::<T>);
+        editable_iface.get_delegate = Some(editable_get_delegate::<T>);
     }
 }
 
+// The text isn't necessarily nul-terminated after `length` bytes.
+unsafe fn text_from_glib<'a>(text: *const c_char, length: c_int) -> &'a str {
+    let bytes = if length < 0 {
+        CStr::from_ptr(text).to_bytes()
+    } else {
+        slice::from_raw_parts(text as *const u8, length as usize)
+    };
+    str::from_utf8(bytes).unwrap()
+}
+
 unsafe extern "C" fn editable_insert_text<T: EditableImpl>(
     editable: *mut ffi::GtkEditable,
     text: *const c_char,
@@ -46,7 +347,7 @@ unsafe extern "C" fn editable_insert_text<T: EditableImpl>(
 
     imp.insert_text(
         from_glib_borrow::<_, Editable>(editable).unsafe_cast_ref(),
-        &GString::from_glib_borrow(text),
+        text_from_glib(text, length),
         length,
         &mut *position,
     )
@@ -80,8 +381,17 @@ unsafe extern "C" fn editable_get_text<T: EditableImpl>(
     let instance = &*(editable as *mut T::Instance);
     let imp = instance.get_impl();
 
-    imp.get_text(from_glib_borrow::<_, Editable>(editable).unsafe_cast_ref())
-        .to_glib_full()
+    let text: *mut c_char = imp
+        .get_text(from_glib_borrow::<_, Editable>(editable).unsafe_cast_ref())
+        .to_glib_full();
+    // The text is transfer none, keep it alive until the next call.
+    glib::gobject_ffi::g_object_set_qdata_full(
+        editable as *mut glib::gobject_ffi::GObject,
+        glib::Quark::from_string("gtk-rs-editable-text").to_glib(),
+        text as glib::ffi::gpointer,
+        Some(glib::ffi::g_free),
+    );
+    text
 }
 
 unsafe extern "C" fn editable_do_insert_text<T: EditableImpl>(
@@ -95,7 +405,7 @@ unsafe extern "C" fn editable_do_insert_text<T: EditableImpl>(
 
     imp.do_insert_text(
         from_glib_borrow::<_, Editable>(editable).unsafe_cast_ref(),
-        &GString::from_glib_borrow(text),
+        text_from_glib(text, length),
         length,
         &mut *position,
     )
@@ -124,22 +434,21 @@ unsafe extern "C" fn editable_get_selection_bounds<T: EditableImpl>(
     let instance = &*(editable as *mut T::Instance);
     let imp = instance.get_impl();
 
-    if let Some((start_pos, end_pos)) =
-        imp.get_selection_bounds(from_glib_borrow::<_, Editable>(editable).unsafe_cast_ref())
+    let (start_pos, end_pos, ret) = match imp
+        .get_selection_bounds(from_glib_borrow::<_, Editable>(editable).unsafe_cast_ref())
     {
-        if !start_position.is_null() {
-            *start_position = start_pos;
-        }
+        Some((start_pos, end_pos)) => (start_pos, end_pos, true),
+        None => (0, 0, false),
+    };
 
-        if !end_position.is_null() {
-            *end_position = end_pos;
-        }
-        true.to_glib()
-    } else {
-        *start_position = 0;
-        *end_position = 0;
-        false.to_glib()
+    if !start_position.is_null() {
+        *start_position = start_pos;
+    }
+
+    if !end_position.is_null() {
+        *end_position = end_pos;
     }
+    ret.to_glib()
 }
 
 unsafe extern "C" fn editable_set_selection_bounds<T: EditableImpl>(
@@ -156,3 +465,15 @@ unsafe extern "C" fn editable_set_selection_bounds<T: EditableImpl>(
         end_position,
     )
 }
+
+unsafe extern "C" fn editable_get_delegate<T: EditableImpl>(
+    editable: *mut ffi::GtkEditable,
+) -> *mut ffi::GtkEditable {
+    let instance = &*(editable as *mut T::Instance);
+    let imp = instance.get_impl();
+
+    // Transfer none, the delegate is owned by the editable.
+    imp.get_delegate(from_glib_borrow::<_, Editable>(editable).unsafe_cast_ref())
+        .to_glib_none()
+        .0
+}
@@ -0,0 +1,24 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use glib::subclass::prelude::*;
+use glib::Object;
+
+use crate::LayoutChild;
+
+// rustdoc-stripper-ignore-next
+/// `GtkLayoutChildClass` has no virtual functions. Layout children keep
+/// the per child properties of their layout manager, whose
+/// [`LayoutManagerImpl::layout_child_type`] is the type of the subclass.
+///
+/// Like `GtkGridLayoutChild`, setters of the properties affecting the
+/// layout invalidate it with `LayoutManagerExt::layout_changed` on
+/// `LayoutChildExt::get_layout_manager`.
+///
+/// [`LayoutManagerImpl::layout_child_type`]: ../layout_manager/trait.LayoutManagerImpl.html#tymethod.layout_child_type
+pub trait LayoutChildImpl: ObjectImpl {}
+
+unsafe impl<T: LayoutChildImpl> IsSubclassable<T> for LayoutChild {
+    fn override_vfuncs(class: &mut glib::Class<Self>) {
+        <Object as IsSubclassable<T>>::override_vfuncs(class);
+    }
+}
@@ -20,16 +20,33 @@ pub trait LayoutManagerImpl: LayoutManagerImplExt + ObjectImpl {
         self.parent_allocate(layout_manager, widget, width, height, baseline)
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Creates the `LayoutChild` holding the properties of `for_child`.
+    ///
+    /// The default creates one of [`layout_child_type`], or returns `None`
+    /// if the layout manager has no child properties.
+    ///
+    /// [`layout_child_type`]: #method.layout_child_type
     fn create_layout_child(
         &self,
         layout_manager: &Self::Type,
         widget: &Widget,
         for_child: &Widget,
-    ) -> LayoutChild {
+    ) -> Option<LayoutChild> {
         self.parent_create_layout_child(layout_manager, widget, for_child)
     }
 
-    fn layout_child_type() -> glib::Type;
+    // rustdoc-stripper-ignore-next
+    /// The type of the `LayoutChild`s holding the per child properties, which
+    /// the default [`create_layout_child`] creates.
+    ///
+    /// The default is `glib::Type::Invalid`, for layout managers without
+    /// child properties.
+    ///
+    /// [`create_layout_child`]: #method.create_layout_child
+    fn layout_child_type() -> glib::Type {
+        glib::Type::Invalid
+    }
 
     fn get_request_mode(&self, layout_manager: &Self::Type, widget: &Widget) -> SizeRequestMode {
         self.parent_get_request_mode(layout_manager, widget)
@@ -82,7 +99,7 @@ pub trait LayoutManagerImplExt: ObjectSubclass {
         layout_manager: &Self::Type,
         widget: &Widget,
         for_child: &Widget,
-    ) -> LayoutChild;
+    ) -> Option<LayoutChild>;
 
     fn parent_get_request_mode(
         &self,
@@ -139,14 +156,14 @@ impl<T: LayoutManagerImpl> LayoutManagerImplExt for T {
         layout_manager: &Self::Type,
         widget: &Widget,
         for_child: &Widget,
-    ) -> LayoutChild {
+    ) -> Option<LayoutChild> {
         unsafe {
             let data = T::type_data();
             let parent_class = data.as_ref().get_parent_class() as *mut ffi::GtkLayoutManagerClass;
             let f = (*parent_class)
                 .create_layout_child
                 .expect("No parent class impl for \"create_layout_child\"");
-            from_glib_none(f(
+            from_glib_full(f(
                 layout_manager
                     .unsafe_cast_ref::<LayoutManager>()
                     .to_glib_none()
@@ -0,0 +1,61 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use super::signal_list_item_factory::SignalListItemFactoryImpl;
+use crate::{ListItem, ListItemFactory, SignalListItemFactory};
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use glib::{Cast, Object};
+
+// rustdoc-stripper-ignore-next
+/// The class structure of `GtkListItemFactory` is private, none of its
+/// virtual methods can be overridden. Subclass [`SignalListItemFactory`]
+/// through [`SignalListItemFactoryImpl`] to provide the `setup`, `bind`,
+/// `unbind` and `teardown` steps.
+///
+/// [`SignalListItemFactory`]: ../../struct.SignalListItemFactory.html
+/// [`SignalListItemFactoryImpl`]: ../signal_list_item_factory/trait.SignalListItemFactoryImpl.html
+pub trait ListItemFactoryImpl: ObjectImpl {}
+
+// rustdoc-stripper-ignore-next
+/// The `parent_*` methods chain up to the class handler of the parent type
+/// and must only be called from the matching method of
+/// [`SignalListItemFactoryImpl`], while the signal is being emitted.
+///
+/// [`SignalListItemFactoryImpl`]: ../signal_list_item_factory/trait.SignalListItemFactoryImpl.html
+pub trait ListItemFactoryImplExt: ObjectSubclass {
+    fn parent_setup(&self, factory: &Self::Type, list_item: &ListItem);
+    fn parent_bind(&self, factory: &Self::Type, list_item: &ListItem);
+    fn parent_unbind(&self, factory: &Self::Type, list_item: &ListItem);
+    fn parent_teardown(&self, factory: &Self::Type, list_item: &ListItem);
+}
+
+impl<T: SignalListItemFactoryImpl> ListItemFactoryImplExt for T {
+    fn parent_setup(&self, factory: &Self::Type, list_item: &ListItem) {
+        unsafe { chain_from_overridden(factory.unsafe_cast_ref(), list_item) }
+    }
+
+    fn parent_bind(&self, factory: &Self::Type, list_item: &ListItem) {
+        unsafe { chain_from_overridden(factory.unsafe_cast_ref(), list_item) }
+    }
+
+    fn parent_unbind(&self, factory: &Self::Type, list_item: &ListItem) {
+        unsafe { chain_from_overridden(factory.unsafe_cast_ref(), list_item) }
+    }
+
+    fn parent_teardown(&self, factory: &Self::Type, list_item: &ListItem) {
+        unsafe { chain_from_overridden(factory.unsafe_cast_ref(), list_item) }
+    }
+}
+
+unsafe fn chain_from_overridden(factory: &SignalListItemFactory, list_item: &ListItem) {
+    glib::gobject_ffi::g_signal_chain_from_overridden_handler(
+        factory.to_glib_none().0 as glib::ffi::gpointer,
+        list_item.to_glib_none().0,
+    );
+}
+
+unsafe impl<T: ListItemFactoryImpl> IsSubclassable<T> for ListItemFactory {
+    fn override_vfuncs(class: &mut glib::Class<Self>) {
+        <Object as IsSubclassable<T>>::override_vfuncs(class);
+    }
+}
@@ -15,7 +15,7 @@ pub trait MediaStreamImpl: MediaStreamImplExt + ObjectImpl {
         self.parent_play(media_stream)
     }
 
-    fn realize(&self, media_stream: &Self::Type, surface: gdk::Surface) {
+    fn realize(&self, media_stream: &Self::Type, surface: &gdk::Surface) {
         self.parent_realize(media_stream, surface)
     }
 
@@ -23,7 +23,7 @@ pub trait MediaStreamImpl: MediaStreamImplExt + ObjectImpl {
         self.parent_seek(media_stream, timestamp)
     }
 
-    fn unrealize(&self, media_stream: &Self::Type, surface: gdk::Surface) {
+    fn unrealize(&self, media_stream: &Self::Type, surface: &gdk::Surface) {
         self.parent_unrealize(media_stream, surface)
     }
 
@@ -35,9 +35,9 @@ pub trait MediaStreamImpl: MediaStreamImplExt + ObjectImpl {
 pub trait MediaStreamImplExt: ObjectSubclass {
     fn parent_pause(&self, media_stream: &Self::Type);
     fn parent_play(&self, media_stream: &Self::Type) -> bool;
-    fn parent_realize(&self, media_stream: &Self::Type, surface: gdk::Surface);
+    fn parent_realize(&self, media_stream: &Self::Type, surface: &gdk::Surface);
     fn parent_seek(&self, media_stream: &Self::Type, timestamp: i64);
-    fn parent_unrealize(&self, media_stream: &Self::Type, surface: gdk::Surface);
+    fn parent_unrealize(&self, media_stream: &Self::Type, surface: &gdk::Surface);
     fn parent_update_audio(&self, media_stream: &Self::Type, muted: bool, volume: f64);
 }
 
@@ -70,7 +70,7 @@ impl<T: MediaStreamImpl> MediaStreamImplExt for T {
         }
     }
 
-    fn parent_realize(&self, media_stream: &Self::Type, surface: gdk::Surface) {
+    fn parent_realize(&self, media_stream: &Self::Type, surface: &gdk::Surface) {
         unsafe {
             let data = T::type_data();
             let parent_class = data.as_ref().get_parent_class() as *mut ffi::GtkMediaStreamClass;
@@ -104,7 +104,7 @@ impl<T: MediaStreamImpl> MediaStreamImplExt for T {
         }
     }
 
-    fn parent_unrealize(&self, media_stream: &Self::Type, surface: gdk::Surface) {
+    fn parent_unrealize(&self, media_stream: &Self::Type, surface: &gdk::Surface) {
         unsafe {
             let data = T::type_data();
             let parent_class = data.as_ref().get_parent_class() as *mut ffi::GtkMediaStreamClass;
@@ -179,8 +179,9 @@ unsafe extern "C" fn media_stream_realize<T: MediaStreamImpl>(
     let instance = &*(ptr as *mut T::Instance);
     let imp = instance.get_impl();
     let wrap: Borrowed<MediaStream> = from_glib_borrow(ptr);
+    let surface: Borrowed<gdk::Surface> = from_glib_borrow(surface);
 
-    imp.realize(wrap.unsafe_cast_ref(), from_glib_none(surface))
+    imp.realize(wrap.unsafe_cast_ref(), &surface)
 }
 
 unsafe extern "C" fn media_stream_seek<T: MediaStreamImpl>(
@@ -201,8 +202,9 @@ unsafe extern "C" fn media_stream_unrealize<T: MediaStreamImpl>(
     let instance = &*(ptr as *mut T::Instance);
     let imp = instance.get_impl();
     let wrap: Borrowed<MediaStream> = from_glib_borrow(ptr);
+    let surface: Borrowed<gdk::Surface> = from_glib_borrow(surface);
 
-    imp.unrealize(wrap.unsafe_cast_ref(), from_glib_none(surface))
+    imp.unrealize(wrap.unsafe_cast_ref(), &surface)
 }
 
 unsafe extern "C" fn media_stream_update_audio<T: MediaStreamImpl>(
@@ -1,5 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use glib::subclass::types::ObjectSubclass;
+use glib::translate::*;
+use glib::StaticType;
+
 pub mod accessible;
 pub mod actionable;
 pub mod adjustment;
@@ -7,6 +11,7 @@ pub mod app_chooser;
 pub mod application;
 pub mod application_window;
 pub mod box_;
+pub mod builder_scope;
 pub mod button;
 pub mod cell_renderer;
 pub mod cell_renderer_text;
@@ -24,8 +29,10 @@ pub mod filter;
 pub mod flow_box_child;
 pub mod frame;
 pub mod gl_area;
+pub mod layout_child;
 pub mod layout_manager;
 pub mod list_box_row;
+pub mod list_item_factory;
 pub mod media_file;
 pub mod media_stream;
 pub mod native;
@@ -42,6 +49,7 @@ pub mod scale_button;
 pub mod scrollable;
 pub mod selection_model;
 pub mod shortcut_manager;
+pub mod signal_list_item_factory;
 pub mod sorter;
 pub mod style_context;
 pub mod text_buffer;
@@ -53,6 +61,22 @@ pub mod tree_view;
 pub mod widget;
 pub mod window;
 
+// The interface of the parent type if it implements it, or else the default
+// implementation from GTK.
+pub(crate) unsafe fn parent_iface<T: ObjectSubclass, I: StaticType, F>() -> *const F {
+    let iface_type = I::static_type().to_glib();
+    let data = T::type_data();
+    let parent_iface = glib::gobject_ffi::g_type_interface_peek(
+        data.as_ref().get_parent_class() as glib::ffi::gpointer,
+        iface_type,
+    );
+    if parent_iface.is_null() {
+        glib::gobject_ffi::g_type_default_interface_peek(iface_type) as *const F
+    } else {
+        parent_iface as *const F
+    }
+}
+
 pub mod prelude {
     #[doc(hidden)]
     pub use gdk::subclass::prelude::*;
@@ -65,55 +89,64 @@ pub mod prelude {
     pub use super::actionable::ActionableImpl;
     pub use super::adjustment::AdjustmentImpl;
     pub use super::app_chooser::AppChooserImpl;
-    pub use super::application::GtkApplicationImpl;
+    pub use super::application::{GtkApplicationImpl, GtkApplicationImplExt};
     pub use super::application_window::ApplicationWindowImpl;
     pub use super::box_::BoxImpl;
+    pub use super::builder_scope::{BuilderScopeImpl, BuilderScopeImplExt};
     pub use super::button::ButtonImpl;
-    pub use super::cell_renderer::CellRendererImpl;
-    pub use super::cell_renderer_text::CellRendererTextImpl;
+    pub use super::cell_renderer::{CellRendererImpl, CellRendererImplExt};
+    pub use super::cell_renderer_text::{CellRendererTextImpl, CellRendererTextImplExt};
     pub use super::check_button::CheckButtonImpl;
-    pub use super::color_chooser::ColorChooserImpl;
+    pub use super::color_chooser::{ColorChooserImpl, ColorChooserImplExt};
     pub use super::combo_box::ComboBoxImpl;
     pub use super::constraint_target::ConstraintTargetImpl;
     pub use super::dialog::DialogImpl;
-    pub use super::drawing_area::DrawingAreaImpl;
-    pub use super::editable::EditableImpl;
+    pub use super::drawing_area::{DrawingAreaImpl, DrawingAreaImplExt};
+    pub use super::editable::{EditableClassSubclassExt, EditableImpl, EditableImplExt};
     pub use super::entry::EntryImpl;
     pub use super::entry_buffer::EntryBufferImpl;
     pub use super::file_chooser::FileChooserImpl;
-    pub use super::filter::FilterImpl;
+    pub use super::filter::{FilterImpl, FilterImplExt};
     pub use super::flow_box_child::FlowBoxChildImpl;
     pub use super::frame::FrameImpl;
     pub use super::gl_area::GLAreaImpl;
-    pub use super::layout_manager::LayoutManagerImpl;
+    pub use super::layout_child::LayoutChildImpl;
+    pub use super::layout_manager::{LayoutManagerImpl, LayoutManagerImplExt};
     pub use super::list_box_row::ListBoxRowImpl;
-    pub use super::media_file::MediaFileImpl;
-    pub use super::media_stream::MediaStreamImpl;
+    pub use super::list_item_factory::{ListItemFactoryImpl, ListItemFactoryImplExt};
+    pub use super::media_file::{MediaFileImpl, MediaFileImplExt};
+    pub use super::media_stream::{MediaStreamImpl, MediaStreamImplExt};
     pub use super::native::NativeImpl;
     pub use super::native_dialog::NativeDialogImpl;
     pub use super::orientable::OrientableImpl;
     pub use super::popover::PopoverImpl;
     pub use super::print_operation::PrintOperationImpl;
-    pub use super::print_operation_preview::PrintOperationPreviewImpl;
+    pub use super::print_operation_preview::{
+        PrintOperationPreviewImpl, PrintOperationPreviewImplExt,
+    };
     pub use super::range::RangeImpl;
     pub use super::recent_manager::RecentManagerImpl;
     pub use super::root::RootImpl;
     pub use super::scale::ScaleImpl;
     pub use super::scale_button::ScaleButtonImpl;
-    pub use super::scrollable::ScrollableImpl;
+    pub use super::scrollable::{ScrollableClassSubclassExt, ScrollableImpl};
     pub use super::selection_model::SelectionModelImpl;
-    pub use super::shortcut_manager::ShortcutManagerImpl;
-    pub use super::sorter::SorterImpl;
+    pub use super::shortcut_manager::{ShortcutManagerImpl, ShortcutManagerImplExt};
+    pub use super::signal_list_item_factory::SignalListItemFactoryImpl;
+    pub use super::sorter::{SorterImpl, SorterImplExt};
     pub use super::style_context::StyleContextImpl;
-    pub use super::text_buffer::TextBufferImpl;
-    pub use super::text_view::TextViewImpl;
+    pub use super::text_buffer::{TextBufferImpl, TextBufferImplExt};
+    pub use super::text_view::{TextViewImpl, TextViewImplExt};
     pub use super::toggle_button::ToggleButtonImpl;
     pub use super::tree_drag_dest::TreeDragDestImpl;
     pub use super::tree_drag_source::TreeDragSourceImpl;
     pub use super::tree_view::TreeViewImpl;
     pub use super::widget::CompositeTemplate;
+    pub use super::widget::CompositeTemplateCallbacks;
+    pub use super::widget::MeasureResult;
     pub use super::widget::TemplateChild;
     pub use super::widget::WidgetClassSubclassExt;
     pub use super::widget::WidgetImpl;
+    pub use super::widget::WidgetImplExt;
     pub use super::window::WindowImpl;
 }
@@ -4,6 +4,11 @@ use crate::subclass::prelude::WidgetImpl;
 use crate::Native;
 use glib::subclass::prelude::*;
 
+// rustdoc-stripper-ignore-next
+/// `GtkNativeInterface` is private to GTK, its virtual methods can't be
+/// overridden. Implementations have to derive from a type already
+/// implementing `gtk::Native`, like `gtk::Window` or `gtk::Popover`, which
+/// keeps the implementation of its parent.
 pub trait NativeImpl: WidgetImpl {}
 
 unsafe impl<T: NativeImpl> IsImplementable<T> for Native {
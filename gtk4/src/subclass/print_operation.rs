@@ -322,6 +322,8 @@ unsafe impl<T: PrintOperationImpl> IsSubclassable<T> for PrintOperation {
         klass.done = Some(print_operation_done::<T>);
         klass.draw_page = Some(print_operation_draw_page::<T>);
         klass.end_print = Some(print_operation_end_print::<T>);
+        klass.paginate = Some(print_operation_paginate::<T>);
+        klass.preview = Some(print_operation_preview::<T>);
         klass.request_page_setup = Some(print_operation_request_page_setup::<T>);
         klass.status_changed = Some(print_operation_status_changed::<T>);
         klass.update_custom_widget = Some(print_operation_update_custom_widget::<T>);
@@ -399,6 +401,40 @@ unsafe extern "C" fn print_operation_end_print<T: PrintOperationImpl>(
     imp.end_print(wrap.unsafe_cast_ref(), &context)
 }
 
+unsafe extern "C" fn print_operation_paginate<T: PrintOperationImpl>(
+    ptr: *mut ffi::GtkPrintOperation,
+    contextptr: *mut ffi::GtkPrintContext,
+) -> glib::ffi::gboolean {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<PrintOperation> = from_glib_borrow(ptr);
+    let context: Borrowed<PrintContext> = from_glib_borrow(contextptr);
+
+    imp.paginate(wrap.unsafe_cast_ref(), &context).to_glib()
+}
+
+unsafe extern "C" fn print_operation_preview<T: PrintOperationImpl>(
+    ptr: *mut ffi::GtkPrintOperation,
+    previewptr: *mut ffi::GtkPrintOperationPreview,
+    contextptr: *mut ffi::GtkPrintContext,
+    parentptr: *mut ffi::GtkWindow,
+) -> glib::ffi::gboolean {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<PrintOperation> = from_glib_borrow(ptr);
+    let preview: Borrowed<PrintOperationPreview> = from_glib_borrow(previewptr);
+    let context: Borrowed<PrintContext> = from_glib_borrow(contextptr);
+    let parent: Borrowed<Option<Window>> = from_glib_borrow(parentptr);
+
+    imp.preview(
+        wrap.unsafe_cast_ref(),
+        &preview,
+        &context,
+        parent.as_ref().as_ref(),
+    )
+    .to_glib()
+}
+
 unsafe extern "C" fn print_operation_request_page_setup<T: PrintOperationImpl>(
     ptr: *mut ffi::GtkPrintOperation,
     contextptr: *mut ffi::GtkPrintContext,
@@ -1,28 +1,14 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::subclass::parent_iface;
 use crate::{PageSetup, PrintContext, PrintOperationPreview};
 use glib::subclass::prelude::*;
 use glib::translate::*;
 use glib::Cast;
 
-pub trait PrintOperationPreviewImpl: ObjectImpl {
+pub trait PrintOperationPreviewImpl: PrintOperationPreviewImplExt + ObjectImpl {
     fn ready(&self, print_operation_preview: &Self::Type, context: &PrintContext) {
-        unsafe {
-            let type_ = ffi::gtk_print_operation_preview_get_type();
-            let iface = glib::gobject_ffi::g_type_default_interface_ref(type_)
-                as *mut ffi::GtkPrintOperationPreviewIface;
-            assert!(!iface.is_null());
-
-            ((*iface).ready.as_ref().unwrap())(
-                print_operation_preview
-                    .unsafe_cast_ref::<PrintOperationPreview>()
-                    .to_glib_none()
-                    .0,
-                context.to_glib_none().0,
-            );
-
-            glib::gobject_ffi::g_type_default_interface_unref(iface as glib::ffi::gpointer);
-        }
+        self.parent_ready(print_operation_preview, context)
     }
 
     fn got_page_size(
@@ -31,23 +17,7 @@ pub trait PrintOperationPreviewImpl: ObjectImpl {
         context: &PrintContext,
         page_setup: &PageSetup,
     ) {
-        unsafe {
-            let type_ = ffi::gtk_print_operation_preview_get_type();
-            let iface = glib::gobject_ffi::g_type_default_interface_ref(type_)
-                as *mut ffi::GtkPrintOperationPreviewIface;
-            assert!(!iface.is_null());
-
-            ((*iface).got_page_size.as_ref().unwrap())(
-                print_operation_preview
-                    .unsafe_cast_ref::<PrintOperationPreview>()
-                    .to_glib_none()
-                    .0,
-                context.to_glib_none().0,
-                page_setup.to_glib_none().0,
-            );
-
-            glib::gobject_ffi::g_type_default_interface_unref(iface as glib::ffi::gpointer);
-        }
+        self.parent_got_page_size(print_operation_preview, context, page_setup)
     }
 
     fn render_page(&self, print_operation_preview: &Self::Type, page_nr: i32);
@@ -55,6 +25,58 @@ pub trait PrintOperationPreviewImpl: ObjectImpl {
     fn end_preview(&self, print_operation_preview: &Self::Type);
 }
 
+pub trait PrintOperationPreviewImplExt: ObjectSubclass {
+    fn parent_ready(&self, print_operation_preview: &Self::Type, context: &PrintContext);
+    fn parent_got_page_size(
+        &self,
+        print_operation_preview: &Self::Type,
+        context: &PrintContext,
+        page_setup: &PageSetup,
+    );
+}
+
+impl<T: PrintOperationPreviewImpl> PrintOperationPreviewImplExt for T {
+    fn parent_ready(&self, print_operation_preview: &Self::Type, context: &PrintContext) {
+        unsafe {
+            let parent_iface =
+                parent_iface::<T, PrintOperationPreview, ffi::GtkPrintOperationPreviewIface>();
+            // Signal handlers are optional, there is nothing to chain up to
+            // without one.
+            if let Some(f) = (*parent_iface).ready {
+                f(
+                    print_operation_preview
+                        .unsafe_cast_ref::<PrintOperationPreview>()
+                        .to_glib_none()
+                        .0,
+                    context.to_glib_none().0,
+                );
+            }
+        }
+    }
+
+    fn parent_got_page_size(
+        &self,
+        print_operation_preview: &Self::Type,
+        context: &PrintContext,
+        page_setup: &PageSetup,
+    ) {
+        unsafe {
+            let parent_iface =
+                parent_iface::<T, PrintOperationPreview, ffi::GtkPrintOperationPreviewIface>();
+            if let Some(f) = (*parent_iface).got_page_size {
+                f(
+                    print_operation_preview
+                        .unsafe_cast_ref::<PrintOperationPreview>()
+                        .to_glib_none()
+                        .0,
+                    context.to_glib_none().0,
+                    page_setup.to_glib_none().0,
+                );
+            }
+        }
+    }
+}
+
 unsafe impl<T: PrintOperationPreviewImpl> IsImplementable<T> for PrintOperationPreview {
     unsafe extern "C" fn interface_init(
         iface: glib::ffi::gpointer,
@@ -5,10 +5,59 @@ use glib::subclass::prelude::*;
 use glib::translate::*;
 use glib::Cast;
 
+// rustdoc-stripper-ignore-next
+/// The virtual methods of `GtkScrollable`.
+///
+/// Scrollable widgets also have to implement the `hadjustment`,
+/// `vadjustment`, `hscroll-policy` and `vscroll-policy` properties of the
+/// interface, installed with
+/// [`ScrollableClassSubclassExt::override_scrollable_properties`]. They
+/// configure the adjustments they are given in `WidgetImpl::size_allocate`,
+/// and draw their contents shifted by their values.
+///
+/// [`ScrollableClassSubclassExt::override_scrollable_properties`]: trait.ScrollableClassSubclassExt.html#method.override_scrollable_properties
 pub trait ScrollableImpl: ObjectImpl {
-    fn get_border(&self, scrollable: &Self::Type) -> Option<Border>;
+    // rustdoc-stripper-ignore-next
+    /// Returns the size of the contents that don't scroll, like the headers
+    /// of a `TreeView`, so that `ScrolledWindow` can place its undershoot
+    /// indicators inside. The default is `None`, for no such border.
+    fn get_border(&self, _scrollable: &Self::Type) -> Option<Border> {
+        None
+    }
 }
 
+pub unsafe trait ScrollableClassSubclassExt: ClassStruct {
+    // rustdoc-stripper-ignore-next
+    /// Overrides the properties of `GtkScrollable` from `first_prop`, to be
+    /// called in `class_init` after installing the properties of the type.
+    /// `first_prop` is the number of these properties plus one.
+    ///
+    /// The properties are `hadjustment`, `vadjustment`, `hscroll-policy`
+    /// and `vscroll-policy`, in this order, and their number is returned.
+    #[doc(alias = "g_object_class_override_property")]
+    fn override_scrollable_properties(&mut self, first_prop: u32) -> u32 {
+        let names = [
+            "hadjustment",
+            "vadjustment",
+            "hscroll-policy",
+            "vscroll-policy",
+        ];
+        unsafe {
+            let object_class = self as *mut _ as *mut glib::gobject_ffi::GObjectClass;
+            for (i, name) in names.iter().enumerate() {
+                glib::gobject_ffi::g_object_class_override_property(
+                    object_class,
+                    first_prop + i as u32,
+                    name.to_glib_none().0,
+                );
+            }
+        }
+        names.len() as u32
+    }
+}
+
+unsafe impl<T: ClassStruct> ScrollableClassSubclassExt for T where T::Type: ScrollableImpl {}
+
 unsafe impl<T: ScrollableImpl> IsImplementable<T> for Scrollable {
     unsafe extern "C" fn interface_init(
         iface: glib::ffi::gpointer,
@@ -30,10 +79,9 @@ unsafe extern "C" fn scrollable_get_border<T: ScrollableImpl>(
     if let Some(border) =
         imp.get_border(from_glib_borrow::<_, Scrollable>(scrollable).unsafe_cast_ref())
     {
-        *borderptr = *border.to_glib_full();
+        *borderptr = *border.to_glib_none().0;
         true.to_glib()
     } else {
-        *borderptr = *std::ptr::null();
         false.to_glib()
     }
 }
@@ -1,13 +1,72 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::subclass::parent_iface;
 use crate::{ShortcutController, ShortcutManager};
 use glib::subclass::prelude::*;
 use glib::translate::*;
 use glib::Cast;
 
-pub trait ShortcutManagerImpl: ObjectImpl {
-    fn add_controller(&self, shortcut_manager: &Self::Type, controller: &ShortcutController);
-    fn remove_controller(&self, shortcut_manager: &Self::Type, controller: &ShortcutController);
+pub trait ShortcutManagerImpl: ShortcutManagerImplExt + ObjectImpl {
+    fn add_controller(&self, shortcut_manager: &Self::Type, controller: &ShortcutController) {
+        self.parent_add_controller(shortcut_manager, controller)
+    }
+
+    fn remove_controller(&self, shortcut_manager: &Self::Type, controller: &ShortcutController) {
+        self.parent_remove_controller(shortcut_manager, controller)
+    }
+}
+
+pub trait ShortcutManagerImplExt: ObjectSubclass {
+    fn parent_add_controller(&self, shortcut_manager: &Self::Type, controller: &ShortcutController);
+    fn parent_remove_controller(
+        &self,
+        shortcut_manager: &Self::Type,
+        controller: &ShortcutController,
+    );
+}
+
+impl<T: ShortcutManagerImpl> ShortcutManagerImplExt for T {
+    fn parent_add_controller(
+        &self,
+        shortcut_manager: &Self::Type,
+        controller: &ShortcutController,
+    ) {
+        unsafe {
+            let parent_iface =
+                parent_iface::<T, ShortcutManager, ffi::GtkShortcutManagerInterface>();
+            let f = (*parent_iface)
+                .add_controller
+                .expect("No parent iface impl for \"add_controller\"");
+            f(
+                shortcut_manager
+                    .unsafe_cast_ref::<ShortcutManager>()
+                    .to_glib_none()
+                    .0,
+                controller.to_glib_none().0,
+            )
+        }
+    }
+
+    fn parent_remove_controller(
+        &self,
+        shortcut_manager: &Self::Type,
+        controller: &ShortcutController,
+    ) {
+        unsafe {
+            let parent_iface =
+                parent_iface::<T, ShortcutManager, ffi::GtkShortcutManagerInterface>();
+            let f = (*parent_iface)
+                .remove_controller
+                .expect("No parent iface impl for \"remove_controller\"");
+            f(
+                shortcut_manager
+                    .unsafe_cast_ref::<ShortcutManager>()
+                    .to_glib_none()
+                    .0,
+                controller.to_glib_none().0,
+            )
+        }
+    }
 }
 
 unsafe impl<T: ShortcutManagerImpl> IsImplementable<T> for ShortcutManager {
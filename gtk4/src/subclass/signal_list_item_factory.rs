@@ -0,0 +1,101 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use super::list_item_factory::{ListItemFactoryImpl, ListItemFactoryImplExt};
+use crate::{ListItem, ListItemFactory, SignalListItemFactory};
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use glib::Cast;
+
+// rustdoc-stripper-ignore-next
+/// The methods are installed as the class handlers of the `setup`, `bind`,
+/// `unbind` and `teardown` signals, handlers connected to the instance
+/// run after them.
+pub trait SignalListItemFactoryImpl: ListItemFactoryImplExt + ListItemFactoryImpl {
+    fn setup(&self, factory: &Self::Type, list_item: &ListItem) {
+        self.parent_setup(factory, list_item)
+    }
+
+    fn bind(&self, factory: &Self::Type, list_item: &ListItem) {
+        self.parent_bind(factory, list_item)
+    }
+
+    fn unbind(&self, factory: &Self::Type, list_item: &ListItem) {
+        self.parent_unbind(factory, list_item)
+    }
+
+    fn teardown(&self, factory: &Self::Type, list_item: &ListItem) {
+        self.parent_teardown(factory, list_item)
+    }
+}
+
+unsafe impl<T: SignalListItemFactoryImpl> IsSubclassable<T> for SignalListItemFactory {
+    fn override_vfuncs(class: &mut glib::Class<Self>) {
+        <ListItemFactory as IsSubclassable<T>>::override_vfuncs(class);
+
+        // GtkSignalListItemFactoryClass is private, override the class
+        // handlers of the signals instead.
+        let type_ = T::get_type().to_glib();
+        unsafe {
+            override_class_handler(type_, b"setup\0", factory_setup::<T> as *const ());
+            override_class_handler(type_, b"bind\0", factory_bind::<T> as *const ());
+            override_class_handler(type_, b"unbind\0", factory_unbind::<T> as *const ());
+            override_class_handler(type_, b"teardown\0", factory_teardown::<T> as *const ());
+        }
+    }
+}
+
+unsafe fn override_class_handler(type_: glib::ffi::GType, signal_name: &[u8], handler: *const ()) {
+    glib::gobject_ffi::g_signal_override_class_handler(
+        signal_name.as_ptr() as *const _,
+        type_,
+        Some(std::mem::transmute::<_, unsafe extern "C" fn()>(handler)),
+    );
+}
+
+unsafe extern "C" fn factory_setup<T: SignalListItemFactoryImpl>(
+    ptr: *mut ffi::GtkSignalListItemFactory,
+    list_item_ptr: *mut ffi::GtkListItem,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<SignalListItemFactory> = from_glib_borrow(ptr);
+    let list_item: Borrowed<ListItem> = from_glib_borrow(list_item_ptr);
+
+    imp.setup(wrap.unsafe_cast_ref(), &list_item)
+}
+
+unsafe extern "C" fn factory_bind<T: SignalListItemFactoryImpl>(
+    ptr: *mut ffi::GtkSignalListItemFactory,
+    list_item_ptr: *mut ffi::GtkListItem,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<SignalListItemFactory> = from_glib_borrow(ptr);
+    let list_item: Borrowed<ListItem> = from_glib_borrow(list_item_ptr);
+
+    imp.bind(wrap.unsafe_cast_ref(), &list_item)
+}
+
+unsafe extern "C" fn factory_unbind<T: SignalListItemFactoryImpl>(
+    ptr: *mut ffi::GtkSignalListItemFactory,
+    list_item_ptr: *mut ffi::GtkListItem,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<SignalListItemFactory> = from_glib_borrow(ptr);
+    let list_item: Borrowed<ListItem> = from_glib_borrow(list_item_ptr);
+
+    imp.unbind(wrap.unsafe_cast_ref(), &list_item)
+}
+
+unsafe extern "C" fn factory_teardown<T: SignalListItemFactoryImpl>(
+    ptr: *mut ffi::GtkSignalListItemFactory,
+    list_item_ptr: *mut ffi::GtkListItem,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<SignalListItemFactory> = from_glib_borrow(ptr);
+    let list_item: Borrowed<ListItem> = from_glib_borrow(list_item_ptr);
+
+    imp.teardown(wrap.unsafe_cast_ref(), &list_item)
+}
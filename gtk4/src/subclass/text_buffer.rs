@@ -458,16 +458,21 @@ unsafe extern "C" fn text_buffer_insert_text<T: TextBufferImpl>(
     ptr: *mut ffi::GtkTextBuffer,
     iter_ptr: *mut ffi::GtkTextIter,
     text_ptr: *const libc::c_char,
-    _length: libc::c_int,
+    length: libc::c_int,
 ) {
     let instance = &*(ptr as *mut T::Instance);
     let imp = instance.get_impl();
     let wrap: Borrowed<TextBuffer> = from_glib_borrow(ptr);
-    let text: Borrowed<glib::GString> = from_glib_borrow(text_ptr);
+    // The text isn't necessarily nul-terminated after `length` bytes.
+    let text = std::str::from_utf8(std::slice::from_raw_parts(
+        text_ptr as *const u8,
+        length as usize,
+    ))
+    .unwrap();
 
     let mut iter = from_glib_none(iter_ptr);
 
-    imp.insert_text(&wrap.unsafe_cast_ref(), &mut iter, text.as_str());
+    imp.insert_text(&wrap.unsafe_cast_ref(), &mut iter, text);
     *iter_ptr = *iter.to_glib_none().0;
 }
 
@@ -6,8 +6,8 @@ use glib::Cast;
 
 use super::widget::WidgetImpl;
 use crate::{
-    DeleteType, MovementStep, Snapshot, TextExtendSelection, TextIter, TextView, TextViewLayer,
-    Widget,
+    DeleteType, MovementStep, Snapshot, TextBuffer, TextExtendSelection, TextIter, TextView,
+    TextViewLayer, Widget,
 };
 
 pub trait TextViewImpl: TextViewImplExt + WidgetImpl {
@@ -19,6 +19,13 @@ pub trait TextViewImpl: TextViewImplExt + WidgetImpl {
         self.parent_copy_clipboard(text_view)
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Creates the buffer of the text view when none was set, allowing it to
+    /// be of a subclass of `TextBuffer`.
+    fn create_buffer(&self, text_view: &Self::Type) -> TextBuffer {
+        self.parent_create_buffer(text_view)
+    }
+
     fn cut_clipboard(&self, text_view: &Self::Type) {
         self.parent_cut_clipboard(text_view)
     }
@@ -76,6 +83,7 @@ pub trait TextViewImpl: TextViewImplExt + WidgetImpl {
 pub trait TextViewImplExt: ObjectSubclass {
     fn parent_backspace(&self, text_view: &Self::Type);
     fn parent_copy_clipboard(&self, text_view: &Self::Type);
+    fn parent_create_buffer(&self, text_view: &Self::Type) -> TextBuffer;
     fn parent_cut_clipboard(&self, text_view: &Self::Type);
     fn parent_delete_from_cursor(&self, text_view: &Self::Type, type_: DeleteType, count: i32);
     fn parent_extend_selection(
@@ -127,6 +135,17 @@ impl<T: TextViewImpl> TextViewImplExt for T {
         }
     }
 
+    fn parent_create_buffer(&self, text_view: &Self::Type) -> TextBuffer {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GtkTextViewClass;
+            let f = (*parent_class)
+                .create_buffer
+                .expect("No parent class impl for \"create_buffer\"");
+            from_glib_full(f(text_view.unsafe_cast_ref::<TextView>().to_glib_none().0))
+        }
+    }
+
     fn parent_cut_clipboard(&self, text_view: &Self::Type) {
         unsafe {
             let data = T::type_data();
@@ -277,6 +296,7 @@ unsafe impl<T: TextViewImpl> IsSubclassable<T> for TextView {
         let klass = class.as_mut();
         klass.backspace = Some(text_view_backspace::<T>);
         klass.copy_clipboard = Some(text_view_copy_clipboard::<T>);
+        klass.create_buffer = Some(text_view_create_buffer::<T>);
         klass.cut_clipboard = Some(text_view_cut_clipboard::<T>);
         klass.delete_from_cursor = Some(text_view_delete_from_cursor::<T>);
         klass.extend_selection = Some(text_view_extend_selection::<T>);
@@ -306,6 +326,16 @@ unsafe extern "C" fn text_view_copy_clipboard<T: TextViewImpl>(ptr: *mut ffi::Gt
     imp.copy_clipboard(wrap.unsafe_cast_ref())
 }
 
+unsafe extern "C" fn text_view_create_buffer<T: TextViewImpl>(
+    ptr: *mut ffi::GtkTextView,
+) -> *mut ffi::GtkTextBuffer {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<TextView> = from_glib_borrow(ptr);
+
+    imp.create_buffer(wrap.unsafe_cast_ref()).to_glib_full()
+}
+
 unsafe extern "C" fn text_view_cut_clipboard<T: TextViewImpl>(ptr: *mut ffi::GtkTextView) {
     let instance = &*(ptr as *mut T::Instance);
     let imp = instance.get_impl();
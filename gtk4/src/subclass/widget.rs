@@ -6,12 +6,71 @@ use glib::translate::*;
 use glib::Cast;
 
 use crate::{
-    AccessibleRole, DirectionType, LayoutManager, Orientation, Shortcut, SizeRequestMode, Snapshot,
-    StateFlags, SystemSetting, TextDirection, Tooltip, Widget, WidgetExt,
+    AccessibleRole, BuilderRustScope, BuilderScope, CssStyleChange, DirectionType, KeyvalTrigger,
+    LayoutManager, NamedAction, Orientation, Shortcut, SizeRequestMode, Snapshot, StateFlags,
+    SystemSetting, TextDirection, Tooltip, Widget, WidgetExt,
 };
 use glib::Object;
 
+// rustdoc-stripper-ignore-next
+/// The size of a widget in one orientation, returned by
+/// [`WidgetImpl::measure_for_size`].
+///
+/// The baselines are `-1` when the widget has none.
+///
+/// [`WidgetImpl::measure_for_size`]: trait.WidgetImpl.html#method.measure_for_size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MeasureResult {
+    pub minimum: i32,
+    pub natural: i32,
+    pub minimum_baseline: i32,
+    pub natural_baseline: i32,
+}
+
+impl MeasureResult {
+    // rustdoc-stripper-ignore-next
+    /// A size without baselines.
+    pub fn new(minimum: i32, natural: i32) -> Self {
+        Self {
+            minimum,
+            natural,
+            minimum_baseline: -1,
+            natural_baseline: -1,
+        }
+    }
+}
+
+impl From<(i32, i32, i32, i32)> for MeasureResult {
+    fn from((minimum, natural, minimum_baseline, natural_baseline): (i32, i32, i32, i32)) -> Self {
+        Self {
+            minimum,
+            natural,
+            minimum_baseline,
+            natural_baseline,
+        }
+    }
+}
+
+impl From<MeasureResult> for (i32, i32, i32, i32) {
+    fn from(result: MeasureResult) -> Self {
+        (
+            result.minimum,
+            result.natural,
+            result.minimum_baseline,
+            result.natural_baseline,
+        )
+    }
+}
+
 pub trait WidgetImpl: WidgetImplExt + ObjectImpl {
+    // rustdoc-stripper-ignore-next
+    /// Computes whether the widget would like to get extra space, when its
+    /// `hexpand` and `vexpand` properties aren't set. `hexpand` and `vexpand`
+    /// start out `false`, or with the value of the property when it is set.
+    ///
+    /// Containers usually expand if any of their children does, which they
+    /// find out with `WidgetExt::compute_expand`. GTK recomputes the value
+    /// when the expand flags of a child change.
     fn compute_expand(&self, widget: &Self::Type, hexpand: &mut bool, vexpand: &mut bool) {
         self.parent_compute_expand(widget, hexpand, vexpand)
     }
@@ -20,6 +79,25 @@ pub trait WidgetImpl: WidgetImplExt + ObjectImpl {
         self.parent_contains(widget, x, y)
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Called when the style of the widget changed, to update the values
+    /// computed from it. The implementation of `GtkWidget` queues a resize
+    /// or a redraw as needed, so overrides usually chain up.
+    ///
+    /// `change` is `None` when GTK doesn't tell what changed.
+    fn css_changed(&self, widget: &Self::Type, change: Option<&CssStyleChange>) {
+        self.parent_css_changed(widget, change)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Called when the text direction of the widget changed from
+    /// `previous_direction`, see `WidgetExt::get_direction`. The
+    /// implementation of `GtkWidget` updates the direction of the Pango
+    /// context of the widget and queues a resize.
+    ///
+    /// Widgets keeping their own `pango::Layout`s have to update them, or
+    /// create them again. Overrides that skip chaining up have to queue a
+    /// resize or a redraw themselves.
     fn direction_changed(&self, widget: &Self::Type, previous_direction: TextDirection) {
         self.parent_direction_changed(widget, previous_direction)
     }
@@ -28,10 +106,30 @@ pub trait WidgetImpl: WidgetImplExt + ObjectImpl {
         self.parent_focus(widget, direction_type)
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Called when the widget gains or loses the keyboard focus, when GTK
+    /// changes its `StateFlags::FOCUSED` flag, before [`state_flags_changed`].
+    ///
+    /// `GtkWidget` has no such virtual function, the default queues a redraw
+    /// to update the focus ring. Overrides skip it unless they call
+    /// `queue_draw` themselves.
+    ///
+    /// [`state_flags_changed`]: #method.state_flags_changed
+    fn focus_changed(&self, widget: &Self::Type, gained: bool) {
+        let _ = gained;
+        widget.unsafe_cast_ref::<Widget>().queue_draw();
+    }
+
     fn get_request_mode(&self, widget: &Self::Type) -> SizeRequestMode {
         self.parent_get_request_mode(widget)
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Gives the keyboard focus to the widget, returns whether it got it.
+    ///
+    /// The implementation of `GtkWidget` only focuses focusable widgets.
+    /// Composite widgets can give the focus to one of their children instead,
+    /// like `GtkEntry` does with its `GtkText`.
     fn grab_focus(&self, widget: &Self::Type) -> bool {
         self.parent_grab_focus(widget)
     }
@@ -48,17 +146,34 @@ pub trait WidgetImpl: WidgetImplExt + ObjectImpl {
         self.parent_map(widget)
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Returns `(minimum, natural, minimum_baseline, natural_baseline)`, the
+    /// baselines being `-1` when the widget has none.
+    ///
+    /// Calls [`measure_for_size`] by default, which returns a
+    /// [`MeasureResult`] instead, either of them can be overridden.
+    ///
+    /// [`measure_for_size`]: #method.measure_for_size
+    /// [`MeasureResult`]: struct.MeasureResult.html
     fn measure(
         &self,
         widget: &Self::Type,
         orientation: Orientation,
         for_size: i32,
-        min: &mut i32,
-        nat: &mut i32,
-        min_base: &mut i32,
-        nat_base: &mut i32,
-    ) {
-        self.parent_measure(widget, orientation, for_size, min, nat, min_base, nat_base)
+    ) -> (i32, i32, i32, i32) {
+        self.measure_for_size(widget, orientation, for_size).into()
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Measures the widget in `orientation`, for a size of `for_size` in the
+    /// other orientation, or `-1` if it is unknown.
+    fn measure_for_size(
+        &self,
+        widget: &Self::Type,
+        orientation: Orientation,
+        for_size: i32,
+    ) -> MeasureResult {
+        self.parent_measure_for_size(widget, orientation, for_size)
     }
 
     fn mnemonic_activate(&self, widget: &Self::Type, group_cycling: bool) -> bool {
@@ -104,12 +219,37 @@ pub trait WidgetImpl: WidgetImplExt + ObjectImpl {
         self.parent_snapshot(widget, snapshot)
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Snapshots one of the children of the widget, from
+    /// [`WidgetImplExt::snapshot_children`].
+    ///
+    /// GTK has no virtual function for this, the children are only
+    /// intercepted when [`snapshot`] goes through `snapshot_children` instead
+    /// of chaining up. Overriding this allows wrapping the snapshot of
+    /// `child` in effect nodes, like with `Snapshot::push_opacity`.
+    ///
+    /// [`WidgetImplExt::snapshot_children`]: trait.WidgetImplExt.html#tymethod.snapshot_children
+    /// [`snapshot`]: #method.snapshot
+    #[doc(alias = "gtk_widget_snapshot_child")]
+    fn snapshot_child(&self, widget: &Self::Type, child: &Widget, snapshot: &Snapshot) {
+        widget
+            .unsafe_cast_ref::<Widget>()
+            .snapshot_child(child, snapshot)
+    }
+
     fn state_flags_changed(&self, widget: &Self::Type, state_flags: &StateFlags) {
         self.parent_state_flags_changed(widget, state_flags)
     }
 
-    fn system_setting_changed(&self, widget: &Self::Type, settings: &SystemSetting) {
-        self.parent_system_setting_changed(widget, settings)
+    // rustdoc-stripper-ignore-next
+    /// Called when a system setting like the font or the DPI changed, to
+    /// update the values computed from it.
+    ///
+    /// The implementation of `GtkWidget` updates the Pango context of the
+    /// widget and passes the change on to the children, overrides have to
+    /// chain up.
+    fn system_setting_changed(&self, widget: &Self::Type, setting: SystemSetting) {
+        self.parent_system_setting_changed(widget, setting)
     }
 
     fn unmap(&self, widget: &Self::Type) {
@@ -128,6 +268,7 @@ pub trait WidgetImpl: WidgetImplExt + ObjectImpl {
 pub trait WidgetImplExt: ObjectSubclass {
     fn parent_compute_expand(&self, widget: &Self::Type, hexpand: &mut bool, vexpand: &mut bool);
     fn parent_contains(&self, widget: &Self::Type, x: f64, y: f64) -> bool;
+    fn parent_css_changed(&self, widget: &Self::Type, change: Option<&CssStyleChange>);
     fn parent_direction_changed(&self, widget: &Self::Type, previous_direction: TextDirection);
     fn parent_focus(&self, widget: &Self::Type, direction_type: DirectionType) -> bool;
     fn parent_get_request_mode(&self, widget: &Self::Type) -> SizeRequestMode;
@@ -140,11 +281,13 @@ pub trait WidgetImplExt: ObjectSubclass {
         widget: &Self::Type,
         orientation: Orientation,
         for_size: i32,
-        min: &mut i32,
-        nat: &mut i32,
-        min_base: &mut i32,
-        nat_base: &mut i32,
-    );
+    ) -> (i32, i32, i32, i32);
+    fn parent_measure_for_size(
+        &self,
+        widget: &Self::Type,
+        orientation: Orientation,
+        for_size: i32,
+    ) -> MeasureResult;
     fn parent_mnemonic_activate(&self, widget: &Self::Type, group_cycling: bool) -> bool;
     fn parent_move_focus(&self, widget: &Self::Type, direction_type: DirectionType);
     fn parent_query_tooltip(
@@ -161,8 +304,14 @@ pub trait WidgetImplExt: ObjectSubclass {
     fn parent_show(&self, widget: &Self::Type);
     fn parent_size_allocate(&self, widget: &Self::Type, width: i32, height: i32, baseline: i32);
     fn parent_snapshot(&self, widget: &Self::Type, snapshot: &Snapshot);
+    // rustdoc-stripper-ignore-next
+    /// Snapshots all the children, in order, through
+    /// [`WidgetImpl::snapshot_child`].
+    ///
+    /// [`WidgetImpl::snapshot_child`]: trait.WidgetImpl.html#method.snapshot_child
+    fn snapshot_children(&self, widget: &Self::Type, snapshot: &Snapshot);
     fn parent_state_flags_changed(&self, widget: &Self::Type, state_flags: &StateFlags);
-    fn parent_system_setting_changed(&self, widget: &Self::Type, settings: &SystemSetting);
+    fn parent_system_setting_changed(&self, widget: &Self::Type, setting: SystemSetting);
     fn parent_unmap(&self, widget: &Self::Type);
     fn parent_unrealize(&self, widget: &Self::Type);
     fn parent_unroot(&self, widget: &Self::Type);
@@ -200,6 +349,19 @@ impl<T: WidgetImpl> WidgetImplExt for T {
         }
     }
 
+    fn parent_css_changed(&self, widget: &Self::Type, change: Option<&CssStyleChange>) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::GtkWidgetClass;
+            if let Some(f) = (*parent_class).css_changed {
+                f(
+                    widget.unsafe_cast_ref::<Widget>().to_glib_none().0,
+                    change.map_or(std::ptr::null_mut(), |change| change.as_ptr()),
+                )
+            }
+        }
+    }
+
     fn parent_direction_changed(&self, widget: &Self::Type, previous_direction: TextDirection) {
         unsafe {
             let data = T::type_data();
@@ -291,11 +453,7 @@ impl<T: WidgetImpl> WidgetImplExt for T {
         widget: &Self::Type,
         orientation: Orientation,
         for_size: i32,
-        min: &mut i32,
-        nat: &mut i32,
-        min_base: &mut i32,
-        nat_base: &mut i32,
-    ) {
+    ) -> (i32, i32, i32, i32) {
         unsafe {
             let data = T::type_data();
             let parent_class = data.as_ref().get_parent_class() as *mut ffi::GtkWidgetClass;
@@ -304,18 +462,32 @@ impl<T: WidgetImpl> WidgetImplExt for T {
                 .measure
                 .expect("No parent class impl for \"measure\"");
 
+            let mut min = 0;
+            let mut nat = 0;
+            let mut min_base = -1;
+            let mut nat_base = -1;
             f(
                 widget.unsafe_cast_ref::<Widget>().to_glib_none().0,
                 orientation.to_glib(),
                 for_size,
-                min,
-                nat,
-                min_base,
-                nat_base,
+                &mut min,
+                &mut nat,
+                &mut min_base,
+                &mut nat_base,
             );
+            (min, nat, min_base, nat_base)
         }
     }
 
+    fn parent_measure_for_size(
+        &self,
+        widget: &Self::Type,
+        orientation: Orientation,
+        for_size: i32,
+    ) -> MeasureResult {
+        self.parent_measure(widget, orientation, for_size).into()
+    }
+
     fn parent_mnemonic_activate(&self, widget: &Self::Type, group_cycling: bool) -> bool {
         unsafe {
             let data = T::type_data();
@@ -440,6 +612,14 @@ impl<T: WidgetImpl> WidgetImplExt for T {
         }
     }
 
+    fn snapshot_children(&self, widget: &Self::Type, snapshot: &Snapshot) {
+        let mut child = widget.unsafe_cast_ref::<Widget>().get_first_child();
+        while let Some(widget_child) = child {
+            self.snapshot_child(widget, &widget_child, snapshot);
+            child = widget_child.get_next_sibling();
+        }
+    }
+
     fn parent_state_flags_changed(&self, widget: &Self::Type, state_flags: &StateFlags) {
         unsafe {
             let data = T::type_data();
@@ -453,14 +633,14 @@ impl<T: WidgetImpl> WidgetImplExt for T {
         }
     }
 
-    fn parent_system_setting_changed(&self, widget: &Self::Type, settings: &SystemSetting) {
+    fn parent_system_setting_changed(&self, widget: &Self::Type, setting: SystemSetting) {
         unsafe {
             let data = T::type_data();
             let parent_class = data.as_ref().get_parent_class() as *mut ffi::GtkWidgetClass;
             if let Some(f) = (*parent_class).system_setting_changed {
                 f(
                     widget.unsafe_cast_ref::<Widget>().to_glib_none().0,
-                    settings.to_glib(),
+                    setting.to_glib(),
                 )
             }
         }
@@ -505,6 +685,7 @@ unsafe impl<T: WidgetImpl> IsSubclassable<T> for Widget {
 
         klass.compute_expand = Some(widget_compute_expand::<T>);
         klass.contains = Some(widget_contains::<T>);
+        klass.css_changed = Some(widget_css_changed::<T>);
         klass.direction_changed = Some(widget_direction_changed::<T>);
         klass.focus = Some(widget_focus::<T>);
         klass.get_request_mode = Some(widget_get_request_mode::<T>);
@@ -569,6 +750,20 @@ unsafe extern "C" fn widget_contains<T: WidgetImpl>(
     imp.contains(wrap.unsafe_cast_ref(), x, y).to_glib()
 }
 
+unsafe extern "C" fn widget_css_changed<T: WidgetImpl>(
+    ptr: *mut ffi::GtkWidget,
+    change_ptr: *mut ffi::GtkCssStyleChange,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Widget> = from_glib_borrow(ptr);
+
+    imp.css_changed(
+        wrap.unsafe_cast_ref(),
+        CssStyleChange::from_ptr(change_ptr).as_ref(),
+    )
+}
+
 unsafe extern "C" fn widget_direction_changed<T: WidgetImpl>(
     ptr: *mut ffi::GtkWidget,
     direction_ptr: ffi::GtkTextDirection,
@@ -656,32 +851,7 @@ unsafe extern "C" fn widget_measure<T: WidgetImpl>(
     let wrap: Borrowed<Widget> = from_glib_borrow(ptr);
     let orientation = from_glib(orientation_ptr);
 
-    let mut min = 0;
-    let mut nat = 0;
-    let mut min_base = -1;
-    let mut nat_base = -1;
-    if !min_ptr.is_null() {
-        min = *min_ptr;
-    }
-    if !nat_ptr.is_null() {
-        nat = *nat_ptr;
-    }
-    if !min_base_ptr.is_null() {
-        min_base = *min_base_ptr;
-    }
-    if !nat_base_ptr.is_null() {
-        nat_base = *nat_base_ptr;
-    }
-
-    imp.measure(
-        wrap.unsafe_cast_ref(),
-        orientation,
-        for_size,
-        &mut min,
-        &mut nat,
-        &mut min_base,
-        &mut nat_base,
-    );
+    let (min, nat, min_base, nat_base) = imp.measure(wrap.unsafe_cast_ref(), orientation, for_size);
     if !min_ptr.is_null() {
         *min_ptr = min;
     }
@@ -807,21 +977,24 @@ unsafe extern "C" fn widget_state_flags_changed<T: WidgetImpl>(
     let instance = &*(ptr as *mut T::Instance);
     let imp = instance.get_impl();
     let wrap: Borrowed<Widget> = from_glib_borrow(ptr);
-    let state_flags = from_glib(state_flags_ptr);
+    let state_flags: StateFlags = from_glib(state_flags_ptr);
 
+    let focused = wrap.get_state_flags().contains(StateFlags::FOCUSED);
+    if focused != state_flags.contains(StateFlags::FOCUSED) {
+        imp.focus_changed(wrap.unsafe_cast_ref(), focused);
+    }
     imp.state_flags_changed(wrap.unsafe_cast_ref(), &state_flags)
 }
 
 unsafe extern "C" fn widget_system_setting_changed<T: WidgetImpl>(
     ptr: *mut ffi::GtkWidget,
-    settings_ptr: ffi::GtkSystemSetting,
+    setting: ffi::GtkSystemSetting,
 ) {
     let instance = &*(ptr as *mut T::Instance);
     let imp = instance.get_impl();
     let wrap: Borrowed<Widget> = from_glib_borrow(ptr);
-    let settings = from_glib(settings_ptr);
 
-    imp.system_setting_changed(wrap.unsafe_cast_ref(), &settings)
+    imp.system_setting_changed(wrap.unsafe_cast_ref(), from_glib(setting))
 }
 
 unsafe extern "C" fn widget_unmap<T: WidgetImpl>(ptr: *mut ffi::GtkWidget) {
@@ -876,6 +1049,55 @@ pub unsafe trait WidgetClassSubclassExt: ClassStruct {
         }
     }
 
+    fn set_template_scope<S: IsA<BuilderScope>>(&mut self, scope: &S) {
+        unsafe {
+            let widget_class = self as *mut _ as *mut ffi::GtkWidgetClass;
+            ffi::gtk_widget_class_set_template_scope(widget_class, scope.as_ref().to_glib_none().0);
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Registers `callback` as the handler named `name` for the `<signal>`
+    /// elements of the template.
+    ///
+    /// The first call replaces the scope of the template with a
+    /// [`BuilderRustScope`], so it has to happen after the template is set
+    /// and all the handlers of the template have to be registered this way.
+    ///
+    /// [`BuilderRustScope`]: ../../struct.BuilderRustScope.html
+    fn add_template_callback<F>(&mut self, name: &str, callback: F)
+    where
+        F: Fn(&[glib::Value]) -> Option<glib::Value> + Send + Sync + 'static,
+    {
+        unsafe {
+            let type_ = <Self::Type as ObjectSubclass>::get_type().to_glib();
+            let quark = glib::Quark::from_string("gtk-rs-template-scope").to_glib();
+            let mut scope = glib::gobject_ffi::g_type_get_qdata(type_, quark);
+            if scope.is_null() {
+                let rust_scope = BuilderRustScope::new();
+                self.set_template_scope(&rust_scope);
+                // Classes are never finalized, the reference is kept forever
+                scope = rust_scope.to_glib_full() as glib::ffi::gpointer;
+                glib::gobject_ffi::g_type_set_qdata(type_, quark, scope);
+            }
+            let scope: Borrowed<BuilderRustScope> =
+                from_glib_borrow(scope as *mut <BuilderRustScope as ObjectType>::GlibType);
+            scope.add_callback(name, callback);
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Registers the `#[template_callback]` methods of the implementation,
+    /// see [`add_template_callback`].
+    ///
+    /// [`add_template_callback`]: #method.add_template_callback
+    fn bind_template_callbacks(&mut self)
+    where
+        Self::Type: CompositeTemplateCallbacks,
+    {
+        <Self::Type as CompositeTemplateCallbacks>::bind_template_callbacks(self);
+    }
+
     fn bind_template_child(&mut self, name: &str) {
         unsafe {
             let widget_class = self as *mut _ as *mut ffi::GtkWidgetClass;
@@ -895,6 +1117,44 @@ pub unsafe trait WidgetClassSubclassExt: ClassStruct {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Adds a shortcut activating the action named `action_name` when
+    /// `keyval` is pressed with `mods`, to all the widgets of the class.
+    ///
+    /// The action is looked up with `WidgetExt::activate_action`, it can be
+    /// a widget action or one of an ancestor, like `win.close`.
+    #[doc(alias = "gtk_widget_class_add_binding_action")]
+    fn add_binding_action(
+        &mut self,
+        keyval: gdk::keys::Key,
+        mods: gdk::ModifierType,
+        action_name: &str,
+    ) {
+        self.add_binding_action_with_arg(keyval, mods, action_name, None::<&glib::Variant>);
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`add_binding_action`], for an action taking the parameter
+    /// `arg`, like `win.select-tab(1)`. A `None` argument activates the
+    /// action without parameter.
+    ///
+    /// [`add_binding_action`]: #method.add_binding_action
+    #[doc(alias = "gtk_widget_class_add_binding_action")]
+    fn add_binding_action_with_arg<T: glib::ToVariant>(
+        &mut self,
+        keyval: gdk::keys::Key,
+        mods: gdk::ModifierType,
+        action_name: &str,
+        arg: Option<&T>,
+    ) {
+        let shortcut = Shortcut::new(
+            Some(&KeyvalTrigger::new(keyval, mods)),
+            Some(&NamedAction::new(action_name)),
+        );
+        shortcut.set_arguments(arg.map(|arg| arg.to_variant()).as_ref());
+        self.add_shortcut(&shortcut);
+    }
+
     fn set_layout_manager_type<T: IsA<LayoutManager>>(&mut self) {
         unsafe {
             let widget_class = self as *mut _ as *mut ffi::GtkWidgetClass;
@@ -986,16 +1246,19 @@ where
 {
     type Target = T;
 
-    /// # Safety
+    /// # Panics
     ///
-    /// Since the template child may not be properly bound,
-    /// this cast is potentially dangerous if, for example,
-    /// the template child isn't bound or is of the wrong type.
-    /// The caller is responsible for ensuring that the template
-    /// child is bound and of the right type.
+    /// Panics if the template child isn't bound, or if it is accessed
+    /// before `init_template()` was called.
+    ///
+    /// The type of the child isn't checked, the object with the bound id
+    /// in the template has to be a `T`.
+    #[track_caller]
     fn deref(&self) -> &Self::Target {
         unsafe {
-            assert!(!self.ptr.is_null());
+            if self.ptr.is_null() {
+                unbound_template_child::<T>();
+            }
             &*(&self.ptr as *const _ as *const T)
         }
     }
@@ -1007,13 +1270,42 @@ where
 {
     #[track_caller]
     pub fn get(&self) -> T {
-        unsafe {
-            Option::<T>::from_glib_none(self.ptr)
-                .expect("Failed to retrieve template child. Please check that it has been bound.")
+        match self.try_get() {
+            Some(child) => child,
+            None => unbound_template_child::<T>(),
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the child, or `None` if it wasn't bound or the template
+    /// wasn't initialized yet.
+    pub fn try_get(&self) -> Option<T> {
+        unsafe { Option::<T>::from_glib_none(self.ptr) }
+    }
+}
+
+#[track_caller]
+fn unbound_template_child<T>() -> ! {
+    panic!(
+        "Failed to retrieve template child of type {}. Please check that it has been bound \
+         and that init_template() was called.",
+        std::any::type_name::<T>()
+    )
 }
 
 pub trait CompositeTemplate: WidgetImpl {
     fn bind_template_children(klass: &mut Self::Class);
 }
+
+// rustdoc-stripper-ignore-next
+/// Implemented by `#[template_callbacks]` for the `#[template_callback]`
+/// methods of an `impl` block.
+pub trait CompositeTemplateCallbacks: 'static {
+    const CALLBACKS: &'static [(&'static str, fn(&[glib::Value]) -> Option<glib::Value>)];
+
+    fn bind_template_callbacks<C: WidgetClassSubclassExt>(klass: &mut C) {
+        for &(name, callback) in Self::CALLBACKS {
+            klass.add_template_callback(name, callback);
+        }
+    }
+}
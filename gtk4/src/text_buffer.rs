@@ -1,6 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{TextBuffer, TextIter};
+use crate::prelude::{TextBufferExt, TextMarkExt};
+use crate::{TextBuffer, TextIter, TextMark, TextSearchFlags, TextTag};
 use glib::object::{Cast, IsA};
 use glib::signal::{connect_raw, SignalHandlerId};
 use glib::translate::*;
@@ -10,6 +11,57 @@ use std::mem::transmute;
 use std::{slice, str};
 
 pub trait TextBufferExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Inserts `text` at `iter` with `tags` applied. `iter` is moved to the
+    /// end of the inserted text.
+    #[doc(alias = "gtk_text_buffer_insert_with_tags")]
+    fn insert_with_tags(&self, iter: &mut TextIter, text: &str, tags: &[&TextTag]);
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`insert_with_tags`], with the tags looked up by name in the tag
+    /// table of the buffer.
+    ///
+    /// [`insert_with_tags`]: #tymethod.insert_with_tags
+    #[doc(alias = "gtk_text_buffer_insert_with_tags_by_name")]
+    fn insert_with_tags_by_name(&self, iter: &mut TextIter, text: &str, tags: &[&str]);
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the text of the whole buffer, without the hidden text like
+    /// the `text` property.
+    fn text(&self) -> glib::GString;
+
+    // rustdoc-stripper-ignore-next
+    /// Creates an anonymous mark at `iter`, that keeps track of the position
+    /// across the changes to the buffer, and is deleted with the guard.
+    ///
+    /// The mark has a right gravity, like the cursor, text inserted at its
+    /// position ends up before it.
+    fn mark_at(&self, iter: &TextIter) -> TextMarkGuard;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns an iterator over the lines, as the iters at their start and
+    /// end, before the line terminator.
+    fn lines(&self) -> TextBufferLines;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns an iterator over the matches of `needle` in the whole buffer,
+    /// each search starting at the end of the previous match.
+    ///
+    /// Like all iters, the matches are invalidated by changes to the buffer.
+    fn find(&self, needle: &str, flags: TextSearchFlags) -> TextSearch;
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`find`], for the matches between `start` and `limit`.
+    ///
+    /// [`find`]: #tymethod.find
+    fn find_in_range(
+        &self,
+        needle: &str,
+        flags: TextSearchFlags,
+        start: &TextIter,
+        limit: &TextIter,
+    ) -> TextSearch;
+
     fn connect_insert_text<F: Fn(&Self, &mut TextIter, &str) + 'static>(
         &self,
         f: F,
@@ -17,6 +69,67 @@ pub trait TextBufferExtManual: 'static {
 }
 
 impl<O: IsA<TextBuffer>> TextBufferExtManual for O {
+    fn insert_with_tags(&self, iter: &mut TextIter, text: &str, tags: &[&TextTag]) {
+        let start_offset = iter.get_offset();
+        self.insert(iter, text);
+        let start = self.get_iter_at_offset(start_offset);
+        for tag in tags {
+            self.apply_tag(*tag, &start, iter);
+        }
+    }
+
+    fn insert_with_tags_by_name(&self, iter: &mut TextIter, text: &str, tags: &[&str]) {
+        let start_offset = iter.get_offset();
+        self.insert(iter, text);
+        let start = self.get_iter_at_offset(start_offset);
+        for tag in tags {
+            self.apply_tag_by_name(tag, &start, iter);
+        }
+    }
+
+    fn text(&self) -> glib::GString {
+        let (start, end) = self.get_bounds();
+        self.get_text(&start, &end, false)
+    }
+
+    fn mark_at(&self, iter: &TextIter) -> TextMarkGuard {
+        TextMarkGuard {
+            buffer: self.as_ref().clone(),
+            mark: self.create_mark(None, iter, false),
+        }
+    }
+
+    fn lines(&self) -> TextBufferLines {
+        TextBufferLines {
+            buffer: self.as_ref().clone(),
+            line: 0,
+        }
+    }
+
+    fn find(&self, needle: &str, flags: TextSearchFlags) -> TextSearch {
+        TextSearch {
+            needle: needle.to_owned(),
+            flags,
+            start: Some(self.get_start_iter()),
+            limit: None,
+        }
+    }
+
+    fn find_in_range(
+        &self,
+        needle: &str,
+        flags: TextSearchFlags,
+        start: &TextIter,
+        limit: &TextIter,
+    ) -> TextSearch {
+        TextSearch {
+            needle: needle.to_owned(),
+            flags,
+            start: Some(start.clone()),
+            limit: Some(limit.clone()),
+        }
+    }
+
     fn connect_insert_text<F: Fn(&Self, &mut TextIter, &str) + 'static>(
         &self,
         f: F,
@@ -42,11 +155,105 @@ unsafe extern "C" fn insert_text_trampoline<T, F: Fn(&T, &mut TextIter, &str) +
 ) where
     T: IsA<TextBuffer>,
 {
-    let mut location_copy = from_glib_none(location);
+    let mut location_copy: TextIter = from_glib_none(location);
     let f: &F = &*(f as *const F);
     f(
         &TextBuffer::from_glib_borrow(this).unsafe_cast_ref(),
         &mut location_copy,
         str::from_utf8(slice::from_raw_parts(text as *const u8, len as usize)).unwrap(),
-    )
+    );
+    // Handlers are allowed to move the iter, and must revalidate it if they
+    // change the buffer.
+    *location = *location_copy.to_glib_none().0;
+}
+
+// rustdoc-stripper-ignore-next
+/// A position in a [`TextBuffer`] that stays valid across its changes,
+/// created with [`TextBufferExtManual::mark_at`].
+///
+/// [`TextBuffer`]: struct.TextBuffer.html
+/// [`TextBufferExtManual::mark_at`]: prelude/trait.TextBufferExtManual.html#tymethod.mark_at
+#[derive(Debug)]
+pub struct TextMarkGuard {
+    buffer: TextBuffer,
+    mark: TextMark,
+}
+
+impl TextMarkGuard {
+    pub fn mark(&self) -> &TextMark {
+        &self.mark
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns an iter at the current position of the mark.
+    pub fn iter(&self) -> TextIter {
+        self.buffer.get_iter_at_mark(&self.mark)
+    }
+}
+
+impl Drop for TextMarkGuard {
+    fn drop(&mut self) {
+        if !self.mark.get_deleted() {
+            self.buffer.delete_mark(&self.mark);
+        }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// An iterator over the lines of a [`TextBuffer`], created with
+/// [`TextBufferExtManual::lines`].
+///
+/// [`TextBuffer`]: struct.TextBuffer.html
+/// [`TextBufferExtManual::lines`]: prelude/trait.TextBufferExtManual.html#tymethod.lines
+#[derive(Debug)]
+pub struct TextBufferLines {
+    buffer: TextBuffer,
+    line: i32,
+}
+
+impl Iterator for TextBufferLines {
+    type Item = (TextIter, TextIter);
+
+    fn next(&mut self) -> Option<(TextIter, TextIter)> {
+        if self.line >= self.buffer.get_line_count() {
+            return None;
+        }
+        let start = self.buffer.get_iter_at_line(self.line)?;
+        let mut end = start.clone();
+        if !end.ends_line() {
+            end.forward_to_line_end();
+        }
+        self.line += 1;
+        Some((start, end))
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// An iterator over the matches of a search in a [`TextBuffer`], created
+/// with [`TextBufferExtManual::find`].
+///
+/// [`TextBuffer`]: struct.TextBuffer.html
+/// [`TextBufferExtManual::find`]: prelude/trait.TextBufferExtManual.html#tymethod.find
+#[derive(Debug)]
+pub struct TextSearch {
+    needle: String,
+    flags: TextSearchFlags,
+    start: Option<TextIter>,
+    limit: Option<TextIter>,
+}
+
+impl Iterator for TextSearch {
+    type Item = (TextIter, TextIter);
+
+    fn next(&mut self) -> Option<(TextIter, TextIter)> {
+        // An empty needle would match forever at the same place.
+        if self.needle.is_empty() {
+            return None;
+        }
+        let start = self.start.take()?;
+        let (match_start, match_end) =
+            start.forward_search(&self.needle, self.flags, self.limit.as_ref())?;
+        self.start = Some(match_end.clone());
+        Some((match_start, match_end))
+    }
 }
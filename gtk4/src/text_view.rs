@@ -1,12 +1,26 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::TextView;
+use crate::prelude::{TextBufferExt, TextViewExt};
+use crate::{TextIter, TextView};
 use glib::translate::*;
 use glib::IsA;
 
 pub trait TextViewExtManual {
     #[doc(alias = "gtk_text_view_im_context_filter_keypress")]
     fn im_context_filter_keypress<R: AsRef<gdk::Event>>(&self, event: &R) -> bool;
+
+    // rustdoc-stripper-ignore-next
+    /// Selects the text between `start` and `end`, like a match of
+    /// `TextBufferExtManual::find`, with the cursor at its end.
+    fn select_match(&self, start: &TextIter, end: &TextIter);
+
+    // rustdoc-stripper-ignore-next
+    /// Scrolls the smallest distance needed for the match at `start` to be
+    /// visible.
+    ///
+    /// It goes through a temporary mark, so it also works before the line
+    /// heights are computed, like right after a change to the buffer.
+    fn scroll_to_match(&self, start: &TextIter);
 }
 
 impl<O: IsA<TextView>> TextViewExtManual for O {
@@ -18,4 +32,17 @@ impl<O: IsA<TextView>> TextViewExtManual for O {
             ))
         }
     }
+
+    fn select_match(&self, start: &TextIter, end: &TextIter) {
+        self.as_ref().get_buffer().select_range(end, start);
+    }
+
+    fn scroll_to_match(&self, start: &TextIter) {
+        let buffer = self.as_ref().get_buffer();
+        let mark = buffer.create_mark(None, start, false);
+        // The scroll is queued with marks of its own, this one isn't needed
+        // anymore.
+        self.scroll_to_mark(&mark, 0.0, false, 0.0, 0.0);
+        buffer.delete_mark(&mark);
+    }
 }
@@ -0,0 +1,216 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gio::prelude::*;
+use glib::object::IsA;
+use glib::{Cast, SignalHandlerId, StaticType};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+// rustdoc-stripper-ignore-next
+/// A [`gio::ListModel`] whose items are known to be of type `T`.
+///
+/// The item type of the wrapped model is checked at construction, and the
+/// type of every item when it is accessed. The wrapper dereferences to the
+/// model so it can be passed to [`SingleSelection`], [`ListView`], ...
+///
+/// [`gio::ListModel`]: ../gio/struct.ListModel.html
+/// [`SingleSelection`]: struct.SingleSelection.html
+/// [`ListView`]: struct.ListView.html
+pub struct TypedListModel<T> {
+    model: gio::ListModel,
+    phantom: PhantomData<T>,
+}
+
+impl<T: IsA<glib::Object>> TypedListModel<T> {
+    // rustdoc-stripper-ignore-next
+    /// Wraps `model`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the item type of `model` is not `T` or a subtype of it.
+    pub fn new<M: IsA<gio::ListModel>>(model: &M) -> Self {
+        let item_type = model.get_item_type();
+        assert!(
+            item_type.is_a(&T::static_type()),
+            "Model items of type {} can't be accessed as {}",
+            item_type,
+            T::static_type()
+        );
+
+        Self::new_unchecked(model.upcast_ref::<gio::ListModel>().clone())
+    }
+
+    fn new_unchecked(model: gio::ListModel) -> Self {
+        Self {
+            model,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn model(&self) -> &gio::ListModel {
+        &self.model
+    }
+
+    pub fn n_items(&self) -> u32 {
+        self.model.get_n_items()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n_items() == 0
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the item at `position`, or `None` if it is out of range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the item isn't a `T`, when the model returns items that
+    /// don't match its item type.
+    pub fn typed_item(&self, position: u32) -> Option<T> {
+        self.model.get_object(position).map(|item| {
+            let item_type = item.get_type();
+            item.downcast().unwrap_or_else(|_| {
+                panic!(
+                    "Model item of type {} at position {} isn't a {}",
+                    item_type,
+                    position,
+                    T::static_type()
+                )
+            })
+        })
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns an iterator over the items.
+    ///
+    /// The number of items is read again on every step, so changes made to
+    /// the model while iterating are observed instead of panicking.
+    pub fn iter(&self) -> TypedListModelIter<'_, T> {
+        TypedListModelIter {
+            model: self,
+            position: 0,
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Connects to `items-changed` of the wrapped model.
+    pub fn connect_items_changed<F: Fn(&Self, u32, u32, u32) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId
+    where
+        T: 'static,
+    {
+        self.model
+            .connect_items_changed(move |model, position, removed, added| {
+                f(
+                    &Self::new_unchecked(model.clone()),
+                    position,
+                    removed,
+                    added,
+                )
+            })
+    }
+}
+
+impl<T> Clone for TypedListModel<T> {
+    fn clone(&self) -> Self {
+        Self {
+            model: self.model.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for TypedListModel<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TypedListModel")
+            .field("model", &self.model)
+            .finish()
+    }
+}
+
+impl<T> Deref for TypedListModel<T> {
+    type Target = gio::ListModel;
+
+    fn deref(&self) -> &gio::ListModel {
+        &self.model
+    }
+}
+
+impl<T> AsRef<gio::ListModel> for TypedListModel<T> {
+    fn as_ref(&self) -> &gio::ListModel {
+        &self.model
+    }
+}
+
+impl<'a, T: IsA<glib::Object>> IntoIterator for &'a TypedListModel<T> {
+    type Item = T;
+    type IntoIter = TypedListModelIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug)]
+pub struct TypedListModelIter<'a, T> {
+    model: &'a TypedListModel<T>,
+    position: u32,
+}
+
+impl<'a, T: IsA<glib::Object>> Iterator for TypedListModelIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let item = self.model.typed_item(self.position)?;
+        self.position += 1;
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_items() {
+        let store = gio::ListStore::new(gio::SimpleAction::static_type());
+        for name in &["first", "second", "third"] {
+            store.append(&gio::SimpleAction::new(name, None));
+        }
+
+        let model = TypedListModel::<gio::SimpleAction>::new(&store);
+        assert_eq!(model.n_items(), 3);
+        assert_eq!(model.typed_item(1).unwrap().get_name().as_str(), "second");
+        assert!(model.typed_item(3).is_none());
+
+        let names = model
+            .iter()
+            .map(|a| a.get_name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["first", "second", "third"]);
+
+        // Items can be accessed as any of their parent types
+        let objects = TypedListModel::<glib::Object>::new(&store);
+        assert_eq!(objects.iter().count(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_item_type() {
+        let store = gio::ListStore::new(gio::SimpleAction::static_type());
+        TypedListModel::<gio::Menu>::new(&store);
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_item() {
+        // Models may return items that don't match their item type.
+        let store = gio::ListStore::new(glib::Object::static_type());
+        store.append(&gio::Menu::new());
+        let model = TypedListModel::<gio::SimpleAction>::new_unchecked(store.upcast());
+        model.typed_item(0);
+    }
+}
@@ -0,0 +1,197 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{TypedListModel, TypedListModelIter};
+use gio::prelude::*;
+use glib::object::IsA;
+use glib::{Cast, StaticType};
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::Deref;
+
+// rustdoc-stripper-ignore-next
+/// A [`gio::ListStore`] holding items of type `T`.
+///
+/// Items are added and returned as `T`, without casts. The store
+/// dereferences to a [`gio::ListModel`] so it can be passed to
+/// [`SingleSelection`], [`ListView`], ...
+///
+/// [`gio::ListStore`]: ../gio/struct.ListStore.html
+/// [`gio::ListModel`]: ../gio/struct.ListModel.html
+/// [`SingleSelection`]: struct.SingleSelection.html
+/// [`ListView`]: struct.ListView.html
+pub struct TypedListStore<T> {
+    store: gio::ListStore,
+    model: TypedListModel<T>,
+}
+
+impl<T: IsA<glib::Object>> TypedListStore<T> {
+    pub fn new() -> Self {
+        let store = gio::ListStore::new(T::static_type());
+        let model = TypedListModel::new(&store);
+        Self { store, model }
+    }
+
+    pub fn list_store(&self) -> &gio::ListStore {
+        &self.store
+    }
+
+    pub fn model(&self) -> &TypedListModel<T> {
+        &self.model
+    }
+
+    pub fn n_items(&self) -> u32 {
+        self.model.n_items()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.model.is_empty()
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the item at `position`, or `None` if it is out of range.
+    pub fn get(&self, position: u32) -> Option<T> {
+        self.model.typed_item(position)
+    }
+
+    pub fn iter(&self) -> TypedListModelIter<'_, T> {
+        self.model.iter()
+    }
+
+    pub fn append(&self, item: &T) {
+        self.store.append(item);
+    }
+
+    pub fn insert(&self, position: u32, item: &T) {
+        self.store.insert(position, item);
+    }
+
+    pub fn remove(&self, position: u32) {
+        self.store.remove(position);
+    }
+
+    pub fn remove_all(&self) {
+        self.store.remove_all();
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Appends `items` with a single `items-changed` emission.
+    pub fn extend_from_slice(&self, items: &[T]) {
+        self.splice(self.n_items(), 0, items);
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Removes `n_removals` items at `position` and inserts `additions` in
+    /// their place, with a single `items-changed` emission.
+    pub fn splice(&self, position: u32, n_removals: u32, additions: &[T]) {
+        let additions = additions
+            .iter()
+            .map(|item| item.upcast_ref::<glib::Object>().clone())
+            .collect::<Vec<_>>();
+        self.store.splice(position, n_removals, &additions);
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Removes the items for which `f` returns `false`, visiting them in
+    /// order.
+    pub fn retain<F: FnMut(&T) -> bool>(&self, mut f: F) {
+        let mut position = 0;
+        while let Some(item) = self.get(position) {
+            if f(&item) {
+                position += 1;
+            } else {
+                self.store.remove(position);
+            }
+        }
+    }
+}
+
+impl<T: IsA<glib::Object>> Default for TypedListStore<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for TypedListStore<T> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store.clone(),
+            model: self.model.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for TypedListStore<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TypedListStore")
+            .field("store", &self.store)
+            .finish()
+    }
+}
+
+impl<T> Deref for TypedListStore<T> {
+    type Target = gio::ListModel;
+
+    fn deref(&self) -> &gio::ListModel {
+        &self.model
+    }
+}
+
+impl<T> AsRef<gio::ListModel> for TypedListStore<T> {
+    fn as_ref(&self) -> &gio::ListModel {
+        &self.model
+    }
+}
+
+impl<'a, T: IsA<glib::Object>> IntoIterator for &'a TypedListStore<T> {
+    type Item = T;
+    type IntoIter = TypedListModelIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: IsA<glib::Object>> FromIterator<T> for TypedListStore<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let store = Self::new();
+        store.extend(iter);
+        store
+    }
+}
+
+impl<T: IsA<glib::Object>> Extend<T> for TypedListStore<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let items = iter.into_iter().collect::<Vec<_>>();
+        self.extend_from_slice(&items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(store: &TypedListStore<gio::SimpleAction>) -> Vec<String> {
+        store.iter().map(|a| a.get_name().to_string()).collect()
+    }
+
+    #[test]
+    fn typed_store() {
+        let mut store = ["a", "b", "c"]
+            .iter()
+            .map(|name| gio::SimpleAction::new(name, None))
+            .collect::<TypedListStore<_>>();
+        assert_eq!(names(&store), ["a", "b", "c"]);
+
+        store.insert(1, &gio::SimpleAction::new("d", None));
+        store.extend(vec![gio::SimpleAction::new("e", None)]);
+        assert_eq!(store.get(1).unwrap().get_name().as_str(), "d");
+        assert!(store.get(5).is_none());
+        assert_eq!(store.get_n_items(), 5);
+
+        store.retain(|a| a.get_name() != "b" && a.get_name() != "d");
+        assert_eq!(names(&store), ["a", "c", "e"]);
+
+        store.splice(0, 2, &[gio::SimpleAction::new("f", None)]);
+        assert_eq!(names(&store), ["f", "e"]);
+    }
+}
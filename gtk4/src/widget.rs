@@ -1,13 +1,18 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::prelude::WidgetExt;
-use crate::Widget;
+use crate::prelude::{GtkWindowExt, WidgetExt};
+use crate::{ApplicationWindow, Snapshot, Widget, WidgetPaintable, Window};
+use gdk::prelude::PaintableExt;
+use gio::prelude::ActionGroupExt;
+use gsk::prelude::RendererExt;
 
 use glib::object::{Cast, IsA, WeakRef};
 use glib::translate::*;
-use glib::ObjectExt;
+use glib::{ObjectExt, ToVariant};
 
 use glib::Continue;
+use std::ffi::CStr;
+use std::ptr;
 
 pub trait WidgetExtManual: 'static {
     #[doc(alias = "gtk_widget_add_tick_callback")]
@@ -17,6 +22,43 @@ pub trait WidgetExtManual: 'static {
     ) -> TickCallbackId;
 
     fn set_name(&self, name: &str);
+
+    // rustdoc-stripper-ignore-next
+    /// Iterates over the children of the widget, from the first one, or from
+    /// the last one with `rev()`.
+    ///
+    /// Siblings are looked up as the iteration goes, so the children added
+    /// or removed in the meantime are taken into account. Removing the
+    /// child that was just returned is fine, the iteration continues with
+    /// its former sibling.
+    fn children(&self) -> WidgetChildren;
+
+    // rustdoc-stripper-ignore-next
+    /// Renders the widget at its current size into a texture with
+    /// `renderer`, which has to be realized, like the renderer of its
+    /// `gtk::Native`.
+    ///
+    /// Returns `None` if the widget has no size, or draws nothing.
+    fn snapshot_to_texture<R: IsA<gsk::Renderer>>(&self, renderer: &R) -> Option<gdk::Texture>;
+
+    // rustdoc-stripper-ignore-next
+    /// Activates the action named `name`, looked up from the widget like
+    /// with [`activate_action_variant`], with `parameter` converted to a
+    /// `glib::Variant`.
+    ///
+    /// Fails if there is no action named `name`, or if it takes another type
+    /// of parameter than `T`. The parameter type is checked for the actions
+    /// installed in the widget classes and the `win` and `app` actions of the
+    /// windows, GTK doesn't give access to the ones of the action groups
+    /// added with [`insert_action_group`].
+    ///
+    /// [`activate_action_variant`]: trait.WidgetExt.html#tymethod.activate_action_variant
+    /// [`insert_action_group`]: trait.WidgetExt.html#tymethod.insert_action_group
+    fn activate_action_typed<T: ToVariant>(
+        &self,
+        name: &str,
+        parameter: &T,
+    ) -> Result<(), glib::BoolError>;
 }
 
 impl<O: IsA<Widget>> WidgetExtManual for O {
@@ -71,6 +113,187 @@ impl<O: IsA<Widget>> WidgetExtManual for O {
             ffi::gtk_widget_set_name(self.as_ref().to_glib_none().0, name.to_glib_none().0);
         }
     }
+
+    fn children(&self) -> WidgetChildren {
+        WidgetChildren {
+            parent: self.as_ref().clone(),
+            front: None,
+            back: None,
+            done: false,
+        }
+    }
+
+    fn snapshot_to_texture<R: IsA<gsk::Renderer>>(&self, renderer: &R) -> Option<gdk::Texture> {
+        let width = self.get_width();
+        let height = self.get_height();
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+
+        let paintable = WidgetPaintable::new(Some(self));
+        let snapshot = Snapshot::new();
+        paintable.snapshot(snapshot.upcast_ref(), width as f64, height as f64);
+        let node = snapshot.to_node()?;
+        renderer.render_texture(
+            &node,
+            Some(&graphene::Rect::new(0.0, 0.0, width as f32, height as f32)),
+        )
+    }
+
+    fn activate_action_typed<T: ToVariant>(
+        &self,
+        name: &str,
+        parameter: &T,
+    ) -> Result<(), glib::BoolError> {
+        let parameter = parameter.to_variant();
+        if let Some(parameter_type) = action_parameter_type(self.upcast_ref(), name) {
+            if parameter_type.as_deref() != Some(parameter.type_()) {
+                return Err(glib::bool_error!(
+                    "Action `{}` takes a parameter of type {}, not {}",
+                    name,
+                    parameter_type.as_ref().map_or("()", |t| t.to_str()),
+                    parameter.type_().to_str()
+                ));
+            }
+        }
+
+        if self.activate_action_variant(name, Some(&parameter)) {
+            Ok(())
+        } else {
+            Err(glib::bool_error!("Action `{}` not found", name))
+        }
+    }
+}
+
+// The parameter type of the action named `name` activated from `widget`, if
+// the action can be found. Actions are looked up like GTK does, from the
+// widget up to its root, in the actions installed in its class and then in
+// its action groups, of which only the `win` and `app` ones are accessible.
+fn action_parameter_type(widget: &Widget, name: &str) -> Option<Option<glib::VariantType>> {
+    let mut widget = Some(widget.clone());
+    while let Some(current) = widget {
+        unsafe {
+            let instance = current.as_ptr() as *mut glib::gobject_ffi::GTypeInstance;
+            let klass = (*instance).g_class as *mut ffi::GtkWidgetClass;
+            let mut index = 0;
+            loop {
+                let mut action_name = ptr::null();
+                let mut parameter_type = ptr::null();
+                let found: bool = from_glib(ffi::gtk_widget_class_query_action(
+                    klass,
+                    index,
+                    ptr::null_mut(),
+                    &mut action_name,
+                    &mut parameter_type,
+                    ptr::null_mut(),
+                ));
+                if !found {
+                    break;
+                }
+                if CStr::from_ptr(action_name).to_bytes() == name.as_bytes() {
+                    return Some(from_glib_none(parameter_type));
+                }
+                index += 1;
+            }
+        }
+
+        let mut split = name.splitn(2, '.');
+        if let (Some(prefix), Some(action)) = (split.next(), split.next()) {
+            let group = match prefix {
+                "win" => current
+                    .downcast_ref::<ApplicationWindow>()
+                    .map(|window| window.clone().upcast::<gio::ActionGroup>()),
+                "app" => current
+                    .downcast_ref::<Window>()
+                    .and_then(|window| window.get_application())
+                    .map(|application| application.upcast::<gio::ActionGroup>()),
+                _ => None,
+            };
+            if let Some(group) = group {
+                if group.has_action(action) {
+                    return Some(group.get_action_parameter_type(action));
+                }
+            }
+        }
+
+        widget = current.get_parent();
+    }
+    None
+}
+
+// rustdoc-stripper-ignore-next
+/// Iterator over the children of a widget, see
+/// [`WidgetExtManual::children`].
+///
+/// [`WidgetExtManual::children`]: prelude/trait.WidgetExtManual.html#tymethod.children
+#[derive(Debug)]
+pub struct WidgetChildren {
+    parent: Widget,
+    // The last child returned from each end, with the sibling it had then in
+    // case it gets removed.
+    front: Option<(Widget, Option<Widget>)>,
+    back: Option<(Widget, Option<Widget>)>,
+    done: bool,
+}
+
+impl WidgetChildren {
+    fn is_child(&self, widget: &Widget) -> bool {
+        widget.get_parent().as_ref() == Some(&self.parent)
+    }
+
+    fn step(
+        &mut self,
+        candidate: Option<Widget>,
+        sibling: impl Fn(&Widget) -> Option<Widget>,
+        other_end: Option<&Widget>,
+    ) -> Option<(Widget, Option<Widget>)> {
+        match candidate {
+            Some(widget) if other_end != Some(&widget) => {
+                let next = sibling(&widget);
+                Some((widget, next))
+            }
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl Iterator for WidgetChildren {
+    type Item = Widget;
+
+    fn next(&mut self) -> Option<Widget> {
+        if self.done {
+            return None;
+        }
+
+        let candidate = match self.front {
+            None => self.parent.get_first_child(),
+            Some((ref last, _)) if self.is_child(last) => last.get_next_sibling(),
+            Some((_, ref next)) => next.clone().filter(|w| self.is_child(w)),
+        };
+        let other_end = self.back.as_ref().map(|(w, _)| w.clone());
+        self.front = self.step(candidate, Widget::get_next_sibling, other_end.as_ref());
+        self.front.as_ref().map(|(w, _)| w.clone())
+    }
+}
+
+impl DoubleEndedIterator for WidgetChildren {
+    fn next_back(&mut self) -> Option<Widget> {
+        if self.done {
+            return None;
+        }
+
+        let candidate = match self.back {
+            None => self.parent.get_last_child(),
+            Some((ref last, _)) if self.is_child(last) => last.get_prev_sibling(),
+            Some((_, ref prev)) => prev.clone().filter(|w| self.is_child(w)),
+        };
+        let other_end = self.front.as_ref().map(|(w, _)| w.clone());
+        self.back = self.step(candidate, Widget::get_prev_sibling, other_end.as_ref());
+        self.back.as_ref().map(|(w, _)| w.clone())
+    }
 }
 
 pub struct TickCallbackId {
@@ -0,0 +1,44 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::accessible::{Property, State};
+use gtk::glib;
+use gtk::prelude::*;
+use gtk4 as gtk;
+
+#[test]
+fn accessible() {
+    crate::test_synced(|| {
+        // GTK reads the values back with the getter of the type it expects and
+        // only emits a critical on a mismatch, make it abort the test instead.
+        let fatal_mask = unsafe {
+            glib::ffi::g_log_set_always_fatal(
+                glib::ffi::G_LOG_FATAL_MASK | glib::ffi::G_LOG_LEVEL_CRITICAL,
+            )
+        };
+
+        let entry = gtk::Entry::new();
+        entry.update_property(&[
+            Property::Autocomplete(gtk::AccessibleAutocomplete::Both),
+            Property::Label("Search"),
+            Property::Level(2),
+            Property::MultiLine(false),
+            Property::Orientation(gtk::Orientation::Vertical),
+            Property::Sort(gtk::AccessibleSort::Descending),
+            Property::ValueNow(0.5),
+        ]);
+
+        let button = gtk::ToggleButton::new();
+        button.update_state(&[
+            State::Busy(true),
+            State::Checked(gtk::AccessibleTristate::Mixed),
+            State::Expanded(Some(true)),
+            State::Invalid(gtk::AccessibleInvalidState::Spelling),
+            State::Pressed(gtk::AccessibleTristate::True),
+            State::Selected(None),
+        ]);
+
+        unsafe {
+            glib::ffi::g_log_set_always_fatal(fatal_mask);
+        }
+    });
+}
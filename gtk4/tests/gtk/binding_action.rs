@@ -0,0 +1,117 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::gdk;
+use gtk::gio;
+use gtk::glib;
+use gtk::glib::StaticVariantType;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{ShortcutActionFlags, ShortcutController, ShortcutTrigger};
+use gtk4 as gtk;
+use std::cell::Cell;
+use std::rc::Rc;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct BoundWidget;
+
+    impl ObjectSubclass for BoundWidget {
+        const NAME: &'static str = "TestBoundWidget";
+        type Type = super::BoundWidget;
+        type ParentType = gtk::Widget;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.add_binding_action_with_arg(
+                gdk::keys::constants::plus,
+                gdk::ModifierType::CONTROL_MASK,
+                "test.add",
+                Some(&2i32),
+            );
+            klass.add_binding_action(
+                gdk::keys::constants::_0,
+                gdk::ModifierType::CONTROL_MASK,
+                "test.reset",
+            );
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for BoundWidget {}
+    impl WidgetImpl for BoundWidget {}
+}
+
+glib::wrapper! {
+    pub struct BoundWidget(ObjectSubclass<imp::BoundWidget>)
+        @extends gtk::Widget;
+}
+
+// Activates the class shortcut triggered by `accelerator` the way GTK does on
+// a key press.
+fn activate_shortcut(widget: &BoundWidget, accelerator: &str) -> bool {
+    let trigger = ShortcutTrigger::parse_string(accelerator).unwrap();
+    let controllers = widget.observe_controllers();
+    for i in 0..controllers.get_n_items() {
+        let controller = match controllers
+            .get_object(i)
+            .and_then(|object| object.downcast::<ShortcutController>().ok())
+        {
+            Some(controller) => controller,
+            None => continue,
+        };
+        for j in 0..controller.get_n_items() {
+            let shortcut = controller
+                .get_object(j)
+                .unwrap()
+                .downcast::<gtk::Shortcut>()
+                .unwrap();
+            if shortcut.get_trigger().unwrap().equal(&trigger) {
+                return shortcut.get_action().unwrap().activate(
+                    ShortcutActionFlags::empty(),
+                    widget,
+                    shortcut.get_arguments().as_ref(),
+                );
+            }
+        }
+    }
+    false
+}
+
+#[test]
+fn binding_action() {
+    crate::test_synced(|| {
+        let sum = Rc::new(Cell::new(0));
+        let group = gio::SimpleActionGroup::new();
+        let add = gio::SimpleAction::new("add", Some(&i32::static_variant_type()));
+        let sum_clone = sum.clone();
+        add.connect_activate(move |_, parameter| {
+            let value = parameter.unwrap().get::<i32>().unwrap();
+            sum_clone.set(sum_clone.get() + value);
+        });
+        group.add_action(&add);
+        let reset = gio::SimpleAction::new("reset", None);
+        let sum_clone = sum.clone();
+        reset.connect_activate(move |_, parameter| {
+            assert!(parameter.is_none());
+            sum_clone.set(0);
+        });
+        group.add_action(&reset);
+
+        let widget: BoundWidget = glib::Object::new(&[]).expect("Failed to create BoundWidget");
+        widget.insert_action_group("test", Some(&group));
+
+        assert!(activate_shortcut(&widget, "<Control>plus"));
+        assert!(activate_shortcut(&widget, "<Control>plus"));
+        assert_eq!(sum.get(), 4);
+        assert!(activate_shortcut(&widget, "<Control>0"));
+        assert_eq!(sum.get(), 0);
+    });
+}
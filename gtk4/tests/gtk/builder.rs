@@ -0,0 +1,42 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{glib, Builder, BuilderObjectError};
+use gtk4 as gtk;
+
+const UI: &str = r#"
+<interface>
+  <object class="GtkLabel" id="label">
+    <property name="label">Label</property>
+  </object>
+</interface>
+"#;
+
+#[test]
+fn builder_objects() {
+    crate::test_synced(|| {
+        assert!(Builder::from_string("<interface><object></interface>").is_err());
+
+        let builder = Builder::from_string(UI).unwrap();
+        let label: gtk::Label = builder.try_object("label").unwrap();
+        assert_eq!(label.get_label(), "Label");
+        assert!(builder.get_object::<gtk::Label>("label").is_some());
+        assert!(builder.get_object::<gtk::Button>("label").is_none());
+
+        assert_eq!(
+            builder.try_object::<gtk::Label>("button").unwrap_err(),
+            BuilderObjectError::NotFound {
+                id: "button".to_owned()
+            }
+        );
+        assert_eq!(
+            builder.try_object::<gtk::Button>("label").unwrap_err(),
+            BuilderObjectError::WrongType {
+                id: "label".to_owned(),
+                expected: gtk::Button::static_type(),
+                actual: gtk::Label::static_type(),
+            }
+        );
+        let _: glib::Object = builder.try_object("label").unwrap();
+    });
+}
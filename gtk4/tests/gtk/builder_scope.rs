@@ -0,0 +1,136 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{glib, Builder, BuilderClosureFlags, BuilderError};
+use gtk4 as gtk;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+type Handler = Arc<dyn Fn(&[glib::Value]) -> Option<glib::Value> + Send + Sync>;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default)]
+    pub struct RegistryScope {
+        pub handlers: RefCell<HashMap<String, Handler>>,
+    }
+
+    impl ObjectSubclass for RegistryScope {
+        const NAME: &'static str = "TestRegistryScope";
+        type Type = super::RegistryScope;
+        type ParentType = glib::Object;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn type_init(type_: &mut glib::subclass::InitializingType<Self>) {
+            type_.add_interface::<gtk::BuilderScope>();
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for RegistryScope {}
+
+    impl BuilderScopeImpl for RegistryScope {
+        fn get_type_from_name(
+            &self,
+            builder_scope: &Self::Type,
+            builder: &Builder,
+            type_name: &str,
+        ) -> glib::Type {
+            match type_name {
+                "Label" => gtk::Label::static_type(),
+                _ => self.parent_get_type_from_name(builder_scope, builder, type_name),
+            }
+        }
+
+        fn create_closure(
+            &self,
+            _builder_scope: &Self::Type,
+            _builder: &Builder,
+            function_name: &str,
+            _flags: BuilderClosureFlags,
+            _object: Option<&glib::Object>,
+        ) -> Result<glib::Closure, glib::Error> {
+            let handler = self
+                .handlers
+                .borrow()
+                .get(function_name)
+                .cloned()
+                .ok_or_else(|| glib::Error::new(BuilderError::InvalidFunction, function_name))?;
+            Ok(glib::Closure::new(move |args| handler(args)))
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct RegistryScope(ObjectSubclass<imp::RegistryScope>)
+        @implements gtk::BuilderScope;
+}
+
+impl RegistryScope {
+    fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create RegistryScope")
+    }
+
+    fn add_handler<F: Fn(&[glib::Value]) -> Option<glib::Value> + Send + Sync + 'static>(
+        &self,
+        name: &str,
+        handler: F,
+    ) {
+        imp::RegistryScope::from_instance(self)
+            .handlers
+            .borrow_mut()
+            .insert(name.to_owned(), Arc::new(handler));
+    }
+}
+
+const UI: &str = r#"
+<interface>
+  <object class="GtkBox">
+    <child>
+      <object class="Label" id="label"/>
+    </child>
+    <child>
+      <object class="GtkButton" id="button">
+        <signal name="clicked" handler="button_clicked"/>
+      </object>
+    </child>
+  </object>
+</interface>
+"#;
+
+#[test]
+fn builder_scope() {
+    crate::test_synced(|| {
+        let scope = RegistryScope::new();
+        let clicks = Arc::new(AtomicU32::new(0));
+        let clicks_clone = clicks.clone();
+        scope.add_handler("button_clicked", move |_| {
+            clicks_clone.fetch_add(1, Ordering::SeqCst);
+            None
+        });
+
+        let builder = Builder::new();
+        builder.set_scope(Some(&scope));
+        builder.add_from_string(UI).unwrap();
+        assert!(builder.get_object::<gtk::Label>("label").is_some());
+
+        let button: gtk::Button = builder.get_object("button").unwrap();
+        button.emit_clicked();
+        assert_eq!(clicks.load(Ordering::SeqCst), 1);
+
+        // Unknown handlers fail to build.
+        let builder = Builder::new();
+        builder.set_scope(Some(&RegistryScope::new()));
+        assert!(builder.add_from_string(UI).is_err());
+    });
+}
@@ -0,0 +1,61 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{gdk, glib};
+use gtk4 as gtk;
+
+const TEXT: &str = "Copied from gtk-rs";
+
+#[test]
+fn clipboard() {
+    crate::test_synced(|| {
+        let display = gdk::Display::get_default().expect("No default display");
+        let clipboard = display.get_clipboard();
+        let context = glib::MainContext::default();
+
+        // The content set by the process itself is read back without going
+        // through the windowing system.
+        clipboard.set_text(TEXT);
+        let text = context
+            .block_on(clipboard.read_text_async_future())
+            .unwrap();
+        assert_eq!(text.as_deref(), Some(TEXT));
+
+        let value = context
+            .block_on(clipboard.read_value_async_future(glib::Type::String, glib::PRIORITY_DEFAULT))
+            .unwrap();
+        assert_eq!(value.get::<String>().unwrap().as_deref(), Some(TEXT));
+
+        let (stream, mime_type) = context
+            .block_on(clipboard.read_async_future(
+                &["application/x-unknown", "text/plain;charset=utf-8"],
+                glib::PRIORITY_DEFAULT,
+            ))
+            .unwrap();
+        assert_eq!(mime_type, "text/plain;charset=utf-8");
+        let bytes = context
+            .block_on(stream.read_bytes_async_future(1024, glib::PRIORITY_DEFAULT))
+            .unwrap();
+        assert_eq!(&*bytes, TEXT.as_bytes());
+
+        assert!(context
+            .block_on(
+                clipboard.read_async_future(&["application/x-unknown"], glib::PRIORITY_DEFAULT)
+            )
+            .is_err());
+
+        let texture = gdk::MemoryTexture::new(
+            2,
+            3,
+            gdk::MemoryFormat::R8g8b8a8,
+            &glib::Bytes::from_owned(vec![255; 2 * 3 * 4]),
+            2 * 4,
+        );
+        clipboard.set_texture(&texture);
+        let pasted = context
+            .block_on(clipboard.read_texture_async_future())
+            .unwrap()
+            .expect("No texture on the clipboard");
+        assert_eq!((pasted.get_width(), pasted.get_height()), (2, 3));
+    });
+}
@@ -0,0 +1,65 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{ConstraintAttribute, ConstraintRelation, ConstraintStrength};
+use gtk4 as gtk;
+
+#[test]
+fn constraint_layout() {
+    crate::test_synced(|| {
+        let label = gtk::Label::new(None);
+        let constraint = gtk::Constraint::new_constant(
+            Some(&label),
+            ConstraintAttribute::Width,
+            ConstraintRelation::Ge,
+            100.0,
+            ConstraintStrength::Strong,
+        );
+        assert_eq!(constraint.get_strength(), ConstraintStrength::Strong);
+
+        let strength = ConstraintStrength::custom(ConstraintStrength::Medium.value() + 1);
+        let constraint = gtk::Constraint::new_constant(
+            Some(&label),
+            ConstraintAttribute::Height,
+            ConstraintRelation::Eq,
+            50.0,
+            strength,
+        );
+        assert_eq!(constraint.get_strength(), strength);
+        assert_eq!(strength.value(), 1001);
+        assert_eq!(ConstraintStrength::custom(1000), ConstraintStrength::Medium);
+
+        let guide = gtk::ConstraintGuide::new();
+        guide.set_min_size(10, 20);
+        guide.set_nat_size(30, 40);
+        guide.set_max_size(50, 60);
+        assert_eq!(guide.get_min_size(), (10, 20));
+        assert_eq!(guide.get_nat_size(), (30, 40));
+        assert_eq!(guide.get_max_size(), (50, 60));
+
+        let layout = gtk::ConstraintLayout::new();
+        let button = gtk::Button::new();
+        layout.add_guide(&guide);
+        let constraints = layout
+            .add_constraints_from_description(
+                &["H:|-[label]-[guide]-[button]-|"],
+                8,
+                8,
+                &[
+                    ("label", label.upcast_ref()),
+                    ("guide", guide.upcast_ref()),
+                    ("button", button.upcast_ref()),
+                ],
+            )
+            .unwrap();
+        assert!(!constraints.is_empty());
+        assert!(layout
+            .add_constraints_from_description(
+                &["H:|-[unknown]-|"],
+                8,
+                8,
+                &[("label", label.upcast_ref())]
+            )
+            .is_err());
+    });
+}
@@ -0,0 +1,42 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::style::{add_css, StyleProviderPriority};
+use gtk::CssProvider;
+use gtk4 as gtk;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn css_provider_checked() {
+    crate::test_synced(|| {
+        let provider = CssProvider::new();
+        assert!(provider
+            .load_from_data_checked(b"label { color: red; }")
+            .is_ok());
+        assert!(provider
+            .load_from_data_checked(b"label { colour: red; }")
+            .is_err());
+        assert!(provider
+            .load_from_path_checked("/nonexistent/style.css")
+            .is_err());
+        assert!(provider
+            .load_from_resource_checked("/org/gtk-rs/nonexistent/style.css")
+            .is_err());
+
+        // The sections of the errors locate them, the lines start from 0.
+        let locations = Rc::new(RefCell::new(Vec::new()));
+        let locations_clone = locations.clone();
+        provider.connect_parsing_error(move |_, section, _| {
+            let start = section.get_start_location().unwrap();
+            locations_clone.borrow_mut().push(start.get_lines());
+        });
+        provider.load_from_data(b"label { color: red; }\nbox {\n  colour: red;\n}");
+        assert_eq!(*locations.borrow(), [2]);
+
+        let provider =
+            add_css("label { color: red; }", StyleProviderPriority::Application).unwrap();
+        let display = gtk::gdk::Display::get_default().unwrap();
+        gtk::StyleContext::remove_provider_for_display(&display, &provider);
+        assert!(add_css("label { colour: red; }", StyleProviderPriority::User).is_err());
+    });
+}
@@ -0,0 +1,108 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk4 as gtk;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct ResizingArea {
+        pub chain_up: Cell<bool>,
+        pub sizes: RefCell<Vec<(i32, i32)>>,
+    }
+
+    impl ObjectSubclass for ResizingArea {
+        const NAME: &'static str = "TestResizingArea";
+        type Type = super::ResizingArea;
+        type ParentType = gtk::DrawingArea;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self::Type>) {
+            let area = unsafe { obj.as_ref() };
+            area.set_draw_func(|_, cr, _, _| cr.paint());
+        }
+    }
+
+    impl ObjectImpl for ResizingArea {}
+    impl WidgetImpl for ResizingArea {}
+
+    impl DrawingAreaImpl for ResizingArea {
+        fn resize(&self, area: &Self::Type, width: i32, height: i32) {
+            self.sizes.borrow_mut().push((width, height));
+            if self.chain_up.get() {
+                self.parent_resize(area, width, height);
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct ResizingArea(ObjectSubclass<imp::ResizingArea>)
+        @extends gtk::Widget, gtk::DrawingArea;
+}
+
+impl ResizingArea {
+    fn new(chain_up: bool) -> Self {
+        let area: Self = glib::Object::new(&[]).expect("Failed to create ResizingArea");
+        imp::ResizingArea::from_instance(&area)
+            .chain_up
+            .set(chain_up);
+        area
+    }
+
+    fn sizes(&self) -> Vec<(i32, i32)> {
+        imp::ResizingArea::from_instance(self)
+            .sizes
+            .borrow()
+            .clone()
+    }
+}
+
+// Allocates a size to the area, which emits `resize`.
+fn allocate(area: &ResizingArea, width: i32, height: i32) {
+    area.measure(gtk::Orientation::Horizontal, -1);
+    area.measure(gtk::Orientation::Vertical, width);
+    area.size_allocate(
+        &gtk::Allocation {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        },
+        -1,
+    );
+}
+
+#[test]
+fn drawing_area() {
+    crate::test_synced(|| {
+        for &chain_up in &[true, false] {
+            let area = ResizingArea::new(chain_up);
+            // `resize` runs the handlers of the instance before the class
+            // handler, with or without chaining up to GtkDrawingArea.
+            let handled = Rc::new(RefCell::new(Vec::new()));
+            let handled_clone = handled.clone();
+            area.connect_resize(move |area, width, height| {
+                handled_clone
+                    .borrow_mut()
+                    .push((width, height, area.sizes().len()));
+            });
+
+            allocate(&area, 120, 80);
+            assert_eq!(*handled.borrow(), [(120, 80, 0)]);
+            assert_eq!(area.sizes(), [(120, 80)]);
+        }
+    });
+}
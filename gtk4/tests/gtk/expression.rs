@@ -0,0 +1,89 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{gio, glib};
+use gtk4 as gtk;
+use std::cell::Cell;
+use std::rc::Rc;
+
+fn adjustment(value: f64) -> gtk::Adjustment {
+    gtk::Adjustment::new(value, 0.0, 100.0, 1.0, 10.0, 0.0)
+}
+
+fn values(model: &gio::ListModel) -> Vec<f64> {
+    (0..model.get_n_items())
+        .map(|i| {
+            model
+                .get_object(i)
+                .unwrap()
+                .downcast::<gtk::Adjustment>()
+                .unwrap()
+                .get_value()
+        })
+        .collect()
+}
+
+#[test]
+fn expressions() {
+    crate::test_synced(|| {
+        let store = gio::ListStore::new(gtk::Adjustment::static_type());
+        for value in &[3.0, 1.0, 2.0] {
+            store.append(&adjustment(*value));
+        }
+
+        let value_expression =
+            gtk::PropertyExpression::new(gtk::Adjustment::static_type(), None, "value");
+        assert_eq!(value_expression.get_value_type(), f64::static_type());
+        assert_eq!(value_expression.get_pspec().get_name(), "value");
+
+        let sorter = gtk::NumericSorter::new(Some(&value_expression));
+        let sorted = gtk::SortListModel::new(Some(&store), Some(&sorter));
+        assert_eq!(values(sorted.upcast_ref()), [1.0, 2.0, 3.0]);
+
+        sorter.set_sort_order(gtk::SortType::Descending);
+        assert_eq!(values(sorted.upcast_ref()), [3.0, 2.0, 1.0]);
+
+        // Sorting on the negated value through a closure expression.
+        let negated = gtk::ClosureExpression::new(&[&value_expression], |values| {
+            -values[1].get_some::<f64>().unwrap()
+        });
+        sorter.set_expression(Some(&negated));
+        sorter.set_sort_order(gtk::SortType::Ascending);
+        assert_eq!(values(sorted.upcast_ref()), [3.0, 2.0, 1.0]);
+
+        let first = adjustment(5.0);
+        let value = negated.evaluate(Some(&first)).unwrap();
+        assert_eq!(value.get_some::<f64>().unwrap(), -5.0);
+        // No object to look the property up on.
+        assert!(negated.evaluate(None::<&glib::Object>).is_none());
+
+        let constant = gtk::ConstantExpression::new(&42.0f64);
+        assert!(constant.is_static());
+        assert_eq!(constant.get_value().get_some::<f64>().unwrap(), 42.0);
+
+        // Binding keeps the target property in sync.
+        let target = adjustment(0.0);
+        let watch = value_expression.bind(&target, "value", Some(&first));
+        assert_eq!(target.get_value(), 5.0);
+        first.set_value(7.0);
+        assert_eq!(target.get_value(), 7.0);
+        watch.unwatch();
+        first.set_value(9.0);
+        assert_eq!(target.get_value(), 7.0);
+
+        // Dropping a watch keeps notifying, until a clone of it is unwatched.
+        let notified = Rc::new(Cell::new(0));
+        let watch = {
+            let notified = notified.clone();
+            value_expression.watch(Some(&first), move || notified.set(notified.get() + 1))
+        };
+        let clone = watch.clone();
+        drop(watch);
+        first.set_value(11.0);
+        assert_eq!(notified.get(), 1);
+        assert_eq!(clone.evaluate().unwrap().get_some::<f64>().unwrap(), 11.0);
+        clone.unwatch();
+        first.set_value(13.0);
+        assert_eq!(notified.get(), 1);
+    });
+}
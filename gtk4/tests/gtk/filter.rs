@@ -0,0 +1,96 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{gio, glib};
+use gtk4 as gtk;
+use std::cell::Cell;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct MaxValueFilter {
+        pub max: Cell<f64>,
+    }
+
+    impl ObjectSubclass for MaxValueFilter {
+        const NAME: &'static str = "TestMaxValueFilter";
+        type Type = super::MaxValueFilter;
+        type ParentType = gtk::Filter;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for MaxValueFilter {}
+
+    impl FilterImpl for MaxValueFilter {
+        fn match_(&self, _filter: &Self::Type, item: &glib::Object) -> bool {
+            let adjustment = item.downcast_ref::<gtk::Adjustment>().unwrap();
+            adjustment.get_value() <= self.max.get()
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct MaxValueFilter(ObjectSubclass<imp::MaxValueFilter>) @extends gtk::Filter;
+}
+
+impl MaxValueFilter {
+    fn new(max: f64) -> Self {
+        let filter: Self = glib::Object::new(&[]).expect("Failed to create MaxValueFilter");
+        filter.set_max(max);
+        filter
+    }
+
+    fn set_max(&self, max: f64) {
+        let imp = imp::MaxValueFilter::from_instance(self);
+        let change = if max < imp.max.get() {
+            gtk::FilterChange::MoreStrict
+        } else {
+            gtk::FilterChange::LessStrict
+        };
+        imp.max.set(max);
+        self.changed(change);
+    }
+}
+
+fn values(model: &gtk::FilterListModel) -> Vec<f64> {
+    (0..model.get_n_items())
+        .map(|i| {
+            model
+                .get_object(i)
+                .unwrap()
+                .downcast::<gtk::Adjustment>()
+                .unwrap()
+                .get_value()
+        })
+        .collect()
+}
+
+#[test]
+fn filter_subclass() {
+    crate::test_synced(|| {
+        let store = gio::ListStore::new(gtk::Adjustment::static_type());
+        for value in &[1.0, 4.0, 2.0, 3.0] {
+            store.append(&gtk::Adjustment::new(*value, 0.0, 10.0, 1.0, 1.0, 0.0));
+        }
+
+        let filter = MaxValueFilter::new(2.0);
+        let model = gtk::FilterListModel::new(Some(&store), Some(&filter));
+        assert_eq!(filter.get_strictness(), gtk::FilterMatch::Some);
+        assert_eq!(values(&model), [1.0, 2.0]);
+
+        filter.set_max(3.0);
+        assert_eq!(values(&model), [1.0, 2.0, 3.0]);
+
+        filter.set_max(1.0);
+        assert_eq!(values(&model), [1.0]);
+    });
+}
@@ -0,0 +1,47 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::gestures::{CanvasGestures, CanvasTransform};
+use gtk::prelude::*;
+use gtk4 as gtk;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn gestures() {
+    crate::test_synced(|| {
+        let area = gtk::DrawingArea::new();
+        let gestures = CanvasGestures::new(&area);
+        assert_eq!(gestures.get_transform(), CanvasTransform::default());
+
+        let changes = Rc::new(Cell::new(0));
+        gestures.connect_transform_changed({
+            let changes = changes.clone();
+            move |_| changes.set(changes.get() + 1)
+        });
+        let transform = CanvasTransform {
+            offset_x: 10.0,
+            offset_y: 20.0,
+            scale: 2.0,
+            angle: std::f64::consts::FRAC_PI_2,
+        };
+        gestures.set_transform(transform);
+        assert_eq!(gestures.get_transform(), transform);
+        assert_eq!(changes.get(), 1);
+        // Setting the same transformation again changes nothing.
+        gestures.set_transform(transform);
+        assert_eq!(changes.get(), 1);
+
+        gestures.set_scale_range(0.5, 1.5);
+        assert_eq!(gestures.get_transform().scale, 1.5);
+        assert_eq!(changes.get(), 2);
+
+        gestures.reset();
+        assert_eq!(gestures.get_transform(), CanvasTransform::default());
+        assert_eq!(changes.get(), 3);
+
+        // The controllers are removed with the gestures.
+        let count = area.observe_controllers().get_n_items();
+        drop(gestures);
+        assert_eq!(area.observe_controllers().get_n_items(), count - 6);
+    });
+}
@@ -0,0 +1,84 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::gio;
+use gtk::gio_actions::{
+    action_group_from_entries, add_action_typed, add_stateful_action_typed, ActionEntry,
+};
+use gtk::glib::{StaticVariantType, ToVariant};
+use gtk::prelude::*;
+use gtk4 as gtk;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+#[test]
+fn gio_actions() {
+    crate::test_synced(|| {
+        let group = gio::SimpleActionGroup::new();
+
+        let sum = Rc::new(Cell::new(0));
+        let sum_clone = sum.clone();
+        add_action_typed(&group, "add", move |value: i32| {
+            sum_clone.set(sum_clone.get() + value)
+        });
+        group.activate_action("add", Some(&2i32.to_variant()));
+        assert_eq!(sum.get(), 2);
+
+        // Booleans are toggled.
+        let dark_mode = add_stateful_action_typed(&group, "dark-mode", false, |_: bool| true);
+        assert!(dark_mode.get_parameter_type().is_none());
+        group.activate_action("dark-mode", None);
+        assert_eq!(dark_mode.get_state(), Some(true.to_variant()));
+
+        // The new state can be refused.
+        let modes = Rc::new(RefCell::new(Vec::new()));
+        let modes_clone = modes.clone();
+        let mode =
+            add_stateful_action_typed(&group, "mode", "a".to_owned(), move |mode: String| {
+                let accepted = mode != "c";
+                modes_clone.borrow_mut().push(mode);
+                accepted
+            });
+        group.activate_action("mode", Some(&"b".to_variant()));
+        group.activate_action("mode", Some(&"c".to_variant()));
+        assert_eq!(*modes.borrow(), ["b", "c"]);
+        assert_eq!(mode.get_state(), Some("b".to_variant()));
+
+        // Without handlers, boolean states are toggled and the other ones set to
+        // the parameter.
+        let activations = Rc::new(Cell::new(0));
+        let activations_clone = activations.clone();
+        let entries = action_group_from_entries(vec![
+            ActionEntry::builder("count")
+                .activate(move |_, _| activations_clone.set(activations_clone.get() + 1))
+                .build(),
+            ActionEntry::builder("visible")
+                .state(false.to_variant())
+                .build(),
+            ActionEntry::builder("size")
+                .parameter_type(&i32::static_variant_type())
+                .state(1i32.to_variant())
+                .change_state(|action, value| {
+                    let value = value.unwrap();
+                    if value.get::<i32>().unwrap() > 0 {
+                        action.set_state(value);
+                    }
+                })
+                .build(),
+        ]);
+        entries.activate_action("count", None);
+        assert_eq!(activations.get(), 1);
+        entries.activate_action("visible", None);
+        assert_eq!(entries.get_action_state("visible"), Some(true.to_variant()));
+        entries.activate_action("size", Some(&3i32.to_variant()));
+        entries.activate_action("size", Some(&(-3i32).to_variant()));
+        assert_eq!(entries.get_action_state("size"), Some(3i32.to_variant()));
+
+        let window = gtk::Window::new();
+        window.insert_action_group("test", Some(&group));
+        window.activate_action_typed("test.add", &3i32).unwrap();
+        assert_eq!(sum.get(), 5);
+        assert!(window.activate_action_typed("test.remove", &3i32).is_err());
+        // The `window.close` action of the window class takes no parameter.
+        assert!(window.activate_action_typed("window.close", &3i32).is_err());
+    });
+}
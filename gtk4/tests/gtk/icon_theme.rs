@@ -0,0 +1,44 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{gdk, IconLookupFlags, IconTheme, TextDirection};
+use gtk4 as gtk;
+
+#[test]
+fn icon_theme() {
+    crate::test_synced(|| {
+        let display = gdk::Display::get_default().unwrap();
+        let theme = IconTheme::get_for_display(&display);
+        let names = theme.get_icon_names();
+        let name = match names.first() {
+            Some(name) => name,
+            // No icons installed.
+            None => return,
+        };
+        assert!(theme.has_icon(name));
+
+        for scale in &[1, 2] {
+            let icon = theme.lookup_icon(
+                name,
+                &[],
+                16,
+                *scale,
+                TextDirection::Ltr,
+                IconLookupFlags::empty(),
+            );
+            let fallback = theme.lookup_icon(
+                "gtk-rs-nonexistent-icon",
+                &[name],
+                16,
+                *scale,
+                TextDirection::Ltr,
+                IconLookupFlags::empty(),
+            );
+            assert_eq!(icon.get_icon_name(), fallback.get_icon_name());
+            assert_eq!(
+                icon.get_file().map(|file| file.get_uri()),
+                fallback.get_file().map(|file| file.get_uri())
+            );
+        }
+    });
+}
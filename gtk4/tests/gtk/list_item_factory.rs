@@ -0,0 +1,100 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk4 as gtk;
+use std::cell::RefCell;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct LoggingFactory {
+        pub log: RefCell<Vec<String>>,
+    }
+
+    impl ObjectSubclass for LoggingFactory {
+        const NAME: &'static str = "TestLoggingFactory";
+        type Type = super::LoggingFactory;
+        type ParentType = gtk::SignalListItemFactory;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for LoggingFactory {}
+    impl ListItemFactoryImpl for LoggingFactory {}
+
+    impl SignalListItemFactoryImpl for LoggingFactory {
+        fn setup(&self, factory: &Self::Type, list_item: &gtk::ListItem) {
+            self.log.borrow_mut().push("class setup".to_string());
+            self.parent_setup(factory, list_item);
+        }
+
+        fn bind(&self, factory: &Self::Type, list_item: &gtk::ListItem) {
+            self.log.borrow_mut().push("class bind".to_string());
+            self.parent_bind(factory, list_item);
+        }
+
+        fn unbind(&self, factory: &Self::Type, list_item: &gtk::ListItem) {
+            self.log.borrow_mut().push("class unbind".to_string());
+            self.parent_unbind(factory, list_item);
+        }
+
+        fn teardown(&self, factory: &Self::Type, list_item: &gtk::ListItem) {
+            self.log.borrow_mut().push("class teardown".to_string());
+            self.parent_teardown(factory, list_item);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct LoggingFactory(ObjectSubclass<imp::LoggingFactory>)
+        @extends gtk::ListItemFactory, gtk::SignalListItemFactory;
+}
+
+impl LoggingFactory {
+    fn log(&self, entry: &str) {
+        imp::LoggingFactory::from_instance(self)
+            .log
+            .borrow_mut()
+            .push(entry.to_string());
+    }
+
+    fn take_log(&self) -> Vec<String> {
+        imp::LoggingFactory::from_instance(self).log.take()
+    }
+}
+
+#[test]
+fn list_item_factory() {
+    crate::test_synced(|| {
+        let factory: LoggingFactory =
+            glib::Object::new(&[]).expect("Failed to create LoggingFactory");
+        let list_item: gtk::ListItem = glib::Object::new(&[]).expect("Failed to create ListItem");
+
+        for &signal in &["setup", "bind", "unbind", "teardown"] {
+            let factory_clone = factory.clone();
+            factory
+                .connect_local(signal, false, move |_| {
+                    factory_clone.log("handler");
+                    None
+                })
+                .unwrap();
+
+            factory.emit(signal, &[&list_item]).unwrap();
+            // The class handler chains up to the one of GtkSignalListItemFactory
+            // and runs before the handlers connected to the instance.
+            assert_eq!(
+                factory.take_log(),
+                [format!("class {}", signal), "handler".to_string()]
+            );
+        }
+    });
+}
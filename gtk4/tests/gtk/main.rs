@@ -0,0 +1,66 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! The tests needing GTK to be initialized.
+
+use gtk4 as gtk;
+use once_cell::sync::Lazy;
+use std::panic;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+mod accessible;
+mod binding_action;
+mod builder;
+mod builder_scope;
+mod clipboard;
+mod constraint_layout;
+mod css_provider;
+mod drawing_area;
+mod expression;
+mod filter;
+mod gestures;
+mod gio_actions;
+mod icon_theme;
+mod list_item_factory;
+mod media_stream;
+mod property_binding;
+mod settings;
+mod shortcut_controller;
+mod sorter;
+mod string_list;
+mod subclass_chaining;
+mod template_callbacks;
+mod text_buffer;
+mod text_buffer_subclass;
+mod widget_children;
+mod widget_css;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+// GTK can only be used from the thread that initialized it, while the test
+// harness runs each test on a thread of its own.
+static GTK_THREAD: Lazy<Mutex<mpsc::Sender<Job>>> = Lazy::new(|| {
+    let (sender, receiver) = mpsc::channel::<Job>();
+    thread::spawn(move || {
+        gtk::init().expect("Failed to initialize GTK");
+        for job in receiver {
+            job();
+        }
+    });
+    Mutex::new(sender)
+});
+
+// Runs `f` on the thread GTK is initialized on, and forwards its panics.
+pub fn test_synced<F: FnOnce() + Send + 'static>(f: F) {
+    let (sender, receiver) = mpsc::channel();
+    GTK_THREAD
+        .lock()
+        .unwrap()
+        .send(Box::new(move || {
+            let _ = sender.send(panic::catch_unwind(panic::AssertUnwindSafe(f)));
+        }))
+        .expect("The GTK thread stopped");
+    if let Err(payload) = receiver.recv().expect("The GTK thread stopped") {
+        panic::resume_unwind(payload);
+    }
+}
@@ -0,0 +1,27 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::{gio, glib};
+use gtk4 as gtk;
+
+#[test]
+fn media_stream() {
+    crate::test_synced(|| {
+        let stream = gtk::MediaFile::new();
+        assert!(stream.get_error().is_none());
+
+        stream.gerror(glib::Error::new(gio::IOErrorEnum::Failed, "first"));
+        // Only the first error is kept.
+        stream.gerror(glib::Error::new(gio::IOErrorEnum::Failed, "second"));
+
+        let error = stream.get_error().unwrap();
+        assert!(error.matches(gio::IOErrorEnum::Failed));
+        assert_eq!(error.to_string(), "first");
+
+        let path = std::path::Path::new("/nonexistent/video.webm");
+        let file = gtk::MediaFile::new_for_filename(path);
+        assert_eq!(file.get_file().unwrap().get_path().as_deref(), Some(path));
+        file.set_filename(None::<&std::path::Path>);
+        assert!(file.get_file().is_none());
+    });
+}
@@ -0,0 +1,81 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::glib;
+use gtk::prelude::*;
+use gtk4 as gtk;
+use std::panic::{self, AssertUnwindSafe};
+
+#[test]
+fn property_binding() {
+    crate::test_synced(|| {
+        let spin_button = gtk::SpinButton::with_range(0.0, 100.0, 1.0);
+        spin_button.set_value(5.0);
+        let entry = gtk::Entry::new();
+
+        let binding = spin_button
+            .bind_property_builder("value", &entry, "text")
+            .transform_to(|value: f64| format!("{:.1}", value))
+            .transform_from(|text: String| text.parse::<f64>().unwrap_or(0.0))
+            .bidirectional()
+            .sync_create()
+            .build();
+        assert_eq!(entry.get_text().unwrap(), "5.0");
+
+        spin_button.set_value(12.0);
+        assert_eq!(entry.get_text().unwrap(), "12.0");
+        entry.set_text("42");
+        assert_eq!(spin_button.get_value(), 42.0);
+
+        binding.unbind();
+        spin_button.set_value(7.0);
+        assert_eq!(entry.get_text().unwrap(), "42");
+
+        // Without `sync_create` nor `bidirectional`, only the later changes of
+        // the source are applied.
+        let check_button = gtk::CheckButton::new();
+        check_button.set_active(true);
+        check_button
+            .bind_property_builder("active", &entry, "visible")
+            .invert_boolean()
+            .build();
+        assert!(entry.get_visible());
+        check_button.set_active(false);
+        assert!(entry.get_visible());
+        check_button.set_active(true);
+        assert!(!entry.get_visible());
+        entry.set_visible(true);
+        assert!(check_button.get_active());
+
+        // Transformations returning `None` leave the other property as it is.
+        let binding = gtk::property_binding::bind_property_full(
+            &spin_button,
+            "value",
+            &entry,
+            "text",
+            glib::BindingFlags::BIDIRECTIONAL | glib::BindingFlags::SYNC_CREATE,
+            |value: f64| Some(format!("{:.0}", value)),
+            |text: String| text.parse::<f64>().ok(),
+        );
+        assert_eq!(entry.get_text().unwrap(), "7");
+        entry.set_text("not a number");
+        assert_eq!(spin_button.get_value(), 7.0);
+        entry.set_text("24");
+        assert_eq!(spin_button.get_value(), 24.0);
+        binding.unbind();
+
+        // The types of the transformations are checked against the properties.
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| {
+            spin_button
+                .bind_property_builder("value", &entry, "text")
+                .transform_to(|value: bool| value)
+                .build()
+        }))
+        .is_err());
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| {
+            spin_button
+                .bind_property_builder("value", &entry, "nonexistent")
+                .build()
+        }))
+        .is_err());
+    });
+}
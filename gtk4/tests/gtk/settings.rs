@@ -0,0 +1,39 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::gdk;
+use gtk::prelude::*;
+use gtk4 as gtk;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn settings() {
+    // This isn't the thread GTK is initialized on.
+    assert!(gtk::Settings::get_default().is_none());
+
+    crate::test_synced(|| {
+        let settings = gtk::Settings::get_default().expect("No default settings");
+        let display = gdk::Display::get_default().unwrap();
+        assert_eq!(gtk::Settings::get_for_display(&display), settings);
+
+        let notified = Rc::new(Cell::new(0));
+        let handler = {
+            let notified = notified.clone();
+            settings.connect_property_gtk_application_prefer_dark_theme_notify(move |_| {
+                notified.set(notified.get() + 1);
+            })
+        };
+
+        let prefer_dark = settings.get_property_gtk_application_prefer_dark_theme();
+        settings.set_property_gtk_application_prefer_dark_theme(!prefer_dark);
+        assert_eq!(
+            settings.get_property_gtk_application_prefer_dark_theme(),
+            !prefer_dark
+        );
+        assert_eq!(notified.get(), 1);
+
+        settings.disconnect(handler);
+        settings.set_property_gtk_application_prefer_dark_theme(prefer_dark);
+        assert_eq!(notified.get(), 1);
+    });
+}
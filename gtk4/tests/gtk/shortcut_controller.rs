@@ -0,0 +1,51 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::gdk;
+use gtk::prelude::*;
+use gtk::{ShortcutActionFlags, ShortcutController, ShortcutTrigger};
+use gtk4 as gtk;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn shortcut_controller() {
+    crate::test_synced(|| {
+        let window = gtk::Window::new();
+        let controller = ShortcutController::new();
+        window.add_controller(&controller);
+
+        let activations = Rc::new(Cell::new(0));
+        let activations_clone = activations.clone();
+        let shortcut = controller
+            .add_shortcut_str("<Control>w", move |widget| {
+                assert!(widget.is::<gtk::Window>());
+                activations_clone.set(activations_clone.get() + 1);
+                true
+            })
+            .unwrap();
+        assert!(shortcut
+            .get_trigger()
+            .unwrap()
+            .equal(&ShortcutTrigger::parse_string("<Control>w").unwrap()));
+
+        let action = shortcut.get_action().unwrap();
+        assert!(action.activate(ShortcutActionFlags::empty(), &window, None));
+        assert_eq!(activations.get(), 1);
+
+        assert!(controller.add_shortcut_str("<Control>", |_| true).is_err());
+
+        // Mnemonics are triggered with Alt by default.
+        assert_eq!(
+            controller.get_mnemonics_modifiers(),
+            gdk::ModifierType::ALT_MASK
+        );
+        let modifiers = gdk::ModifierType::ALT_MASK | gdk::ModifierType::SHIFT_MASK;
+        controller.set_mnemonics_modifiers(modifiers);
+        assert_eq!(controller.get_mnemonics_modifiers(), modifiers);
+
+        // Widgets with an activate signal are activated by their mnemonic.
+        let button = gtk::Button::with_mnemonic("_Close");
+        window.set_child(Some(&button));
+        assert!(button.mnemonic_activate(false));
+    });
+}
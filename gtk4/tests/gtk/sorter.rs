@@ -0,0 +1,105 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{gio, glib};
+use gtk4 as gtk;
+use std::cell::Cell;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct ValueSorter {
+        pub reversed: Cell<bool>,
+    }
+
+    impl ObjectSubclass for ValueSorter {
+        const NAME: &'static str = "TestValueSorter";
+        type Type = super::ValueSorter;
+        type ParentType = gtk::Sorter;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for ValueSorter {}
+
+    impl SorterImpl for ValueSorter {
+        fn compare(
+            &self,
+            _sorter: &Self::Type,
+            item1: &glib::Object,
+            item2: &glib::Object,
+        ) -> gtk::Ordering {
+            let value1 = item1.downcast_ref::<gtk::Adjustment>().unwrap().get_value();
+            let value2 = item2.downcast_ref::<gtk::Adjustment>().unwrap().get_value();
+            let ordering = value1.partial_cmp(&value2).unwrap();
+            if self.reversed.get() {
+                ordering.reverse().into()
+            } else {
+                ordering.into()
+            }
+        }
+
+        fn get_order(&self, _sorter: &Self::Type) -> gtk::SorterOrder {
+            gtk::SorterOrder::Total
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct ValueSorter(ObjectSubclass<imp::ValueSorter>) @extends gtk::Sorter;
+}
+
+impl ValueSorter {
+    fn new() -> Self {
+        glib::Object::new(&[]).expect("Failed to create ValueSorter")
+    }
+
+    fn set_reversed(&self, reversed: bool) {
+        let imp = imp::ValueSorter::from_instance(self);
+        imp.reversed.set(reversed);
+        self.changed(gtk::SorterChange::Inverted);
+    }
+}
+
+fn values(model: &gtk::SortListModel) -> Vec<f64> {
+    (0..model.get_n_items())
+        .map(|i| {
+            model
+                .get_object(i)
+                .unwrap()
+                .downcast::<gtk::Adjustment>()
+                .unwrap()
+                .get_value()
+        })
+        .collect()
+}
+
+#[test]
+fn sorter_subclass() {
+    crate::test_synced(|| {
+        let store = gio::ListStore::new(gtk::Adjustment::static_type());
+        for value in &[3.0, 1.0, 4.0, 2.0] {
+            store.append(&gtk::Adjustment::new(*value, 0.0, 10.0, 1.0, 1.0, 0.0));
+        }
+
+        let sorter = ValueSorter::new();
+        let model = gtk::SortListModel::new(Some(&store), Some(&sorter));
+        assert_eq!(sorter.get_order(), gtk::SorterOrder::Total);
+        assert_eq!(values(&model), [1.0, 2.0, 3.0, 4.0]);
+
+        sorter.set_reversed(true);
+        assert_eq!(values(&model), [4.0, 3.0, 2.0, 1.0]);
+
+        let first = store.get_object(0).unwrap();
+        let second = store.get_object(1).unwrap();
+        assert_eq!(sorter.compare(&first, &second), gtk::Ordering::Smaller);
+    });
+}
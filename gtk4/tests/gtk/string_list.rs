@@ -0,0 +1,41 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk4 as gtk;
+
+fn strings(list: &gtk::StringList) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn string_list() {
+    crate::test_synced(|| {
+        let mut list = gtk::StringList::new(&["a", "b"]);
+        list.extend(vec!["c".to_string(), "d".to_string()]);
+        assert_eq!(strings(&list), ["a", "b", "c", "d"]);
+
+        // Replaces "b" and "c" in the middle.
+        list.splice(1, 2, &["e", "f", "g"]);
+        assert_eq!(strings(&list), ["a", "e", "f", "g", "d"]);
+        assert_eq!(list.get_n_items(), 5);
+
+        list.remove(0);
+        list.append("h");
+        assert_eq!(strings(&list), ["e", "f", "g", "d", "h"]);
+
+        let collected = ["x", "y"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<gtk::StringList>();
+        assert_eq!(strings(&collected), ["x", "y"]);
+        assert_eq!((&collected).into_iter().count(), 2);
+
+        let drop_down = gtk::DropDown::from_strings(&["one", "two"]);
+        let model = drop_down
+            .get_model()
+            .unwrap()
+            .downcast::<gtk::StringList>()
+            .unwrap();
+        assert_eq!(strings(&model), ["one", "two"]);
+    });
+}
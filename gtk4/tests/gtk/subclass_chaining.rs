@@ -0,0 +1,95 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk4 as gtk;
+use std::cell::Cell;
+use std::rc::Rc;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct ChainingWindow {
+        pub chain_up: Cell<bool>,
+        pub close_requests: Cell<u32>,
+    }
+
+    impl ObjectSubclass for ChainingWindow {
+        const NAME: &'static str = "TestChainingWindow";
+        type Type = super::ChainingWindow;
+        type ParentType = gtk::Window;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for ChainingWindow {}
+    impl WidgetImpl for ChainingWindow {}
+
+    impl WindowImpl for ChainingWindow {
+        fn close_request(&self, window: &Self::Type) -> glib::signal::Inhibit {
+            self.close_requests.set(self.close_requests.get() + 1);
+            if self.chain_up.get() {
+                self.parent_close_request(window)
+            } else {
+                glib::signal::Inhibit(false)
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct ChainingWindow(ObjectSubclass<imp::ChainingWindow>)
+        @extends gtk::Widget, gtk::Window;
+}
+
+impl ChainingWindow {
+    fn new(chain_up: bool) -> Self {
+        let window: Self = glib::Object::new(&[]).expect("Failed to create ChainingWindow");
+        imp::ChainingWindow::from_instance(&window)
+            .chain_up
+            .set(chain_up);
+        // The default handler of GtkWindow hides the window instead of
+        // letting it be destroyed.
+        window.set_hide_on_close(true);
+        window
+    }
+
+    fn close_requests(&self) -> u32 {
+        imp::ChainingWindow::from_instance(self)
+            .close_requests
+            .get()
+    }
+}
+
+// Closes the window and returns whether it got destroyed.
+fn close(window: &ChainingWindow) -> bool {
+    let destroyed = Rc::new(Cell::new(false));
+    let destroyed_clone = destroyed.clone();
+    window.connect_destroy(move |_| destroyed_clone.set(true));
+    // Windows are only closed once realized.
+    window.show();
+    window.close();
+    destroyed.get()
+}
+
+#[test]
+fn subclass_chaining() {
+    crate::test_synced(|| {
+        let window = ChainingWindow::new(true);
+        assert!(!close(&window));
+        assert_eq!(window.close_requests(), 1);
+        assert!(!window.is_visible());
+
+        let window = ChainingWindow::new(false);
+        assert!(close(&window));
+        assert_eq!(window.close_requests(), 1);
+    });
+}
@@ -0,0 +1,127 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::{glib, CompositeTemplate};
+use gtk4 as gtk;
+use std::cell::{Cell, RefCell};
+
+const TEMPLATE: &str = r#"
+<interface>
+  <template class="TestCallbacksBox" parent="GtkBox">
+    <child>
+      <object class="GtkButton" id="button">
+        <signal name="clicked" handler="on_clicked" swapped="true"/>
+      </object>
+    </child>
+    <child>
+      <object class="GtkButton" id="named_button">
+        <property name="label">Named</property>
+        <signal name="clicked" handler="on-named-clicked"/>
+      </object>
+    </child>
+    <child>
+      <object class="GtkSwitch" id="switch">
+        <signal name="state-set" handler="on_state_set" swapped="true"/>
+      </object>
+    </child>
+  </template>
+</interface>
+"#;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(callbacks)]
+    pub struct CallbacksBox {
+        #[template_child]
+        pub button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub named_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub switch: TemplateChild<gtk::Switch>,
+        pub clicks: Cell<u32>,
+        pub log: RefCell<Vec<String>>,
+    }
+
+    impl ObjectSubclass for CallbacksBox {
+        const NAME: &'static str = "TestCallbacksBox";
+        type Type = super::CallbacksBox;
+        type ParentType = gtk::Box;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.set_template(TEMPLATE.as_bytes());
+            Self::bind_template_children(klass);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self::Type>) {
+            obj.init_template();
+        }
+    }
+
+    #[gtk::template_callbacks]
+    impl CallbacksBox {
+        // Swapped, the template object comes first.
+        #[template_callback]
+        fn on_clicked(&self, button: gtk::Button) {
+            if button == *self.button {
+                self.clicks.set(self.clicks.get() + 1);
+            }
+        }
+
+        // Not swapped, the template object comes last.
+        #[template_callback(name = "on-named-clicked")]
+        fn on_named_clicked(button: gtk::Button, this: super::CallbacksBox) {
+            let label = button.get_label();
+            CallbacksBox::from_instance(&this)
+                .log
+                .borrow_mut()
+                .push(label.as_deref().unwrap_or_default().to_string());
+        }
+
+        // The return value is converted back for the signal.
+        #[template_callback]
+        fn on_state_set(&self, state: bool, _switch: gtk::Switch) -> bool {
+            self.log.borrow_mut().push(format!("state {}", state));
+            true
+        }
+    }
+
+    impl ObjectImpl for CallbacksBox {}
+    impl WidgetImpl for CallbacksBox {}
+    impl BoxImpl for CallbacksBox {}
+}
+
+glib::wrapper! {
+    pub struct CallbacksBox(ObjectSubclass<imp::CallbacksBox>)
+        @extends gtk::Widget, gtk::Box;
+}
+
+#[test]
+fn template_callbacks() {
+    crate::test_synced(|| {
+        let widget: CallbacksBox = glib::Object::new(&[]).expect("Failed to create CallbacksBox");
+        let imp = imp::CallbacksBox::from_instance(&widget);
+
+        imp.button.emit_clicked();
+        imp.button.emit_clicked();
+        assert_eq!(imp.clicks.get(), 2);
+
+        imp.named_button.emit_clicked();
+        assert_eq!(*imp.log.borrow(), ["Named"]);
+
+        // The handler returns `true`, so the switch doesn't change its state.
+        imp.switch.set_active(true);
+        assert_eq!(*imp.log.borrow(), ["Named", "state true"]);
+        assert!(!imp.switch.get_state());
+    });
+}
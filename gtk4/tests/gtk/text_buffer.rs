@@ -0,0 +1,70 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk4 as gtk;
+
+#[test]
+fn text_buffer() {
+    crate::test_synced(|| {
+        let buffer = gtk::TextBuffer::new(None);
+        buffer.set_text("world");
+
+        // The mark follows the text when some is inserted before it.
+        let guard = buffer.mark_at(&buffer.get_iter_at_offset(2));
+        buffer.insert(&mut buffer.get_start_iter(), "hello ");
+        assert_eq!(guard.iter().get_offset(), 8);
+        let mark = guard.mark().clone();
+        drop(guard);
+        assert!(mark.get_deleted());
+
+        let bold = buffer
+            .create_tag(Some("bold"), &[("weight", &700)])
+            .unwrap();
+        let mut iter = buffer.get_end_iter();
+        buffer.insert_with_tags(&mut iter, "!", &[&bold]);
+        assert_eq!(buffer.text(), "hello world!");
+        assert!(buffer.get_iter_at_offset(11).has_tag(&bold));
+        assert!(!buffer.get_iter_at_offset(10).has_tag(&bold));
+
+        // Handlers can move the insertion point.
+        buffer.connect_insert_text(|buffer, iter, _| {
+            *iter = buffer.get_start_iter();
+        });
+        buffer.insert(&mut buffer.get_end_iter(), ">");
+        assert_eq!(buffer.text(), ">hello world!");
+
+        buffer.set_text("One fish\ntwo fish\nred fish\n");
+        let lines = buffer
+            .lines()
+            .map(|(start, end)| buffer.get_text(&start, &end, false).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(lines, ["One fish", "two fish", "red fish", ""]);
+
+        let offsets = |matches: gtk::TextSearch| {
+            matches
+                .map(|(start, end)| (start.get_offset(), end.get_offset()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            offsets(buffer.find("fish", gtk::TextSearchFlags::empty())),
+            [(4, 8), (13, 17), (22, 26)]
+        );
+        assert!(buffer
+            .find("ONE", gtk::TextSearchFlags::empty())
+            .next()
+            .is_none());
+        assert_eq!(
+            offsets(buffer.find("ONE", gtk::TextSearchFlags::CASE_INSENSITIVE)),
+            [(0, 3)]
+        );
+        assert_eq!(
+            offsets(buffer.find_in_range(
+                "fish",
+                gtk::TextSearchFlags::empty(),
+                &buffer.get_iter_at_offset(5),
+                &buffer.get_iter_at_offset(20),
+            )),
+            [(13, 17)]
+        );
+    });
+}
@@ -0,0 +1,74 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk4 as gtk;
+
+mod imp {
+    use super::*;
+
+    // Upper-cases the inserted text, and keeps its first line from being
+    // deleted.
+    #[derive(Debug, Default)]
+    pub struct TitleBuffer;
+
+    impl ObjectSubclass for TitleBuffer {
+        const NAME: &'static str = "TestTitleBuffer";
+        type Type = super::TitleBuffer;
+        type ParentType = gtk::TextBuffer;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for TitleBuffer {}
+
+    impl TextBufferImpl for TitleBuffer {
+        fn insert_text(&self, buffer: &Self::Type, iter: &mut gtk::TextIter, new_text: &str) {
+            self.parent_insert_text(buffer, iter, &new_text.to_uppercase())
+        }
+
+        fn delete_range(
+            &self,
+            buffer: &Self::Type,
+            start: &mut gtk::TextIter,
+            end: &mut gtk::TextIter,
+        ) {
+            if start.get_line() == 0 {
+                start.forward_line();
+            }
+            if start < end {
+                self.parent_delete_range(buffer, start, end)
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct TitleBuffer(ObjectSubclass<imp::TitleBuffer>) @extends gtk::TextBuffer;
+}
+
+#[test]
+fn text_buffer_subclass() {
+    crate::test_synced(|| {
+        let buffer: TitleBuffer = glib::Object::new(&[]).expect("Failed to create TitleBuffer");
+        buffer.set_text("title\nbody");
+        assert_eq!(buffer.text(), "TITLE\nBODY");
+
+        // The iter is revalidated to the end of the insertion.
+        let mut iter = buffer.get_end_iter();
+        buffer.insert(&mut iter, " text");
+        assert!(iter.is_end());
+        assert_eq!(buffer.text(), "TITLE\nBODY TEXT");
+
+        let (mut start, mut end) = buffer.get_bounds();
+        buffer.delete(&mut start, &mut end);
+        assert_eq!(buffer.text(), "TITLE\n");
+    });
+}
@@ -0,0 +1,55 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::prelude::*;
+use gtk4 as gtk;
+
+fn labels(widgets: impl Iterator<Item = gtk::Widget>) -> Vec<String> {
+    widgets
+        .map(|w| w.downcast::<gtk::Label>().unwrap().get_label().to_string())
+        .collect()
+}
+
+#[test]
+fn children() {
+    crate::test_synced(|| {
+        let container = gtk::Box::new(gtk::Orientation::Vertical, 0);
+        for label in &["a", "b", "c", "d"] {
+            container.append(&gtk::Label::new(Some(label)));
+        }
+
+        assert_eq!(labels(container.children()), ["a", "b", "c", "d"]);
+        assert_eq!(labels(container.children().rev()), ["d", "c", "b", "a"]);
+
+        // Both ends meet without returning a child twice.
+        let mut children = container.children();
+        assert_eq!(labels(children.by_ref().take(1)), ["a"]);
+        assert_eq!(labels(children.by_ref().rev().take(1)), ["d"]);
+        assert_eq!(labels(children), ["b", "c"]);
+
+        // Removing the current child doesn't stop the iteration.
+        let mut visited = Vec::new();
+        for child in container.children() {
+            let label = child.downcast_ref::<gtk::Label>().unwrap().get_label();
+            if label == "b" {
+                container.remove(&child);
+            }
+            visited.push(label.to_string());
+        }
+        assert_eq!(visited, ["a", "b", "c", "d"]);
+        assert_eq!(labels(container.children()), ["a", "c", "d"]);
+
+        let mut visited = Vec::new();
+        for child in container.children().rev() {
+            visited.push(
+                child
+                    .downcast_ref::<gtk::Label>()
+                    .unwrap()
+                    .get_label()
+                    .to_string(),
+            );
+            container.remove(&child);
+        }
+        assert_eq!(visited, ["d", "c", "a"]);
+        assert!(container.get_first_child().is_none());
+    });
+}
@@ -0,0 +1,67 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk4 as gtk;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct StyledWidget;
+
+    impl ObjectSubclass for StyledWidget {
+        const NAME: &'static str = "TestStyledWidget";
+        type Type = super::StyledWidget;
+        type ParentType = gtk::Widget;
+        type Instance = glib::subclass::simple::InstanceStruct<Self>;
+        type Class = glib::subclass::simple::ClassStruct<Self>;
+
+        glib::object_subclass!();
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.set_css_name("styled-widget");
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl ObjectImpl for StyledWidget {}
+    impl WidgetImpl for StyledWidget {}
+}
+
+glib::wrapper! {
+    pub struct StyledWidget(ObjectSubclass<imp::StyledWidget>)
+        @extends gtk::Widget;
+}
+
+fn css_classes(widget: &StyledWidget) -> Vec<String> {
+    widget
+        .get_css_classes()
+        .iter()
+        .map(|class| class.to_string())
+        .collect()
+}
+
+#[test]
+fn widget_css() {
+    crate::test_synced(|| {
+        let widget: StyledWidget = glib::Object::new(&[]).expect("Failed to create StyledWidget");
+        assert_eq!(widget.get_css_name().unwrap(), "styled-widget");
+
+        widget.add_css_class("card");
+        widget.add_css_class("flat");
+        assert!(widget.has_css_class("card"));
+        assert_eq!(css_classes(&widget), ["card", "flat"]);
+
+        widget.remove_css_class("card");
+        assert!(!widget.has_css_class("card"));
+
+        widget.set_css_classes(&["title", "dim-label"]);
+        assert!(!widget.has_css_class("flat"));
+        assert_eq!(css_classes(&widget), ["title", "dim-label"]);
+    });
+}